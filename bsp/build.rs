@@ -48,16 +48,6 @@ fn main() {
     println!("cargo:rustc-link-arg=-Tlink.x");
     println!("cargo:rustc-link-arg=-Tdefmt.x");
 
-    #[cfg(feature = "fsm_fourd")]
-    {
-        let input_file = Path::new("src/config/lsm6dsv320x_fourd_orientation.json");
-        let output_file = Path::new("src/config/fsm_config.rs");
-        parser::generate_rs_from_json(&input_file, &output_file, "FOUR_D", "LSM6DSV320X", false);
-
-        println!("cargo:rerun-if-changed=src/config/lsm6dsv320x_fourd_orientation.json");
-        println!("cargo:rerun-if-changed=src/config/fsm_config.rs");
-    }
-
     #[cfg(feature = "fsm_glance")]
     {
         let input_file = Path::new("src/config/lsm6dsv320x_glance.json");
@@ -78,7 +68,6 @@ fn main() {
         println!("cargo:rerun-if-changed=src/config/mlc_config.rs");
     }
 
-    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_FSM_FOURD");
     println!("cargo:rerun-if-env-changed=CARGO_FEATURE_FSM_GLANCE");
     println!("cargo:rerun-if-env-changed=CARGO_FEATURE_MLC_GYM");
 }
@@ -0,0 +1,58 @@
+// 4D orientation detection, from lsm6dsv320x_fourd_orientation.json
+// The driver crate includes this file in its reg_config tests.
+
+use super::RegConfigOp;
+
+pub const FOUR_D: &[RegConfigOp] = &[
+    RegConfigOp::write(0x10, 0x00),
+    RegConfigOp::write(0x11, 0x00),
+    RegConfigOp::write(0x01, 0x80),
+    RegConfigOp::write(0x04, 0x00),
+    RegConfigOp::write(0x05, 0x00),
+    RegConfigOp::write(0x5F, 0x4B),
+    RegConfigOp::write(0x46, 0x01),
+    RegConfigOp::write(0x0A, 0x00),
+    RegConfigOp::write(0x0B, 0x01),
+    RegConfigOp::write(0x0E, 0x00),
+    RegConfigOp::write(0x0F, 0x00),
+    RegConfigOp::write(0x17, 0x40),
+    RegConfigOp::write(0x02, 0x11),
+    RegConfigOp::write(0x08, 0x7A),
+    RegConfigOp::write(0x09, 0x00),
+    RegConfigOp::write(0x09, 0x00),
+    RegConfigOp::write(0x09, 0x01),
+    RegConfigOp::write(0x09, 0x01),
+    RegConfigOp::write(0x09, 0x00),
+    RegConfigOp::write(0x09, 0x04),
+    RegConfigOp::write(0x02, 0x41),
+    RegConfigOp::write(0x08, 0x00),
+    RegConfigOp::write(0x09, 0x91),
+    RegConfigOp::write(0x09, 0x10),
+    RegConfigOp::write(0x09, 0x16),
+    RegConfigOp::write(0x09, 0x00),
+    RegConfigOp::write(0x09, 0x0F),
+    RegConfigOp::write(0x09, 0x00),
+    RegConfigOp::write(0x09, 0x66),
+    RegConfigOp::write(0x09, 0x3A),
+    RegConfigOp::write(0x09, 0x66),
+    RegConfigOp::write(0x09, 0x32),
+    RegConfigOp::write(0x09, 0xF0),
+    RegConfigOp::write(0x09, 0x00),
+    RegConfigOp::write(0x09, 0x00),
+    RegConfigOp::write(0x09, 0x0F),
+    RegConfigOp::write(0x09, 0x00),
+    RegConfigOp::write(0x09, 0xEF),
+    RegConfigOp::write(0x09, 0x33),
+    RegConfigOp::write(0x09, 0x05),
+    RegConfigOp::write(0x09, 0x73),
+    RegConfigOp::write(0x09, 0x99),
+    RegConfigOp::write(0x09, 0x08),
+    RegConfigOp::write(0x09, 0x22),
+    RegConfigOp::write(0x04, 0x00),
+    RegConfigOp::write(0x05, 0x01),
+    RegConfigOp::write(0x17, 0x00),
+    RegConfigOp::write(0x01, 0x00),
+    RegConfigOp::write(0x5E, 0x02),
+    RegConfigOp::write(0x17, 0x03),
+    RegConfigOp::write(0x10, 0x44),
+];
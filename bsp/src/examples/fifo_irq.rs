@@ -31,7 +31,7 @@ where
     // Restore default configuration
     sensor.sw_reset().await.unwrap();
     // Enable Block Data Update
-    sensor.block_data_update_set(1).await.unwrap();
+    sensor.block_data_update_enable_set(true).await.unwrap();
 
     // Set FIFO watermark (numer of unread sensor data TAG + 6 bytes
     // stored in FIFO) to FIFO_WATERMARK samples
@@ -39,7 +39,7 @@ where
 
     // Set FIFO batch XL/Gyro ODR.
     sensor.fifo_xl_batch_set(FifoBatch::_60hz).await.unwrap();
-    sensor.fifo_hg_xl_batch_set(1).await.unwrap();
+    sensor.fifo_hg_xl_batch_enable_set(true).await.unwrap();
     sensor.fifo_gy_batch_set(FifoBatch::_120hz).await.unwrap();
     // Set FIFO mode to Stream mode (aka Continuous Mode)
     sensor.fifo_mode_set(FifoMode::Stream).await.unwrap();
@@ -47,11 +47,11 @@ where
         .fifo_timestamp_batch_set(FifoTimestampBatch::Dec32)
         .await
         .unwrap();
-    sensor.timestamp_set(1).await.unwrap();
+    sensor.timestamp_enable_set(true).await.unwrap();
 
     // Set Otuput Data Rate
     sensor.xl_setup(DataRate::_60hz, XlMode::HighPerformance).await.unwrap();
-    sensor.hg_xl_data_rate_set(HgXlDataRate::_480hz, 1).await.unwrap();
+    sensor.hg_xl_setup(HgXlDataRate::_480hz, true).await.unwrap();
     sensor.gy_setup(DataRate::_120hz, GyMode::HighPerformance).await.unwrap();
 
     // Set full scale
@@ -65,22 +65,20 @@ where
     filt_settling_mask.irq_xl = 1;
     filt_settling_mask.irq_g = 1;
     sensor.filt_settling_mask_set(filt_settling_mask).await.unwrap();
-    sensor.filt_gy_lp1_set(1).await.unwrap();
+    sensor.filt_gy_lp1_enable_set(true).await.unwrap();
     sensor
         .filt_gy_lp1_bandwidth_set(FiltLpBandwidth::UltraLight)
         .await
         .unwrap();
-    sensor.filt_xl_lp2_set(1).await.unwrap();
+    sensor.filt_xl_lp2_enable_set(true).await.unwrap();
     sensor
         .filt_xl_lp2_bandwidth_set(FiltLpBandwidth::Strong)
         .await
         .unwrap();
 
     // enable fifo_th on High-G XL (sensor at highest frequency)
-    let mut pin_int = PinInt1Route::default();
-    pin_int.fifo_th = 1;
-    sensor.pin_int1_route_set(&pin_int).await.unwrap();
-    //sensor.pin_int2_route_set(&pin_int);
+    sensor.int1_route_set(Int1Route::FIFO_TH).await.unwrap();
+    //sensor.int2_route_set(Int2Route::FIFO_TH).await.unwrap();
 
     let mut lowg_xl_sum = [0f32; 3];
     let mut lowg_xl_cnt = 0;
@@ -93,47 +91,36 @@ where
         // Wait for interrupt
         int_pin.wait_for_event().await;
 
-        let fifo_status = sensor.fifo_status_get().await.unwrap();
-        let num = fifo_status.fifo_level;
+        let fifo_state = sensor.fifo_state_get().await.unwrap();
+        let num = fifo_state.level;
 
         for _ in 0..num {
             // Read FIFO sensor value
-            let f_data = sensor.fifo_out_raw_get().await.unwrap();
-
-            let data_x: i16 = (((f_data.data[1] as u16) << 8) | f_data.data[0] as u16) as i16;
-            let data_y: i16 = (((f_data.data[3] as u16) << 8) | f_data.data[2] as u16) as i16;
-            let data_z: i16 = (((f_data.data[5] as u16) << 8) | f_data.data[4] as u16) as i16;
-            let raw_array_data = [data_x, data_y, data_z];
-            let ts = u32::from_le_bytes([
-                f_data.data[0],
-                f_data.data[1],
-                f_data.data[2],
-                f_data.data[3],
-            ]);
-
-            match f_data.tag {
-                Tag::XlNc => {
-                    for (index, &data) in raw_array_data.iter().enumerate() {
+            let frame = sensor.fifo_out_get().await.unwrap();
+
+            match frame {
+                FifoFrame::XlNc { xyz, .. } => {
+                    for (index, &data) in xyz.iter().enumerate() {
                         let acc_mg = from_fs2_to_mg(data);
                         lowg_xl_sum[index] += acc_mg;
                     }
                     lowg_xl_cnt += 1;
                 }
-                Tag::XlHg => {
-                    for (index, &data) in raw_array_data.iter().enumerate() {
+                FifoFrame::XlHg { xyz, .. } => {
+                    for (index, &data) in xyz.iter().enumerate() {
                         let acc_mg = from_fs320_to_mg(data);
                         hg_xl_sum[index] += acc_mg;
                     }
                     hg_xl_cnt += 1;
                 }
-                Tag::GyNc => {
-                    for (index, &data) in raw_array_data.iter().enumerate() {
+                FifoFrame::GyNc { xyz, .. } => {
+                    for (index, &data) in xyz.iter().enumerate() {
                         let angular_rate_mdps = from_fs2000_to_mdps(data);
                         gyro_sum[index] += angular_rate_mdps;
                     }
                     gyro_cnt += 1;
                 }
-                Tag::Timestamp => {
+                FifoFrame::Timestamp { timestamp: ts, .. } => {
                     writeln!(tx, "TIMESTAMP [ms] {}", ts).unwrap();
 
                     // Print media low-g xl data
@@ -196,8 +183,8 @@ where
                         gyro_cnt = 0;
                     }
                 }
-                _ => {
-                    writeln!(tx, "[0x{:x}] UNHANDLED TAG", f_data.tag as u8).unwrap();
+                other => {
+                    writeln!(tx, "UNHANDLED FRAME {:?}", other).unwrap();
                 }
             }
         }
@@ -2,8 +2,7 @@ use defmt::info;
 use maybe_async::maybe_async;
 use crate::*;
 
-use crate::config::fsm_config::FOUR_D;
-use st_mems_reg_config_conv::ucf_entry::MemsUcfOp;
+use crate::config::fsm_fourd::FOUR_D;
 
 #[maybe_async]
 pub async fn run<B, D, L, I>(bus: B, mut tx: L, mut delay: D, mut int_pin: I) -> !
@@ -14,6 +13,7 @@ where
     I: InterruptPin
 {
     use lsm6dsv320x::*;
+    use lsm6dsv320x::prelude::Event;
 
     info!("Configuring the sensor");
     let mut sensor = Lsm6dsv320x::from_bus(bus, delay.clone());
@@ -31,40 +31,24 @@ where
     // Restore default configuration
     sensor.sw_reset().await.unwrap();
 
-    for ucf_entry in FOUR_D {
-        match ucf_entry.op {
-            MemsUcfOp::Delay => {
-                delay.delay_ms(ucf_entry.data.into()).await;
-            }
-            MemsUcfOp::Write => {
-                sensor
-                    .bus
-                    .write_to_register(ucf_entry.address as u8, &[ucf_entry.data])
-                    .await
-                    .unwrap();
-            }
-            _ => {}
-        }
-    }
+    sensor.load_reg_config(FOUR_D).await.unwrap();
 
     loop {
         // Wait for interrupt
         int_pin.wait_for_event().await;
 
-        let status = sensor.all_sources_get().await.unwrap();
-        if status.fsm1 == 1 {
-            let fsm_out = sensor.fsm_out_get().await.unwrap();
-            match fsm_out.fsm_outs1 {
-                0x10 => {
+        for event in sensor.events_get().await.unwrap() {
+            match event {
+                Event::FsmOutput(1, 0x10) => {
                     writeln!(tx, "Y down event").unwrap();
                 }
-                0x20 => {
+                Event::FsmOutput(1, 0x20) => {
                     writeln!(tx, "Y up event").unwrap();
                 }
-                0x40 => {
+                Event::FsmOutput(1, 0x40) => {
                     writeln!(tx, "X down event").unwrap();
                 }
-                0x80 => {
+                Event::FsmOutput(1, 0x80) => {
                     writeln!(tx, "X up event").unwrap();
                 }
                 _ => {}
@@ -14,6 +14,7 @@ where
     I: InterruptPin
 {
     use lsm6dsv320x::*;
+    use lsm6dsv320x::prelude::{Event, RegConfigOp};
 
     info!("Configuring the sensor");
     let mut sensor = Lsm6dsv320x::from_bus(bus, delay.clone());
@@ -31,34 +32,23 @@ where
     // Restore default configuration
     sensor.sw_reset().await.unwrap();
 
-    for ucf_entry in GLANCE {
-        match ucf_entry.op {
-            MemsUcfOp::Delay => {
-                delay.delay_ms(ucf_entry.data.into()).await;
-            }
-            MemsUcfOp::Write => {
-                sensor
-                    .bus
-                    .write_to_register(ucf_entry.address as u8, &[ucf_entry.data])
-                    .await
-                    .unwrap();
-            }
-            _ => {}
-        }
-    }
+    let program = GLANCE.iter().filter_map(|ucf_entry| match ucf_entry.op {
+        MemsUcfOp::Delay => Some(RegConfigOp::delay(ucf_entry.data.into())),
+        MemsUcfOp::Write => Some(RegConfigOp::write(ucf_entry.address as u8, ucf_entry.data)),
+        _ => None,
+    });
+    sensor.load_reg_config(program).await.unwrap();
 
     loop {
         // Wait for interrupt
         int_pin.wait_for_event().await;
 
-        let status = sensor.all_sources_get().await.unwrap();
-        if status.fsm1 == 1 {
-            let fsm_out = sensor.fsm_out_get().await.unwrap();
-            match fsm_out.fsm_outs1 {
-                0x20 => {
+        for event in sensor.events_get().await.unwrap() {
+            match event {
+                Event::FsmOutput(1, 0x20) => {
                     writeln!(tx, "deglance event").unwrap();
                 }
-                0x8 => {
+                Event::FsmOutput(1, 0x8) => {
                     writeln!(tx, "Glance event").unwrap();
                 }
                 _ => {}
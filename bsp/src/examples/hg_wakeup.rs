@@ -29,10 +29,10 @@ where
     // Restore default configuration
     sensor.sw_reset().await.unwrap();
     // Enable Block Data Update
-    sensor.block_data_update_set(1).await.unwrap();
+    sensor.block_data_update_enable_set(true).await.unwrap();
 
     // Set Otuput Data Rate
-    sensor.hg_xl_data_rate_set(HgXlDataRate::_960hz, 1).await.unwrap();
+    sensor.hg_xl_setup(HgXlDataRate::_960hz, true).await.unwrap();
 
     // Set full scale
     sensor.hg_xl_full_scale_set(HgXlFullScale::_256g).await.unwrap();
@@ -44,7 +44,7 @@ where
     filt_settling_mask.irq_g = 1;
 
     sensor.filt_settling_mask_set(filt_settling_mask).await.unwrap();
-    sensor.filt_xl_lp2_set(1).await.unwrap();
+    sensor.filt_xl_lp2_enable_set(true).await.unwrap();
     sensor
         .filt_xl_lp2_bandwidth_set(FiltLpBandwidth::Strong)
         .await.unwrap();
@@ -55,25 +55,23 @@ where
     sensor.hg_wake_up_cfg_set(wakeup_cfg).await.unwrap();
 
     // Enable interrupt on High-G XL (sensor at highest frequency)
-    let mut pin_int = PinIntRouteHg::default();
-    pin_int.hg_wakeup = 1;
-    sensor.pin_int1_route_hg_set(&pin_int).await.unwrap();
-    //sensor.pint_int2_route_hg_set(&pin_int).await.unwrap();
+    sensor.int1_route_hg_set(IntRouteHg::HG_WAKEUP).await.unwrap();
+    //sensor.int2_route_hg_set(IntRouteHg::HG_WAKEUP).await.unwrap();
 
-    let mut int_cfg = HgWuInterruptCfg::default();
-    int_cfg.hg_interrupts_enable = 1;
-    sensor.hg_wu_interrupt_cfg_set(int_cfg).await.unwrap();
+    let mut int_cfg = HgInterruptConfig::default();
+    int_cfg.enable = true;
+    sensor.hg_interrupt_config_set(int_cfg).await.unwrap();
 
     loop {
         // Wait for interrupt
         int_pin.wait_for_event().await;
 
-        let status = sensor.hg_event_get().await.unwrap();
+        let status = sensor.hg_event_status_get().await.unwrap();
 
-        if status.hg_event == 1 {
-            let axis_x = status.hg_wakeup_x;
-            let axis_y = status.hg_wakeup_y;
-            let axis_z = status.hg_wakeup_z;
+        if status.event {
+            let axis_x = status.wake_up_axes.x;
+            let axis_y = status.wake_up_axes.y;
+            let axis_z = status.wake_up_axes.z;
 
             writeln!(
                 tx,
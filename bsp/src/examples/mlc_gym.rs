@@ -14,6 +14,7 @@ where
     I: InterruptPin
 {
     use lsm6dsv320x::*;
+    use lsm6dsv320x::prelude::{Event, RegConfigOp};
 
     info!("Configuring the sensor");
     let mut sensor = Lsm6dsv320x::from_bus(bus, delay.clone());
@@ -31,37 +32,26 @@ where
     // Restore default configuration
     sensor.sw_reset().await.unwrap();
 
-    for ucf_entry in GYM_RIGHT {
-        match ucf_entry.op {
-            MemsUcfOp::Delay => {
-                delay.delay_ms(ucf_entry.data.into()).await;
-            }
-            MemsUcfOp::Write => {
-                sensor
-                    .bus
-                    .write_to_register(ucf_entry.address as u8, &[ucf_entry.data])
-                    .await
-                    .unwrap();
-            }
-            _ => {}
-        }
-    }
+    let program = GYM_RIGHT.iter().filter_map(|ucf_entry| match ucf_entry.op {
+        MemsUcfOp::Delay => Some(RegConfigOp::delay(ucf_entry.data.into())),
+        MemsUcfOp::Write => Some(RegConfigOp::write(ucf_entry.address as u8, ucf_entry.data)),
+        _ => None,
+    });
+    sensor.load_reg_config(program).await.unwrap();
 
     loop {
         // Wait for interrupt
         int_pin.wait_for_event().await;
 
-        let status = sensor.all_sources_get().await.unwrap();
-        if status.mlc1 == 1 {
-            let mlc_out = sensor.mlc_out_get().await.unwrap();
-            match mlc_out.mlc1_src {
-                4 => {
+        for event in sensor.events_get().await.unwrap() {
+            match event {
+                Event::MlcClass(1, 4) => {
                     writeln!(tx, "biceps curl event").unwrap();
                 }
-                8 => {
+                Event::MlcClass(1, 8) => {
                     writeln!(tx, "Lateral raises event").unwrap();
                 }
-                12 => {
+                Event::MlcClass(1, 12) => {
                     writeln!(tx, "Squats event").unwrap();
                 }
                 _ => {}
@@ -31,11 +31,11 @@ where
     // Restore default configuration
     sensor.sw_reset().await.unwrap();
     // Enable Block Data Update
-    sensor.block_data_update_set(1).await.unwrap();
+    sensor.block_data_update_enable_set(true).await.unwrap();
 
     // Set Otuput Data Rate
     sensor.xl_setup(DataRate::_1920hz, XlMode::HighPerformance).await.unwrap();
-    sensor.hg_xl_data_rate_set(HgXlDataRate::_960hz, 1).await.unwrap();
+    sensor.hg_xl_setup(HgXlDataRate::_960hz, true).await.unwrap();
     sensor.gy_setup(DataRate::_120hz, GyMode::HighPerformance).await.unwrap();
 
     // Set full scale
@@ -50,20 +50,18 @@ where
     filt_settling_mask.irq_g = 1;
 
     sensor.filt_settling_mask_set(filt_settling_mask).await.unwrap();
-    sensor.filt_gy_lp1_set(1).await.unwrap();
+    sensor.filt_gy_lp1_enable_set(true).await.unwrap();
     sensor
         .filt_gy_lp1_bandwidth_set(FiltLpBandwidth::UltraLight)
         .await.unwrap();
-    sensor.filt_xl_lp2_set(1).await.unwrap();
+    sensor.filt_xl_lp2_enable_set(true).await.unwrap();
     sensor
         .filt_xl_lp2_bandwidth_set(FiltLpBandwidth::Strong)
         .await.unwrap();
 
     // Enable interrupt on High-G XL (sensor at highest frequency)
-    let mut pin_int = PinIntRouteHg::default();
-    pin_int.drdy_hg_xl = 1;
-    sensor.pin_int1_route_hg_set(&pin_int).await.unwrap();
-    //sensor.pint_int2_route_hg_set(&pin_int).await.unwrap();
+    sensor.int1_route_hg_set(IntRouteHg::DRDY_HG_XL).await.unwrap();
+    //sensor.int2_route_hg_set(IntRouteHg::DRDY_HG_XL).await.unwrap();
 
     let mut lowg_xl_sum: [f32; 3] = [0.0; 3];
     let mut lowg_xl_cnt: u16 = 0;
@@ -78,10 +76,10 @@ where
         // Wait for interrupt
         int_pin.wait_for_event().await;
 
-        let status = sensor.flag_data_ready_get().await.unwrap();
+        let status = sensor.data_ready_get().await.unwrap();
 
         // Read output only if new xl value is available
-        if status.drdy_xl == 1 {
+        if status.contains(DataReadyFlags::DRDY_XL) {
             // Read acceleration data
             let data_raw_acceleration = sensor.acceleration_raw_get().await.unwrap();
 
@@ -92,7 +90,7 @@ where
             lowg_xl_cnt += 1;
         }
 
-        if status.drdy_hgxl == 1 {
+        if status.contains(DataReadyFlags::DRDY_HGXL) {
             // Read acceleration field data
             let data_raw_motion = sensor.hg_acceleration_raw_get().await.unwrap();
 
@@ -104,7 +102,7 @@ where
         }
 
         // Read output only if new gyroscope value is available
-        if status.drdy_gy == 1 {
+        if status.contains(DataReadyFlags::DRDY_GY) {
             // Read angular rate data
             let data_raw_angular_rate = sensor.angular_rate_raw_get().await.unwrap();
 
@@ -116,7 +114,7 @@ where
             gyro_cnt += 1;
         }
 
-        if status.drdy_temp == 1 {
+        if status.contains(DataReadyFlags::DRDY_TEMP) {
             // Read temperature data
             let data_raw_temperature = sensor.temperature_raw_get().await.unwrap();
             let temperature_deg_c = from_lsb_to_celsius(data_raw_temperature);
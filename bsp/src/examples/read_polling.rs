@@ -30,11 +30,11 @@ where
     // Restore default configuration
     sensor.sw_reset().await.unwrap();
     // Enable Block Data Update
-    sensor.block_data_update_set(1).await.unwrap();
+    sensor.block_data_update_enable_set(true).await.unwrap();
 
     // Set Otuput Data Rate
     sensor.xl_setup(DataRate::_60hz, XlMode::HighPerformance).await.unwrap();
-    sensor.hg_xl_data_rate_set(HgXlDataRate::_960hz, 1).await.unwrap();
+    sensor.hg_xl_setup(HgXlDataRate::_960hz, true).await.unwrap();
     sensor.gy_setup(DataRate::_120hz, GyMode::HighPerformance).await.unwrap();
 
     // Configure filtering chain
@@ -43,12 +43,12 @@ where
     filt_settling_mask.irq_xl = 1;
     filt_settling_mask.irq_g = 1;
     sensor.filt_settling_mask_set(filt_settling_mask).await.unwrap();
-    sensor.filt_gy_lp1_set(1).await.unwrap();
+    sensor.filt_gy_lp1_enable_set(true).await.unwrap();
     sensor
         .filt_gy_lp1_bandwidth_set(FiltLpBandwidth::UltraLight)
         .await
         .unwrap();
-    sensor.filt_xl_lp2_set(1).await.unwrap();
+    sensor.filt_xl_lp2_enable_set(true).await.unwrap();
     sensor
         .filt_xl_lp2_bandwidth_set(FiltLpBandwidth::Strong)
         .await
@@ -66,9 +66,9 @@ where
     // Read samples in polling mode (no int)
     loop {
         // Read output only if new xl value is available
-        let drdy = sensor.flag_data_ready_get().await.unwrap();
+        let drdy = sensor.data_ready_get().await.unwrap();
 
-        if drdy.drdy_xl == 1 {
+        if drdy.contains(DataReadyFlags::DRDY_XL) {
             // Read acceleration data
             let data_raw_acceleration = sensor.acceleration_raw_get().await.unwrap();
 
@@ -79,7 +79,7 @@ where
             lowg_xl_cnt += 1;
         }
 
-        if drdy.drdy_hgxl == 1 {
+        if drdy.contains(DataReadyFlags::DRDY_HGXL) {
             // Read acceleration field data
             let data_raw_motion = sensor.hg_acceleration_raw_get().await.unwrap();
 
@@ -91,7 +91,7 @@ where
         }
 
         // Read output only if new gyroscope value is available
-        if drdy.drdy_gy == 1 {
+        if drdy.contains(DataReadyFlags::DRDY_GY) {
             // Read angular rate data
             let data_raw_angular_rate = sensor.angular_rate_raw_get().await.unwrap();
 
@@ -103,7 +103,7 @@ where
             gyro_cnt += 1;
         }
 
-        if drdy.drdy_temp == 1 {
+        if drdy.contains(DataReadyFlags::DRDY_TEMP) {
             // Read temperature data
             let data_raw_temperature = sensor.temperature_raw_get().await.unwrap();
             let temperature_deg_c = from_lsb_to_celsius(data_raw_temperature);
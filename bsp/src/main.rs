@@ -96,7 +96,9 @@ mod examples {
 
 #[cfg(feature = "fsm_fourd")]
 mod config {
-    pub mod fsm_config;
+    use crate::lsm6dsv320x::prelude::RegConfigOp;
+
+    pub mod fsm_fourd;
 }
 
 // FSM glance detection
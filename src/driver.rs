@@ -3,6 +3,7 @@ use super::{
     SensorOperation, SevenBitAddress, SpiDevice, bisync, i2c, prelude::*, register::BankState, spi,
 };
 
+use core::borrow::Borrow;
 use core::fmt::Debug;
 use core::marker::PhantomData;
 use half::f16;
@@ -35,6 +36,7 @@ pub enum Error<B> {
     FailedToReadMemBank,
     FailedToSetMemBank(MemBank),
     HwNoResponse,
+    InvalidFifoPlan(FifoPlanError), // FifoPlan rejected by FifoPlan::budget
 }
 
 #[bisync]
@@ -285,16 +287,30 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     ///
     /// # Arguments
     ///
-    /// * `val`: 1/0 Enables/Disables accelerometer user offset correction block.
-    pub async fn xl_offset_on_out_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    /// * `val`: true/false Enables/Disables accelerometer user offset correction block.
+    pub async fn xl_offset_on_out_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ctrl9 = Ctrl9::read(self).await?;
-        ctrl9.set_usr_off_on_out(val);
+        ctrl9.set_usr_off_on_out(val as u8);
         ctrl9.write(self).await
     }
 
+    /// Enables accelerometer user offset correction block; it is valid for the low-pass path.
+    #[deprecated(since = "2.1.0", note = "please use xl_offset_on_out_enable_set")]
+    pub async fn xl_offset_on_out_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.xl_offset_on_out_enable_set(val & 0x01 == 1).await
+    }
+
+    /// Get the settings of accelerometer user offset correction block; it is valid for the low-pass path.
+    pub async fn xl_offset_on_out_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        Ctrl9::read(self)
+            .await
+            .map(|ctrl9| ctrl9.usr_off_on_out() == 1)
+    }
+
     /// Get the settings of accelerometer user offset correction block; it is valid for the low-pass path.
+    #[deprecated(since = "2.1.0", note = "please use xl_offset_on_out_enable_get")]
     pub async fn xl_offset_on_out_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ctrl9::read(self).await.map(|ctrl9| ctrl9.usr_off_on_out())
+        self.xl_offset_on_out_enable_get().await.map(u8::from)
     }
 
     /// Set the accelerometer user offset correction values (in mg).
@@ -304,7 +320,11 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     ///
     /// The USR_OFF_W bit is automatically enabled based on the input values (precision is shared across
     /// all axes).
-    pub async fn xl_offset_mg_set(&mut self, val: XlOffsetMg) -> Result<(), Error<B::Error>> {
+    pub async fn xl_offset_set(
+        &mut self,
+        val: Vector3<Acceleration<MilliG>>,
+    ) -> Result<(), Error<B::Error>> {
+        let val = XlOffsetMg::from(val);
         let mut z_ofs_usr = ZOfsUsr::read(self).await?;
         let mut y_ofs_usr = YOfsUsr::read(self).await?;
         let mut x_ofs_usr = XOfsUsr::read(self).await?;
@@ -360,8 +380,16 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(())
     }
 
+    /// Set the accelerometer user offset correction values (in mg).
+    #[deprecated(since = "2.1.0", note = "please use xl_offset_set")]
+    pub async fn xl_offset_mg_set(&mut self, val: XlOffsetMg) -> Result<(), Error<B::Error>> {
+        self.xl_offset_set(val.into()).await
+    }
+
     /// Get the accelerometer user offset correction values (in mg).
-    pub async fn xl_offset_mg_get(&mut self) -> Result<XlOffsetMg, Error<B::Error>> {
+    pub async fn xl_offset_get(
+        &mut self,
+    ) -> Result<Vector3<Acceleration<MilliG>>, Error<B::Error>> {
         let ctrl9 = Ctrl9::read(self).await?;
         let z_ofs_usr = ZOfsUsr::read(self).await.map(|ofs| ofs.z_ofs_usr())?;
         let y_ofs_usr = YOfsUsr::read(self).await.map(|ofs| ofs.y_ofs_usr())?;
@@ -379,11 +407,21 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
             x_mg: x_ofs_usr as f32 * scale_factor,
         };
 
-        Ok(val)
+        Ok(val.into())
+    }
+
+    /// Get the accelerometer user offset correction values (in mg).
+    #[deprecated(since = "2.1.0", note = "please use xl_offset_get")]
+    pub async fn xl_offset_mg_get(&mut self) -> Result<XlOffsetMg, Error<B::Error>> {
+        self.xl_offset_get().await.map(XlOffsetMg::from)
     }
 
     /// Set the HG Accelerometer user offset correction values (in mg).
-    pub async fn hg_xl_offset_mg_set(&mut self, val: XlOffsetMg) -> Result<(), Error<B::Error>> {
+    pub async fn hg_xl_offset_set(
+        &mut self,
+        val: Vector3<Acceleration<MilliG>>,
+    ) -> Result<(), Error<B::Error>> {
+        let val = XlOffsetMg::from(val);
         let mut ctrl1_xl_hg = Ctrl1XlHg::read(self).await?;
 
         let mut ofs_usr = XlHgXYZOfsUsr::default();
@@ -414,8 +452,16 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(())
     }
 
+    /// Set the HG Accelerometer user offset correction values (in mg).
+    #[deprecated(since = "2.1.0", note = "please use hg_xl_offset_set")]
+    pub async fn hg_xl_offset_mg_set(&mut self, val: XlOffsetMg) -> Result<(), Error<B::Error>> {
+        self.hg_xl_offset_set(val.into()).await
+    }
+
     /// Get the HG Accelerometer user offset correction values in mg.
-    pub async fn hg_xl_offset_mg_get(&mut self) -> Result<XlOffsetMg, Error<B::Error>> {
+    pub async fn hg_xl_offset_get(
+        &mut self,
+    ) -> Result<Vector3<Acceleration<MilliG>>, Error<B::Error>> {
         let ctrl1_xl_hg = Ctrl1XlHg::read(self).await?;
 
         let ofs_usr_arr = XlHgXYZOfsUsr::read(self).await?;
@@ -426,14 +472,19 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
             x_mg: 0.0,
         };
 
-        if ctrl1_xl_hg.hg_usr_off_on_out() == 0 {
-            Ok(val)
-        } else {
+        if ctrl1_xl_hg.hg_usr_off_on_out() != 0 {
             val.z_mg = ofs_usr_arr.z as f32 * 0.25;
             val.y_mg = ofs_usr_arr.y as f32 * 0.25;
             val.x_mg = ofs_usr_arr.x as f32 * 0.25;
-            Ok(val)
         }
+
+        Ok(val.into())
+    }
+
+    /// Get the HG Accelerometer user offset correction values in mg.
+    #[deprecated(since = "2.1.0", note = "please use hg_xl_offset_get")]
+    pub async fn hg_xl_offset_mg_get(&mut self) -> Result<XlOffsetMg, Error<B::Error>> {
+        self.hg_xl_offset_get().await.map(XlOffsetMg::from)
     }
 
     /// Perform reboot of the device
@@ -451,7 +502,7 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         /* Save current data rates */
         let xl_data_rate = self.xl_data_rate_get().await?;
         let gy_data_rate = self.gy_data_rate_get().await?;
-        let (hg_xl_data_rate, reg_out_en) = self.hg_xl_data_rate_get().await?;
+        let (hg_xl_data_rate, reg_out_en) = self.hg_xl_setup_get().await?;
 
         /* Save XL/GY current modes */
         let xl_md = self.xl_mode_get().await?;
@@ -460,7 +511,7 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         /* 1. Set the low-g accelerometer, high-g accelerometer, and gyroscope in power-down mode */
         self.xl_setup(DataRate::Off, xl_md).await?;
         self.gy_setup(DataRate::Off, gy_md).await?;
-        self.hg_xl_data_rate_set(HgXlDataRate::Off, 0).await?;
+        self.hg_xl_setup(HgXlDataRate::Off, false).await?;
 
         /* 2. Set the BOOT bit of the CTRL3 register to 1. */
         ctrl3.set_boot(1);
@@ -472,7 +523,7 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         /* Restore data rates */
         self.xl_setup(xl_data_rate, xl_md).await?;
         self.gy_setup(gy_data_rate, gy_md).await?;
-        self.hg_xl_data_rate_set(hg_xl_data_rate, reg_out_en).await
+        self.hg_xl_setup(hg_xl_data_rate, reg_out_en).await
     }
 
     /// Perform s/w reset of the device.
@@ -497,7 +548,7 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         /* 1. Set the low-g accelerometer, high-g accelerometer, and gyroscope in power-down mode */
         self.xl_setup(DataRate::Off, XlMode::Normal).await?;
         self.gy_setup(DataRate::Off, GyMode::LowPower).await?;
-        self.hg_xl_data_rate_set(HgXlDataRate::Off, 0).await?;
+        self.hg_xl_setup(HgXlDataRate::Off, false).await?;
 
         /* 2. Set the SW_RESET bit of the CTRL3 register to 1. */
         ctrl3.set_sw_reset(1);
@@ -657,7 +708,7 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         } else {
             // if HAODR switch is not required, just set ctrl1 settings
             ctrl1.set_op_mode_xl(xl_mode as u8);
-            ctrl1.set_odr_xl(xl_odr as u8);
+            ctrl1.set_odr_xl((xl_odr as u8) & 0x0F);
             haodr.set_haodr_sel(xl_ha);
             ctrl1.write(self).await?;
             haodr.write(self).await?;
@@ -725,7 +776,7 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
             // if HAODR switch is not required, just set ctrl2 settings
 
             ctrl2.set_op_mode_g(gy_mode as u8);
-            ctrl2.set_odr_g(gy_odr as u8);
+            ctrl2.set_odr_g((gy_odr as u8) & 0x0F);
             haodr.set_haodr_sel(gy_ha);
 
             ctrl2.write(self).await?;
@@ -800,8 +851,8 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
 
         // set xl and gy data rates and restore high-g xl and eis to their previous data rates
 
-        ctrl1.set_odr_xl(xl_odr as u8);
-        ctrl2.set_odr_g(gy_odr as u8);
+        ctrl1.set_odr_xl((xl_odr as u8) & 0x0F);
+        ctrl2.set_odr_g((gy_odr as u8) & 0x0F);
         ctrl1.write(self).await?;
         ctrl2.write(self).await?;
         // if off, there is no need to turn them on
@@ -815,6 +866,159 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(())
     }
 
+    /// Get a snapshot of the device configuration.
+    pub async fn device_config_get(&mut self) -> Result<DeviceConfig, Error<B::Error>> {
+        let (hg_xl_odr, hg_xl_reg_out_en) = self.hg_xl_setup_get().await?;
+        let routing = self.interrupt_routing_get().await?;
+
+        Ok(DeviceConfig {
+            xl_odr: self.xl_data_rate_get().await?,
+            xl_mode: self.xl_mode_get().await?,
+            xl_full_scale: self.xl_full_scale_get().await?,
+            gy_odr: self.gy_data_rate_get().await?,
+            gy_mode: self.gy_mode_get().await?,
+            gy_full_scale: self.gy_full_scale_get().await?,
+            hg_xl_odr,
+            hg_xl_reg_out_en,
+            hg_xl_full_scale: self.hg_xl_full_scale_get().await?,
+            block_data_update: self.block_data_update_enable_get().await?,
+            timestamp: self.timestamp_enable_get().await?,
+            filt_gy_lp1: self.filt_gy_lp1_enable_get().await?,
+            filt_gy_lp1_bandwidth: self.filt_gy_lp1_bandwidth_get().await?,
+            filt_xl_lp2: self.filt_xl_lp2_enable_get().await?,
+            filt_xl_lp2_bandwidth: self.filt_xl_lp2_bandwidth_get().await?,
+            fifo_mode: self.fifo_mode_get().await?,
+            fifo_watermark: self.fifo_watermark_get().await?,
+            fifo_compress_algo: self.fifo_compress_algo_get().await?,
+            fifo_xl_batch: self.fifo_xl_batch_get().await?,
+            fifo_gy_batch: self.fifo_gy_batch_get().await?,
+            fifo_hg_xl_batch: self.fifo_hg_xl_batch_enable_get().await?,
+            fifo_temp_batch: self.fifo_temp_batch_get().await?,
+            fifo_timestamp_batch: self.fifo_timestamp_batch_get().await?,
+            fifo_sflp_batch: self.fifo_sflp_batch_get().await?,
+            fifo_stpcnt_batch: self.fifo_stpcnt_batch_enable_get().await?,
+            pin_int1_route: routing.int1,
+            pin_int2_route: routing.int2,
+            pin_int1_route_hg: routing.int1_hg,
+            pin_int2_route_hg: routing.int2_hg,
+            pin_int1_route_embedded: routing.int1_emb,
+            pin_int2_route_embedded: routing.int2_emb,
+        })
+    }
+
+    /// Program a snapshot of the device configuration.
+    ///
+    /// Sensors are put in power-down mode before changing the configuration
+    /// and turned on at the end, through `xl_setup`, `gy_setup` and
+    /// `hg_xl_setup`, so the same constraints are checked.
+    /// The FIFO is set in bypass mode while the batching is configured.
+    /// The interrupt routing is applied with `interrupt_routing_set`.
+    pub async fn device_config_set(&mut self, val: &DeviceConfig) -> Result<(), Error<B::Error>> {
+        /* 1. Set the low-g accelerometer, high-g accelerometer, and gyroscope in power-down mode */
+        self.hg_xl_setup(HgXlDataRate::Off, false).await?;
+        let xl_mode = self.xl_mode_get().await?;
+        self.xl_setup(DataRate::Off, xl_mode).await?;
+        let gy_mode = self.gy_mode_get().await?;
+        self.gy_setup(DataRate::Off, gy_mode).await?;
+
+        /* 2. Full scales, data update and filtering chain */
+        self.xl_full_scale_set(val.xl_full_scale).await?;
+        self.gy_full_scale_set(val.gy_full_scale).await?;
+        self.hg_xl_full_scale_set(val.hg_xl_full_scale).await?;
+        self.block_data_update_enable_set(val.block_data_update)
+            .await?;
+        self.timestamp_enable_set(val.timestamp).await?;
+        self.filt_gy_lp1_enable_set(val.filt_gy_lp1).await?;
+        self.filt_gy_lp1_bandwidth_set(val.filt_gy_lp1_bandwidth)
+            .await?;
+        self.filt_xl_lp2_enable_set(val.filt_xl_lp2).await?;
+        self.filt_xl_lp2_bandwidth_set(val.filt_xl_lp2_bandwidth)
+            .await?;
+
+        /* 3. FIFO configuration, starting from bypass mode */
+        self.fifo_mode_set(FifoMode::Bypass).await?;
+        self.fifo_watermark_set(val.fifo_watermark).await?;
+        self.fifo_compress_algo_set(val.fifo_compress_algo).await?;
+        self.fifo_xl_batch_set(val.fifo_xl_batch).await?;
+        self.fifo_gy_batch_set(val.fifo_gy_batch).await?;
+        self.fifo_hg_xl_batch_enable_set(val.fifo_hg_xl_batch)
+            .await?;
+        self.fifo_temp_batch_set(val.fifo_temp_batch).await?;
+        self.fifo_timestamp_batch_set(val.fifo_timestamp_batch)
+            .await?;
+        self.fifo_sflp_batch_set(val.fifo_sflp_batch.clone())
+            .await?;
+        self.fifo_stpcnt_batch_enable_set(val.fifo_stpcnt_batch)
+            .await?;
+        self.fifo_mode_set(val.fifo_mode).await?;
+
+        /* 4. Interrupt routing */
+        let mut routing = InterruptRouting::new();
+        routing.int1 = val.pin_int1_route;
+        routing.int2 = val.pin_int2_route;
+        routing.int1_hg = val.pin_int1_route_hg;
+        routing.int2_hg = val.pin_int2_route_hg;
+        routing.int1_emb = val.pin_int1_route_embedded;
+        routing.int2_emb = val.pin_int2_route_embedded;
+        self.interrupt_routing_set(&routing).await?;
+
+        /* 5. Turn on the sensors */
+        self.xl_setup(val.xl_odr, val.xl_mode).await?;
+        self.gy_setup(val.gy_odr, val.gy_mode).await?;
+        self.hg_xl_setup(val.hg_xl_odr, val.hg_xl_reg_out_en).await
+    }
+
+    /// Load a register configuration program (ST JSON `reg_config` / UCF).
+    ///
+    /// Operations are executed in order:
+    /// - `Write` writes the register of the selected memory bank; writes to
+    ///   FUNC_CFG_ACCESS (0x01) are tracked to follow bank switching done by
+    ///   the program itself.
+    /// - `MemBank` selects the memory bank.
+    /// - `PageWrite` writes the advanced pages through PAGE_RW, keeping the
+    ///   selected memory bank.
+    /// - `Delay` waits using the driver timer.
+    ///
+    /// At the end of the program the main memory bank is restored.
+    pub async fn load_reg_config<I>(&mut self, program: I) -> Result<(), Error<B::Error>>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<RegConfigOp>,
+    {
+        let mut bank = MemBank::MainMemBank;
+
+        for op in program {
+            match *op.borrow() {
+                RegConfigOp::Write { address, data } => {
+                    self.write_to_register(address, &[data]).await?;
+                    if address == Reg::FuncCfgAccess as u8 {
+                        bank = reg_config_mem_bank(data);
+                    }
+                }
+                RegConfigOp::Delay { ms } => self.tim.delay_ms(ms).await,
+                RegConfigOp::MemBank(val) => {
+                    self.mem_bank_set(val).await?;
+                    bank = val;
+                }
+                RegConfigOp::PageWrite { address, data } => {
+                    if bank != MemBank::MainMemBank {
+                        self.mem_bank_set(MemBank::MainMemBank).await?;
+                    }
+                    self.ln_pg_write(address, &[data], 1).await?;
+                    if bank != MemBank::MainMemBank {
+                        self.mem_bank_set(bank).await?;
+                    }
+                }
+            }
+        }
+
+        if bank != MemBank::MainMemBank {
+            self.mem_bank_set(MemBank::MainMemBank).await?;
+        }
+
+        Ok(())
+    }
+
     /// Set the accelerometer output data rate (ODR).
     ///
     /// When not set to Off it starts values reading.
@@ -849,13 +1053,13 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(val)
     }
 
-    /// Set the HG Accelerometer output data rate (ODR). If reg_out_en == 1 it enables the reading
+    /// Set the HG Accelerometer output data rate (ODR). If reg_out_en is true it enables the reading
     /// of HG accelerometer from registers: UiOutxLAOisHg to UiOutxHAOisHg.
     /// (using hg_acceleration_raw_get function)
-    pub async fn hg_xl_data_rate_set(
+    pub async fn hg_xl_setup(
         &mut self,
         val: HgXlDataRate,
-        reg_out_en: u8,
+        reg_out_en: bool,
     ) -> Result<(), Error<B::Error>> {
         let ctrl1 = Ctrl1::read(self).await?;
         let ctrl2 = Ctrl2::read(self).await?;
@@ -879,18 +1083,39 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         }
 
         ctrl1_xl_hg.set_odr_xl_hg((val as u8) & 0x07);
-        ctrl1_xl_hg.set_xl_hg_regout_en(reg_out_en & 0x01);
+        ctrl1_xl_hg.set_xl_hg_regout_en(reg_out_en as u8);
         ctrl1_xl_hg.write(self).await
     }
 
-    /// Get the Hg Accelerometer output data rate (ODR) selection.
-    pub async fn hg_xl_data_rate_get(&mut self) -> Result<(HgXlDataRate, u8), Error<B::Error>> {
+    /// Set the HG Accelerometer output data rate (ODR). If reg_out_en == 1 it enables the reading
+    /// of HG accelerometer from registers: UiOutxLAOisHg to UiOutxHAOisHg.
+    /// (using hg_acceleration_raw_get function)
+    #[deprecated(since = "2.1.0", note = "please use hg_xl_setup")]
+    pub async fn hg_xl_data_rate_set(
+        &mut self,
+        val: HgXlDataRate,
+        reg_out_en: u8,
+    ) -> Result<(), Error<B::Error>> {
+        self.hg_xl_setup(val, reg_out_en & 0x01 == 1).await
+    }
+
+    /// Get the Hg Accelerometer output data rate (ODR) selection and whether
+    /// the HG accelerometer output registers are enabled.
+    pub async fn hg_xl_setup_get(&mut self) -> Result<(HgXlDataRate, bool), Error<B::Error>> {
         let ctrl1_xl_hg = Ctrl1XlHg::read(self).await?;
-        let reg_out_en = ctrl1_xl_hg.xl_hg_regout_en();
+        let reg_out_en = ctrl1_xl_hg.xl_hg_regout_en() == 1;
         let odr_val = HgXlDataRate::try_from(ctrl1_xl_hg.odr_xl_hg()).unwrap_or_default();
         Ok((odr_val, reg_out_en))
     }
 
+    /// Get the Hg Accelerometer output data rate (ODR) selection.
+    #[deprecated(since = "2.1.0", note = "please use hg_xl_setup_get")]
+    pub async fn hg_xl_data_rate_get(&mut self) -> Result<(HgXlDataRate, u8), Error<B::Error>> {
+        self.hg_xl_setup_get()
+            .await
+            .map(|(odr, reg_out_en)| (odr, u8::from(reg_out_en)))
+    }
+
     /// Accelerometer operating mode selection.
     #[deprecated(note = "please use xl_setup function")]
     pub async fn xl_mode_set(&mut self, val: XlMode) -> Result<(), Error<B::Error>> {
@@ -954,34 +1179,58 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
 
     /// Enable/Disable the auto increment setting.
     ///
-    /// If val == 1 it Enable automatic increment of the register address during
+    /// If `val` is true it Enable automatic increment of the register address during
     /// multiple-byte access with a serial interface; enabled by default.
-    pub async fn auto_increment_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn auto_increment_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ctrl3 = Ctrl3::read(self).await?;
-        ctrl3.set_if_inc(val);
+        ctrl3.set_if_inc(val as u8);
         ctrl3.write(self).await
     }
 
+    /// Enable/Disable the auto increment setting.
+    #[deprecated(since = "2.1.0", note = "please use auto_increment_enable_set")]
+    pub async fn auto_increment_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.auto_increment_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the actual auto increment setting
     ///
     /// Register address automatically incremented during a multiple byte access
     /// with a serial interface (enable by default).
+    pub async fn auto_increment_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        Ctrl3::read(self).await.map(|ctlr3| ctlr3.if_inc() == 1)
+    }
+
+    /// Get the actual auto increment setting
+    #[deprecated(since = "2.1.0", note = "please use auto_increment_enable_get")]
     pub async fn auto_increment_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ctrl3::read(self).await.map(|ctlr3| ctlr3.if_inc())
+        self.auto_increment_enable_get().await.map(u8::from)
     }
 
     /// Enable/Disable Block Data Update (BDU)
     ///
     /// If active the output registers are not updated until LSB and MSB have been read.
-    pub async fn block_data_update_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn block_data_update_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ctrl3 = Ctrl3::read(self).await?;
-        ctrl3.set_bdu(val);
+        ctrl3.set_bdu(val as u8);
         ctrl3.write(self).await
     }
 
+    /// Enable/Disable Block Data Update (BDU)
+    #[deprecated(since = "2.1.0", note = "please use block_data_update_enable_set")]
+    pub async fn block_data_update_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.block_data_update_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get actual settings of Block Data Update (BDU)
+    pub async fn block_data_update_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        Ctrl3::read(self).await.map(|ctrl3| ctrl3.bdu() == 1)
+    }
+
+    /// Get actual settings of Block Data Update (BDU)
+    #[deprecated(since = "2.1.0", note = "please use block_data_update_enable_get")]
     pub async fn block_data_update_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ctrl3::read(self).await.map(|ctrl3| ctrl3.bdu())
+        self.block_data_update_enable_get().await.map(u8::from)
     }
 
     /// Configure ODR trigger.
@@ -1025,34 +1274,58 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enables/disable interrupt and switch between latched/pulsed interrupts
-    pub async fn interrupt_enable_set(
+    pub async fn interrupt_config_set(
         &mut self,
-        val: InterruptMode,
+        val: InterruptConfig,
     ) -> Result<(), Error<B::Error>> {
         let mut func = FunctionsEnable::read(self).await?;
-        func.set_interrupts_enable(val.enable);
+        func.set_interrupts_enable(val.enable as u8);
         func.write(self).await?;
 
         let mut tap_cfg = TapCfg0::read(self).await?;
-        tap_cfg.set_lir(val.lir);
+        tap_cfg.set_lir(val.latched as u8);
         tap_cfg.write(self).await
     }
 
+    /// Enables/disable interrupt and switch between latched/pulsed interrupts
+    #[deprecated(since = "2.1.0", note = "please use interrupt_config_set")]
+    pub async fn interrupt_enable_set(
+        &mut self,
+        val: InterruptMode,
+    ) -> Result<(), Error<B::Error>> {
+        self.interrupt_config_set(InterruptConfig {
+            enable: val.enable & 0x01 == 1,
+            latched: val.lir & 0x01 == 1,
+        })
+        .await
+    }
+
     /// Get the interrupt Mode
     ///
     /// Enable/disabled and latched/pulsed information.
-    pub async fn interrupt_enable_get(&mut self) -> Result<InterruptMode, Error<B::Error>> {
+    pub async fn interrupt_config_get(&mut self) -> Result<InterruptConfig, Error<B::Error>> {
         let func = FunctionsEnable::read(self).await?;
         let cfg = TapCfg0::read(self).await?;
 
-        let val = InterruptMode {
-            enable: func.interrupts_enable(),
-            lir: cfg.lir(),
+        let val = InterruptConfig {
+            enable: func.interrupts_enable() == 1,
+            latched: cfg.lir() == 1,
         };
 
         Ok(val)
     }
 
+    /// Get the interrupt Mode
+    #[deprecated(since = "2.1.0", note = "please use interrupt_config_get")]
+    pub async fn interrupt_enable_get(&mut self) -> Result<InterruptMode, Error<B::Error>> {
+        let val = self.interrupt_config_get().await?;
+
+        Ok(InterruptMode {
+            enable: val.enable as u8,
+            lir: val.latched as u8,
+        })
+    }
+
     /// Set the Gyroscope full-scale.
     pub async fn gy_full_scale_set(&mut self, val: GyFullScale) -> Result<(), Error<B::Error>> {
         let mut ctrl6 = Ctrl6::read(self).await?;
@@ -1164,6 +1437,222 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(val)
     }
 
+    /// Run the Accelerometer self-test procedure.
+    ///
+    /// The accelerometer is set at 60 Hz, ±8 g. The output change with positive
+    /// and negative stimulus is checked against XL_SELF_TEST_MIN_MG and
+    /// XL_SELF_TEST_MAX_MG. The previous configuration is restored at the end.
+    pub async fn xl_self_test_run(&mut self) -> Result<SelfTestReport, Error<B::Error>> {
+        let odr = self.xl_data_rate_get().await?;
+        let mode = self.xl_mode_get().await?;
+        let fs = self.xl_full_scale_get().await?;
+        let st = self.xl_self_test_get().await?;
+
+        /* 1. Set full scale and data rate for self-test */
+        self.xl_full_scale_set(XlFullScale::_8g).await?;
+        let report = match self
+            .xl_setup(DataRate::_60hz, XlMode::HighPerformance)
+            .await
+        {
+            Ok(()) => {
+                self.self_test_sequence(
+                    SelfTestSensor::Xl,
+                    XL_SELF_TEST_MIN_MG,
+                    XL_SELF_TEST_MAX_MG,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        /* Restore previous configuration */
+        self.xl_setup(DataRate::Off, mode).await?;
+        self.xl_self_test_set(st).await?;
+        self.xl_full_scale_set(fs).await?;
+        self.xl_setup(odr, mode).await?;
+
+        report
+    }
+
+    /// Run the Gyroscope self-test procedure.
+    ///
+    /// The gyroscope is set at 240 Hz, ±2000 dps. The output change with positive
+    /// and negative stimulus is checked against GY_SELF_TEST_MIN_MDPS and
+    /// GY_SELF_TEST_MAX_MDPS. The previous configuration is restored at the end.
+    pub async fn gy_self_test_run(&mut self) -> Result<SelfTestReport, Error<B::Error>> {
+        let odr = self.gy_data_rate_get().await?;
+        let mode = self.gy_mode_get().await?;
+        let fs = self.gy_full_scale_get().await?;
+        let st = self.gy_self_test_get().await?;
+
+        /* 1. Set full scale and data rate for self-test */
+        self.gy_full_scale_set(GyFullScale::_2000dps).await?;
+        let report = match self
+            .gy_setup(DataRate::_240hz, GyMode::HighPerformance)
+            .await
+        {
+            Ok(()) => {
+                self.self_test_sequence(
+                    SelfTestSensor::Gy,
+                    GY_SELF_TEST_MIN_MDPS,
+                    GY_SELF_TEST_MAX_MDPS,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        /* Restore previous configuration */
+        self.gy_setup(DataRate::Off, mode).await?;
+        self.gy_self_test_set(st).await?;
+        self.gy_full_scale_set(fs).await?;
+        self.gy_setup(odr, mode).await?;
+
+        report
+    }
+
+    /// Run the High-G Accelerometer self-test procedure.
+    ///
+    /// The high-g accelerometer is set at 480 Hz, ±32 g. The output change with
+    /// positive and negative stimulus is checked against HG_XL_SELF_TEST_MIN_MG
+    /// and HG_XL_SELF_TEST_MAX_MG. The previous configuration is restored at the end.
+    pub async fn hg_xl_self_test_run(&mut self) -> Result<SelfTestReport, Error<B::Error>> {
+        let (odr, reg_out_en) = self.hg_xl_setup_get().await?;
+        let fs = self.hg_xl_full_scale_get().await?;
+        let st = self.hg_xl_self_test_get().await?;
+
+        /* 1. Set full scale and data rate for self-test */
+        self.hg_xl_full_scale_set(HgXlFullScale::_32g).await?;
+        let report = match self.hg_xl_setup(HgXlDataRate::_480hz, true).await {
+            Ok(()) => {
+                self.self_test_sequence(
+                    SelfTestSensor::HgXl,
+                    HG_XL_SELF_TEST_MIN_MG,
+                    HG_XL_SELF_TEST_MAX_MG,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        /* Restore previous configuration */
+        self.hg_xl_setup(HgXlDataRate::Off, false).await?;
+        self.hg_xl_self_test_set(st).await?;
+        self.hg_xl_full_scale_set(fs).await?;
+        self.hg_xl_setup(odr, reg_out_en).await?;
+
+        report
+    }
+
+    async fn self_test_sequence(
+        &mut self,
+        sensor: SelfTestSensor,
+        min: f32,
+        max: f32,
+    ) -> Result<SelfTestReport, Error<B::Error>> {
+        let mut report = SelfTestReport::default();
+
+        /* 2. Wait for stable output and average samples with self-test off */
+        self.self_test_mode_set(sensor, SelfTest::Disable).await?;
+        self.tim.delay_ms(100).await;
+        let out_nost = self.self_test_average_get(sensor).await?;
+
+        /* 3. Apply positive and negative stimulus and average samples */
+        for sign in [SelfTest::Positive, SelfTest::Negative] {
+            self.self_test_mode_set(sensor, sign).await?;
+            self.tim.delay_ms(100).await;
+            let out_st = self.self_test_average_get(sensor).await?;
+
+            let delta = match sign {
+                SelfTest::Positive => &mut report.positive,
+                _ => &mut report.negative,
+            };
+            for ((delta, st), nost) in delta.iter_mut().zip(out_st).zip(out_nost) {
+                *delta = (st - nost).abs();
+            }
+        }
+
+        /* 4. Disable self-test */
+        self.self_test_mode_set(sensor, SelfTest::Disable).await?;
+        self.tim.delay_ms(100).await;
+
+        /* 5. Check output changes against limits */
+        for ((pass, pos), neg) in report
+            .pass
+            .iter_mut()
+            .zip(report.positive)
+            .zip(report.negative)
+        {
+            *pass = (min..=max).contains(&pos) && (min..=max).contains(&neg);
+        }
+
+        Ok(report)
+    }
+
+    async fn self_test_mode_set(
+        &mut self,
+        sensor: SelfTestSensor,
+        val: SelfTest,
+    ) -> Result<(), Error<B::Error>> {
+        match sensor {
+            SelfTestSensor::Xl => self.xl_self_test_set(val).await,
+            SelfTestSensor::Gy => self.gy_self_test_set(val).await,
+            SelfTestSensor::HgXl => self.hg_xl_self_test_set(val).await,
+        }
+    }
+
+    async fn self_test_average_get(
+        &mut self,
+        sensor: SelfTestSensor,
+    ) -> Result<[f32; 3], Error<B::Error>> {
+        let mut sum = [0f32; 3];
+
+        // first sample after a stimulus change is discarded
+        for n in 0..=SELF_TEST_SAMPLES {
+            let mut retry: u8 = 0;
+            loop {
+                let drdy = self.data_ready_get().await?;
+                let ready = match sensor {
+                    SelfTestSensor::Xl => drdy.contains(DataReadyFlags::DRDY_XL),
+                    SelfTestSensor::Gy => drdy.contains(DataReadyFlags::DRDY_GY),
+                    SelfTestSensor::HgXl => drdy.contains(DataReadyFlags::DRDY_HGXL),
+                };
+                if ready {
+                    break;
+                }
+
+                retry += 1;
+                if retry > 200 {
+                    return Err(Error::HwNoResponse);
+                }
+                self.tim.delay_ms(1).await;
+            }
+
+            let val = match sensor {
+                SelfTestSensor::Xl => self
+                    .acceleration_raw_get()
+                    .await?
+                    .map(|lsb| XlFullScale::_8g.to_mg(lsb)),
+                SelfTestSensor::Gy => self
+                    .angular_rate_raw_get()
+                    .await?
+                    .map(|lsb| GyFullScale::_2000dps.to_mdps(lsb)),
+                SelfTestSensor::HgXl => self
+                    .hg_acceleration_raw_get()
+                    .await?
+                    .map(|lsb| HgXlFullScale::_32g.to_mg(lsb)),
+            };
+
+            if n > 0 {
+                for (sum, val) in sum.iter_mut().zip(val) {
+                    *sum += val;
+                }
+            }
+        }
+
+        Ok(sum.map(|s| s / SELF_TEST_SAMPLES as f32))
+    }
+
     /// Set the IF2 Accelerometer self-test.
     pub async fn ois_xl_self_test_set(&mut self, val: SelfTest) -> Result<(), Error<B::Error>> {
         let mut if2_int_ois = If2IntOis::read(self).await?;
@@ -1241,282 +1730,400 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         })
     }
 
+    /// Set the High-g interrupt generation.
+    pub async fn hg_interrupt_config_set(
+        &mut self,
+        val: HgInterruptConfig,
+    ) -> Result<(), Error<B::Error>> {
+        let mut hg_func = HgFunctionsEnable::read(self).await?;
+        hg_func.set_hg_interrupts_enable(val.enable as u8);
+        hg_func.set_hg_wu_change_int_sel(val.wake_up_change as u8);
+        hg_func.write(self).await
+    }
+
     /// Set the High-g wake-up interrupt.
+    #[deprecated(since = "2.1.0", note = "please use hg_interrupt_config_set")]
     pub async fn hg_wu_interrupt_cfg_set(
         &mut self,
         val: HgWuInterruptCfg,
     ) -> Result<(), Error<B::Error>> {
-        let mut hg_func = HgFunctionsEnable::read(self).await?;
-        hg_func.set_hg_interrupts_enable(val.hg_interrupts_enable);
-        hg_func.set_hg_wu_change_int_sel(val.hg_wakeup_int_sel);
-        hg_func.write(self).await
+        self.hg_interrupt_config_set(HgInterruptConfig::from(&val))
+            .await
     }
 
-    /// Get the actual High-g wake-up interrupt.
-    pub async fn hg_wu_interrupt_cfg_get(&mut self) -> Result<HgWuInterruptCfg, Error<B::Error>> {
+    /// Get the actual High-g interrupt generation.
+    pub async fn hg_interrupt_config_get(&mut self) -> Result<HgInterruptConfig, Error<B::Error>> {
         let hg_func = HgFunctionsEnable::read(self).await?;
 
-        let val = HgWuInterruptCfg {
-            hg_interrupts_enable: hg_func.hg_interrupts_enable(),
-            hg_wakeup_int_sel: hg_func.hg_wu_change_int_sel(),
+        let val = HgInterruptConfig {
+            enable: hg_func.hg_interrupts_enable() == 1,
+            wake_up_change: hg_func.hg_wu_change_int_sel() == 1,
         };
 
         Ok(val)
     }
 
+    /// Get the actual High-g wake-up interrupt.
+    #[deprecated(since = "2.1.0", note = "please use hg_interrupt_config_get")]
+    pub async fn hg_wu_interrupt_cfg_get(&mut self) -> Result<HgWuInterruptCfg, Error<B::Error>> {
+        let val = self.hg_interrupt_config_get().await?;
+
+        Ok(HgWuInterruptCfg {
+            hg_interrupts_enable: val.enable as u8,
+            hg_wakeup_int_sel: val.wake_up_change as u8,
+        })
+    }
+
     /// Enable/disable user offset data correction driving to hg embedded functions.
-    pub async fn hg_emb_usr_off_correction_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn hg_emb_usr_off_correction_enable_set(
+        &mut self,
+        val: bool,
+    ) -> Result<(), Error<B::Error>> {
         let mut reg = EmbFuncCfg::read(self).await?;
-        reg.set_hg_usr_off_on_emb_func(val & 0x1);
+        reg.set_hg_usr_off_on_emb_func(val as u8);
         reg.write(self).await
     }
 
+    /// Enable/disable user offset data correction driving to hg embedded functions.
+    #[deprecated(
+        since = "2.1.0",
+        note = "please use hg_emb_usr_off_correction_enable_set"
+    )]
+    pub async fn hg_emb_usr_off_correction_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.hg_emb_usr_off_correction_enable_set(val & 0x01 == 1)
+            .await
+    }
+
     /// Get the actual user offset data correction driving to hg embedded functions.
-    pub async fn hg_emb_usr_off_correction_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn hg_emb_usr_off_correction_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         EmbFuncCfg::read(self)
             .await
-            .map(|cfg| cfg.hg_usr_off_on_emb_func())
+            .map(|cfg| cfg.hg_usr_off_on_emb_func() == 1)
+    }
+
+    /// Get the actual user offset data correction driving to hg embedded functions.
+    #[deprecated(
+        since = "2.1.0",
+        note = "please use hg_emb_usr_off_correction_enable_get"
+    )]
+    pub async fn hg_emb_usr_off_correction_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.hg_emb_usr_off_correction_enable_get()
+            .await
+            .map(u8::from)
     }
 
     /// Enable/disable user offset data correction driving to hg wake-up.
-    pub async fn hg_wu_usr_off_correction_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn hg_wu_usr_off_correction_enable_set(
+        &mut self,
+        val: bool,
+    ) -> Result<(), Error<B::Error>> {
         let mut ctrl2_xl_hg = Ctrl2XlHg::read(self).await?;
-        ctrl2_xl_hg.set_hg_usr_off_on_wu(val & 0x1);
+        ctrl2_xl_hg.set_hg_usr_off_on_wu(val as u8);
         ctrl2_xl_hg.write(self).await
     }
 
+    /// Enable/disable user offset data correction driving to hg wake-up.
+    #[deprecated(
+        since = "2.1.0",
+        note = "please use hg_wu_usr_off_correction_enable_set"
+    )]
+    pub async fn hg_wu_usr_off_correction_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.hg_wu_usr_off_correction_enable_set(val & 0x01 == 1)
+            .await
+    }
+
     /// Get the actual user offset data correction driving to hg wake-up
-    pub async fn hg_wu_usr_off_correction_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn hg_wu_usr_off_correction_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         Ctrl2XlHg::read(self)
             .await
-            .map(|reg| reg.hg_usr_off_on_wu())
+            .map(|reg| reg.hg_usr_off_on_wu() == 1)
+    }
+
+    /// Get the actual user offset data correction driving to hg wake-up
+    #[deprecated(
+        since = "2.1.0",
+        note = "please use hg_wu_usr_off_correction_enable_get"
+    )]
+    pub async fn hg_wu_usr_off_correction_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.hg_wu_usr_off_correction_enable_get()
+            .await
+            .map(u8::from)
     }
 
     /// Get the High-g all event status
     ///
     /// Event includes: Wakeup (on 3 axis), wakeup change, shock, shock change.
-    pub async fn hg_event_get(&mut self) -> Result<HgEvent, Error<B::Error>> {
+    pub async fn hg_event_status_get(&mut self) -> Result<HgEventStatus, Error<B::Error>> {
         let int_src = AllIntSrc::read(self).await?;
         let wup_src = HgWakeUpSrc::read(self).await?;
 
-        let hg_event = HgEvent {
-            hg_event: int_src.hg_ia(),
-            hg_wakeup_z: wup_src.hg_z_wu(),
-            hg_wakeup_y: wup_src.hg_y_wu(),
-            hg_wakeup_x: wup_src.hg_x_wu(),
-            hg_wakeup: wup_src.hg_wu_ia(),
-            hg_wakeup_chg: wup_src.hg_wu_change_ia(),
-            hg_shock: wup_src.hg_shock_state(),
-            hg_shock_change: wup_src.hg_wu_change_ia(),
+        let val = HgEventStatus {
+            event: int_src.hg_ia() == 1,
+            wake_up: wup_src.hg_wu_ia() == 1,
+            wake_up_axes: Axes {
+                x: wup_src.hg_x_wu() == 1,
+                y: wup_src.hg_y_wu() == 1,
+                z: wup_src.hg_z_wu() == 1,
+            },
+            wake_up_change: wup_src.hg_wu_change_ia() == 1,
+            shock: wup_src.hg_shock_state() == 1,
+            shock_change: wup_src.hg_shock_change_ia() == 1,
         };
-        Ok(hg_event)
+        Ok(val)
+    }
+
+    /// Get the High-g all event status
+    ///
+    /// Event includes: Wakeup (on 3 axis), wakeup change, shock, shock change.
+    #[deprecated(since = "2.1.0", note = "please use hg_event_status_get")]
+    pub async fn hg_event_get(&mut self) -> Result<HgEvent, Error<B::Error>> {
+        self.hg_event_status_get().await.map(HgEvent::from)
     }
 
     /// Set the signals that need to be routed on int1 pad.
     ///
-    /// See `PinInt1Route` for a complete list of available events.
-    pub async fn pin_int1_route_set(&mut self, val: &PinInt1Route) -> Result<(), Error<B::Error>> {
+    /// See `Int1Route` for a complete list of available events.
+    pub async fn int1_route_set(&mut self, val: Int1Route) -> Result<(), Error<B::Error>> {
         let mut int1_ctrl = Int1Ctrl::read(self).await?;
-        int1_ctrl.set_int1_drdy_xl(val.drdy_xl);
-        int1_ctrl.set_int1_drdy_g(val.drdy_g);
-        int1_ctrl.set_int1_fifo_th(val.fifo_th);
-        int1_ctrl.set_int1_fifo_ovr(val.fifo_ovr);
-        int1_ctrl.set_int1_fifo_full(val.fifo_full);
-        int1_ctrl.set_int1_cnt_bdr(val.cnt_bdr);
+        int1_ctrl.set_int1_drdy_xl(val.bit(Int1Route::DRDY_XL));
+        int1_ctrl.set_int1_drdy_g(val.bit(Int1Route::DRDY_G));
+        int1_ctrl.set_int1_fifo_th(val.bit(Int1Route::FIFO_TH));
+        int1_ctrl.set_int1_fifo_ovr(val.bit(Int1Route::FIFO_OVR));
+        int1_ctrl.set_int1_fifo_full(val.bit(Int1Route::FIFO_FULL));
+        int1_ctrl.set_int1_cnt_bdr(val.bit(Int1Route::CNT_BDR));
         int1_ctrl.write(self).await?;
 
         let mut md1_cfg = Md1Cfg::read(self).await?;
-        md1_cfg.set_int1_shub(val.shub);
-        md1_cfg.set_int1_6d(val.sixd);
-        md1_cfg.set_int1_single_tap(val.single_tap);
-        md1_cfg.set_int1_double_tap(val.double_tap);
-        md1_cfg.set_int1_wu(val.wakeup);
-        md1_cfg.set_int1_ff(val.freefall);
-        md1_cfg.set_int1_sleep_change(val.sleep_change);
+        md1_cfg.set_int1_shub(val.bit(Int1Route::SHUB));
+        md1_cfg.set_int1_6d(val.bit(Int1Route::SIXD));
+        md1_cfg.set_int1_single_tap(val.bit(Int1Route::SINGLE_TAP));
+        md1_cfg.set_int1_double_tap(val.bit(Int1Route::DOUBLE_TAP));
+        md1_cfg.set_int1_wu(val.bit(Int1Route::WAKEUP));
+        md1_cfg.set_int1_ff(val.bit(Int1Route::FREEFALL));
+        md1_cfg.set_int1_sleep_change(val.bit(Int1Route::SLEEP_CHANGE));
         md1_cfg.write(self).await?;
 
         Ok(())
     }
 
+    /// Set the signals that need to be routed on int1 pad.
+    #[deprecated(since = "2.1.0", note = "please use int1_route_set")]
+    pub async fn pin_int1_route_set(&mut self, val: &PinInt1Route) -> Result<(), Error<B::Error>> {
+        self.int1_route_set(Int1Route::from(val)).await
+    }
+
     /// Report the signals that are routed on int1 pad.
-    /// Values returned are:
-    ///     - drdy_xl
-    ///     - drdy_g
-    ///     - fifo_th
-    ///     - cnt_bdr
-    ///     - emb_func_endop
-    ///     - timestamp
-    ///     - single_tap
-    ///     - double_tap
-    ///     - wakeup
-    ///     - freefall
-    ///     - sleep_change
-    ///
-    /// The remaining are leaved default
-    pub async fn pin_int1_route_get(&mut self) -> Result<PinInt1Route, Error<B::Error>> {
+    pub async fn int1_route_get(&mut self) -> Result<Int1Route, Error<B::Error>> {
         let int1_ctrl = Int1Ctrl::read(self).await?;
         let md1_cfg = Md1Cfg::read(self).await?;
 
-        let val = PinInt1Route {
-            drdy_xl: int1_ctrl.int1_drdy_xl(),
-            drdy_g: int1_ctrl.int1_drdy_g(),
-            fifo_th: int1_ctrl.int1_fifo_th(),
-            fifo_ovr: int1_ctrl.int1_fifo_ovr(),
-            fifo_full: int1_ctrl.int1_fifo_full(),
-            cnt_bdr: int1_ctrl.int1_cnt_bdr(),
-            shub: md1_cfg.int1_shub(),
-            sixd: md1_cfg.int1_6d(),
-            single_tap: md1_cfg.int1_single_tap(),
-            double_tap: md1_cfg.int1_double_tap(),
-            wakeup: md1_cfg.int1_wu(),
-            freefall: md1_cfg.int1_ff(),
-            sleep_change: md1_cfg.int1_sleep_change(),
-        };
+        let mut val = Int1Route::empty();
+        val.set(Int1Route::DRDY_XL, int1_ctrl.int1_drdy_xl() == 1);
+        val.set(Int1Route::DRDY_G, int1_ctrl.int1_drdy_g() == 1);
+        val.set(Int1Route::FIFO_TH, int1_ctrl.int1_fifo_th() == 1);
+        val.set(Int1Route::FIFO_OVR, int1_ctrl.int1_fifo_ovr() == 1);
+        val.set(Int1Route::FIFO_FULL, int1_ctrl.int1_fifo_full() == 1);
+        val.set(Int1Route::CNT_BDR, int1_ctrl.int1_cnt_bdr() == 1);
+        val.set(Int1Route::SHUB, md1_cfg.int1_shub() == 1);
+        val.set(Int1Route::SIXD, md1_cfg.int1_6d() == 1);
+        val.set(Int1Route::SINGLE_TAP, md1_cfg.int1_single_tap() == 1);
+        val.set(Int1Route::DOUBLE_TAP, md1_cfg.int1_double_tap() == 1);
+        val.set(Int1Route::WAKEUP, md1_cfg.int1_wu() == 1);
+        val.set(Int1Route::FREEFALL, md1_cfg.int1_ff() == 1);
+        val.set(Int1Route::SLEEP_CHANGE, md1_cfg.int1_sleep_change() == 1);
 
         Ok(val)
     }
 
+    /// Report the signals that are routed on int1 pad.
+    #[deprecated(since = "2.1.0", note = "please use int1_route_get")]
+    pub async fn pin_int1_route_get(&mut self) -> Result<PinInt1Route, Error<B::Error>> {
+        self.int1_route_get().await.map(PinInt1Route::from)
+    }
+
     /// Set the signals that need to be routed on int2 pad.
-    pub async fn pin_int2_route_set(&mut self, val: &PinInt2Route) -> Result<(), Error<B::Error>> {
+    pub async fn int2_route_set(&mut self, val: Int2Route) -> Result<(), Error<B::Error>> {
         let mut int2_ctrl = Int2Ctrl::read(self).await?;
 
-        int2_ctrl.set_int2_drdy_xl(val.drdy_xl);
-        int2_ctrl.set_int2_drdy_g(val.drdy_g);
-        int2_ctrl.set_int2_fifo_th(val.fifo_th);
-        int2_ctrl.set_int2_fifo_ovr(val.fifo_ovr);
-        int2_ctrl.set_int2_fifo_full(val.fifo_full);
-        int2_ctrl.set_int2_cnt_bdr(val.cnt_bdr);
-        int2_ctrl.set_int2_drdy_g_eis(val.drdy_g_eis);
-        int2_ctrl.set_int2_emb_func_endop(val.emb_func_endop);
+        int2_ctrl.set_int2_drdy_xl(val.bit(Int2Route::DRDY_XL));
+        int2_ctrl.set_int2_drdy_g(val.bit(Int2Route::DRDY_G));
+        int2_ctrl.set_int2_fifo_th(val.bit(Int2Route::FIFO_TH));
+        int2_ctrl.set_int2_fifo_ovr(val.bit(Int2Route::FIFO_OVR));
+        int2_ctrl.set_int2_fifo_full(val.bit(Int2Route::FIFO_FULL));
+        int2_ctrl.set_int2_cnt_bdr(val.bit(Int2Route::CNT_BDR));
+        int2_ctrl.set_int2_drdy_g_eis(val.bit(Int2Route::DRDY_G_EIS));
+        int2_ctrl.set_int2_emb_func_endop(val.bit(Int2Route::EMB_FUNC_ENDOP));
 
         int2_ctrl.write(self).await?;
 
         let mut ctrl4 = Ctrl4::read(self).await?;
-        ctrl4.set_int2_drdy_temp(val.drdy_temp);
+        ctrl4.set_int2_drdy_temp(val.bit(Int2Route::DRDY_TEMP));
         ctrl4.write(self).await?;
 
         let mut md2_cfg = Md2Cfg::read(self).await?;
-        md2_cfg.set_int2_timestamp(val.timestamp);
-        md2_cfg.set_int2_6d(val.sixd);
-        md2_cfg.set_int2_single_tap(val.single_tap);
-        md2_cfg.set_int2_double_tap(val.double_tap);
-        md2_cfg.set_int2_wu(val.wakeup);
-        md2_cfg.set_int2_ff(val.freefall);
-        md2_cfg.set_int2_sleep_change(val.sleep_change);
+        md2_cfg.set_int2_timestamp(val.bit(Int2Route::TIMESTAMP));
+        md2_cfg.set_int2_6d(val.bit(Int2Route::SIXD));
+        md2_cfg.set_int2_single_tap(val.bit(Int2Route::SINGLE_TAP));
+        md2_cfg.set_int2_double_tap(val.bit(Int2Route::DOUBLE_TAP));
+        md2_cfg.set_int2_wu(val.bit(Int2Route::WAKEUP));
+        md2_cfg.set_int2_ff(val.bit(Int2Route::FREEFALL));
+        md2_cfg.set_int2_sleep_change(val.bit(Int2Route::SLEEP_CHANGE));
         md2_cfg.write(self).await?;
 
         Ok(())
     }
 
+    /// Set the signals that need to be routed on int2 pad.
+    #[deprecated(since = "2.1.0", note = "please use int2_route_set")]
+    pub async fn pin_int2_route_set(&mut self, val: &PinInt2Route) -> Result<(), Error<B::Error>> {
+        self.int2_route_set(Int2Route::from(val)).await
+    }
+
     /// Report the signals that are routed on int2 pad.
-    pub async fn pin_int2_route_get(&mut self) -> Result<PinInt2Route, Error<B::Error>> {
+    pub async fn int2_route_get(&mut self) -> Result<Int2Route, Error<B::Error>> {
         let int2_ctrl = Int2Ctrl::read(self).await?;
         let ctrl4 = Ctrl4::read(self).await?;
         let md2_cfg = Md2Cfg::read(self).await?;
 
-        let route = PinInt2Route {
-            drdy_xl: int2_ctrl.int2_drdy_xl(),
-            drdy_g: int2_ctrl.int2_drdy_g(),
-            fifo_th: int2_ctrl.int2_fifo_th(),
-            fifo_ovr: int2_ctrl.int2_fifo_ovr(),
-            fifo_full: int2_ctrl.int2_fifo_full(),
-            cnt_bdr: int2_ctrl.int2_cnt_bdr(),
-            drdy_g_eis: int2_ctrl.int2_drdy_g_eis(),
-            emb_func_endop: int2_ctrl.int2_emb_func_endop(),
-            timestamp: md2_cfg.int2_timestamp(),
-            sixd: md2_cfg.int2_6d(),
-            single_tap: md2_cfg.int2_single_tap(),
-            double_tap: md2_cfg.int2_double_tap(),
-            wakeup: md2_cfg.int2_wu(),
-            freefall: md2_cfg.int2_ff(),
-            sleep_change: md2_cfg.int2_sleep_change(),
-            drdy_temp: ctrl4.int2_drdy_temp(),
-        };
+        let mut route = Int2Route::empty();
+        route.set(Int2Route::DRDY_XL, int2_ctrl.int2_drdy_xl() == 1);
+        route.set(Int2Route::DRDY_G, int2_ctrl.int2_drdy_g() == 1);
+        route.set(Int2Route::FIFO_TH, int2_ctrl.int2_fifo_th() == 1);
+        route.set(Int2Route::FIFO_OVR, int2_ctrl.int2_fifo_ovr() == 1);
+        route.set(Int2Route::FIFO_FULL, int2_ctrl.int2_fifo_full() == 1);
+        route.set(Int2Route::CNT_BDR, int2_ctrl.int2_cnt_bdr() == 1);
+        route.set(Int2Route::DRDY_G_EIS, int2_ctrl.int2_drdy_g_eis() == 1);
+        route.set(
+            Int2Route::EMB_FUNC_ENDOP,
+            int2_ctrl.int2_emb_func_endop() == 1,
+        );
+        route.set(Int2Route::TIMESTAMP, md2_cfg.int2_timestamp() == 1);
+        route.set(Int2Route::SIXD, md2_cfg.int2_6d() == 1);
+        route.set(Int2Route::SINGLE_TAP, md2_cfg.int2_single_tap() == 1);
+        route.set(Int2Route::DOUBLE_TAP, md2_cfg.int2_double_tap() == 1);
+        route.set(Int2Route::WAKEUP, md2_cfg.int2_wu() == 1);
+        route.set(Int2Route::FREEFALL, md2_cfg.int2_ff() == 1);
+        route.set(Int2Route::SLEEP_CHANGE, md2_cfg.int2_sleep_change() == 1);
+        route.set(Int2Route::DRDY_TEMP, ctrl4.int2_drdy_temp() == 1);
 
         Ok(route)
     }
 
+    /// Report the signals that are routed on int2 pad.
+    #[deprecated(since = "2.1.0", note = "please use int2_route_get")]
+    pub async fn pin_int2_route_get(&mut self) -> Result<PinInt2Route, Error<B::Error>> {
+        self.int2_route_get().await.map(PinInt2Route::from)
+    }
+
     /// Select the signals that need to be routed on int1 pad. (hg part)
-    pub async fn pin_int1_route_hg_set(
-        &mut self,
-        val: &PinIntRouteHg,
-    ) -> Result<(), Error<B::Error>> {
+    pub async fn int1_route_hg_set(&mut self, val: IntRouteHg) -> Result<(), Error<B::Error>> {
         let mut ctrl7 = Ctrl7::read(self).await?;
-        ctrl7.set_int1_drdy_xl_hg(val.drdy_hg_xl);
+        ctrl7.set_int1_drdy_xl_hg(val.bit(IntRouteHg::DRDY_HG_XL));
         ctrl7.write(self).await?;
 
         let mut hg_func = HgFunctionsEnable::read(self).await?;
-        hg_func.set_int1_hg_wu(val.hg_wakeup);
+        hg_func.set_int1_hg_wu(val.bit(IntRouteHg::HG_WAKEUP));
         hg_func.write(self).await?;
 
         let mut reg_shock = InactivityThs::read(self).await?;
-        reg_shock.set_int1_hg_shock_change(val.hg_shock_change);
+        reg_shock.set_int1_hg_shock_change(val.bit(IntRouteHg::HG_SHOCK_CHANGE));
         reg_shock.write(self).await?;
 
         Ok(())
     }
 
+    /// Select the signals that need to be routed on int1 pad. (hg part)
+    #[deprecated(since = "2.1.0", note = "please use int1_route_hg_set")]
+    pub async fn pin_int1_route_hg_set(
+        &mut self,
+        val: &PinIntRouteHg,
+    ) -> Result<(), Error<B::Error>> {
+        self.int1_route_hg_set(IntRouteHg::from(val)).await
+    }
+
     /// Report the signals that are routed on int1 pad for hg part.
     /// Fields get are:
     ///     - drdy_hg_xl
     ///     - hg_wakeup
     ///     - hg_shock_change
-    pub async fn pin_int1_route_hg_get(&mut self) -> Result<PinIntRouteHg, Error<B::Error>> {
+    pub async fn int1_route_hg_get(&mut self) -> Result<IntRouteHg, Error<B::Error>> {
         let ctrl7 = Ctrl7::read(self).await?;
         let hg_func = HgFunctionsEnable::read(self).await?;
         let reg_shock = InactivityThs::read(self).await?;
 
-        let val = PinIntRouteHg {
-            drdy_hg_xl: ctrl7.int1_drdy_xl_hg(),
-            hg_shock_change: reg_shock.int1_hg_shock_change(),
-            hg_wakeup: hg_func.int1_hg_wu(),
-        };
+        let mut val = IntRouteHg::empty();
+        val.set(IntRouteHg::DRDY_HG_XL, ctrl7.int1_drdy_xl_hg() == 1);
+        val.set(
+            IntRouteHg::HG_SHOCK_CHANGE,
+            reg_shock.int1_hg_shock_change() == 1,
+        );
+        val.set(IntRouteHg::HG_WAKEUP, hg_func.int1_hg_wu() == 1);
 
         Ok(val)
     }
 
+    /// Report the signals that are routed on int1 pad for hg part.
+    #[deprecated(since = "2.1.0", note = "please use int1_route_hg_get")]
+    pub async fn pin_int1_route_hg_get(&mut self) -> Result<PinIntRouteHg, Error<B::Error>> {
+        self.int1_route_hg_get().await.map(PinIntRouteHg::from)
+    }
+
     /// Select the signals that need to be routed on int2 pad. (hg part)
-    pub async fn pin_int2_route_hg_set(
-        &mut self,
-        val: &PinIntRouteHg,
-    ) -> Result<(), Error<B::Error>> {
+    pub async fn int2_route_hg_set(&mut self, val: IntRouteHg) -> Result<(), Error<B::Error>> {
         let mut ctrl7 = Ctrl7::read(self).await?;
-        ctrl7.set_int2_drdy_xl_hg(val.drdy_hg_xl);
+        ctrl7.set_int2_drdy_xl_hg(val.bit(IntRouteHg::DRDY_HG_XL));
         ctrl7.write(self).await?;
 
         let mut hg_func = HgFunctionsEnable::read(self).await?;
-        hg_func.set_int2_hg_wu(val.hg_wakeup);
+        hg_func.set_int2_hg_wu(val.bit(IntRouteHg::HG_WAKEUP));
         hg_func.write(self).await?;
 
         let mut reg_shock = InactivityThs::read(self).await?;
-        reg_shock.set_int2_hg_shock_change(val.hg_shock_change);
+        reg_shock.set_int2_hg_shock_change(val.bit(IntRouteHg::HG_SHOCK_CHANGE));
         reg_shock.write(self).await
     }
 
+    /// Select the signals that need to be routed on int2 pad. (hg part)
+    #[deprecated(since = "2.1.0", note = "please use int2_route_hg_set")]
+    pub async fn pin_int2_route_hg_set(
+        &mut self,
+        val: &PinIntRouteHg,
+    ) -> Result<(), Error<B::Error>> {
+        self.int2_route_hg_set(IntRouteHg::from(val)).await
+    }
+
     /// Report the signals that are routed on int2 pad for hg part.
     ///
     /// Fields get are:
     ///     - drdy_hg_xl
     ///     - hg_wakeup
     ///     - hg_shock_change
-    pub async fn pin_int2_route_hg_get(&mut self) -> Result<PinIntRouteHg, Error<B::Error>> {
+    pub async fn int2_route_hg_get(&mut self) -> Result<IntRouteHg, Error<B::Error>> {
         let ctrl7 = Ctrl7::read(self).await?;
         let hg_func = HgFunctionsEnable::read(self).await?;
         let reg_shock = InactivityThs::read(self).await?;
 
-        let val = PinIntRouteHg {
-            drdy_hg_xl: ctrl7.int2_drdy_xl_hg(),
-            hg_wakeup: hg_func.int2_hg_wu(),
-            hg_shock_change: reg_shock.int2_hg_shock_change(),
-        };
+        let mut val = IntRouteHg::empty();
+        val.set(IntRouteHg::DRDY_HG_XL, ctrl7.int2_drdy_xl_hg() == 1);
+        val.set(IntRouteHg::HG_WAKEUP, hg_func.int2_hg_wu() == 1);
+        val.set(
+            IntRouteHg::HG_SHOCK_CHANGE,
+            reg_shock.int2_hg_shock_change() == 1,
+        );
 
         Ok(val)
     }
 
+    /// Report the signals that are routed on int2 pad for hg part.
+    #[deprecated(since = "2.1.0", note = "please use int2_route_hg_get")]
+    pub async fn pin_int2_route_hg_get(&mut self) -> Result<PinIntRouteHg, Error<B::Error>> {
+        self.int2_route_hg_get().await.map(PinIntRouteHg::from)
+    }
+
     /// Select the signals that need to be routed on int1 pad. (embedded events)
-    pub async fn pin_int1_route_embedded_set(
+    pub async fn int1_route_embedded_set(
         &mut self,
-        val: &PinIntRouteEmb,
+        val: IntRouteEmb,
     ) -> Result<(), Error<B::Error>> {
         let mut md1_cfg = Md1Cfg::read(self).await?;
         md1_cfg.set_int1_emb_func(1);
@@ -1524,72 +2131,93 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
 
         self.operate_over_embed(async |state| {
             let mut emb_func_int1 = EmbFuncInt1::read(state).await?;
-            emb_func_int1.set_int1_step_detector(val.step_detector);
-            emb_func_int1.set_int1_tilt(val.tilt);
-            emb_func_int1.set_int1_sig_mot(val.sig_mot);
+            emb_func_int1.set_int1_step_detector(val.bit(IntRouteEmb::STEP_DETECTOR));
+            emb_func_int1.set_int1_tilt(val.bit(IntRouteEmb::TILT));
+            emb_func_int1.set_int1_sig_mot(val.bit(IntRouteEmb::SIG_MOT));
             emb_func_int1.write(state).await?;
 
             let mut fsm_int1 = FsmInt1::read(state).await?;
-            fsm_int1.set_int1_fsm1(val.fsm1);
-            fsm_int1.set_int1_fsm2(val.fsm2);
-            fsm_int1.set_int1_fsm3(val.fsm3);
-            fsm_int1.set_int1_fsm4(val.fsm4);
-            fsm_int1.set_int1_fsm5(val.fsm5);
-            fsm_int1.set_int1_fsm6(val.fsm6);
-            fsm_int1.set_int1_fsm7(val.fsm7);
-            fsm_int1.set_int1_fsm8(val.fsm8);
+            fsm_int1.set_int1_fsm1(val.bit(IntRouteEmb::FSM1));
+            fsm_int1.set_int1_fsm2(val.bit(IntRouteEmb::FSM2));
+            fsm_int1.set_int1_fsm3(val.bit(IntRouteEmb::FSM3));
+            fsm_int1.set_int1_fsm4(val.bit(IntRouteEmb::FSM4));
+            fsm_int1.set_int1_fsm5(val.bit(IntRouteEmb::FSM5));
+            fsm_int1.set_int1_fsm6(val.bit(IntRouteEmb::FSM6));
+            fsm_int1.set_int1_fsm7(val.bit(IntRouteEmb::FSM7));
+            fsm_int1.set_int1_fsm8(val.bit(IntRouteEmb::FSM8));
             fsm_int1.write(state).await?;
 
             let mut mlc_int1 = MlcInt1::read(state).await?;
-            mlc_int1.set_int1_mlc1(val.mlc1);
-            mlc_int1.set_int1_mlc2(val.mlc2);
-            mlc_int1.set_int1_mlc3(val.mlc3);
-            mlc_int1.set_int1_mlc4(val.mlc4);
-            mlc_int1.set_int1_mlc5(val.mlc5);
-            mlc_int1.set_int1_mlc6(val.mlc6);
-            mlc_int1.set_int1_mlc7(val.mlc7);
-            mlc_int1.set_int1_mlc8(val.mlc8);
+            mlc_int1.set_int1_mlc1(val.bit(IntRouteEmb::MLC1));
+            mlc_int1.set_int1_mlc2(val.bit(IntRouteEmb::MLC2));
+            mlc_int1.set_int1_mlc3(val.bit(IntRouteEmb::MLC3));
+            mlc_int1.set_int1_mlc4(val.bit(IntRouteEmb::MLC4));
+            mlc_int1.set_int1_mlc5(val.bit(IntRouteEmb::MLC5));
+            mlc_int1.set_int1_mlc6(val.bit(IntRouteEmb::MLC6));
+            mlc_int1.set_int1_mlc7(val.bit(IntRouteEmb::MLC7));
+            mlc_int1.set_int1_mlc8(val.bit(IntRouteEmb::MLC8));
             mlc_int1.write(state).await
         })
         .await
     }
 
+    /// Select the signals that need to be routed on int1 pad. (embedded events)
+    #[deprecated(since = "2.1.0", note = "please use int1_route_embedded_set")]
+    pub async fn pin_int1_route_embedded_set(
+        &mut self,
+        val: &PinIntRouteEmb,
+    ) -> Result<(), Error<B::Error>> {
+        self.int1_route_embedded_set(IntRouteEmb::from(val)).await
+    }
+
     /// Report the signals that are routed on int1 pad.
-    pub async fn pin_int1_route_embedded_get(&mut self) -> Result<PinIntRouteEmb, Error<B::Error>> {
+    pub async fn int1_route_embedded_get(&mut self) -> Result<IntRouteEmb, Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let emb_func_int1 = EmbFuncInt1::read(state).await?;
             let fsm_int1 = FsmInt1::read(state).await?;
             let mlc_int1 = MlcInt1::read(state).await?;
 
-            Ok(PinIntRouteEmb {
-                step_detector: emb_func_int1.int1_step_detector(),
-                tilt: emb_func_int1.int1_tilt(),
-                sig_mot: emb_func_int1.int1_sig_mot(),
-                fsm1: fsm_int1.int1_fsm1(),
-                fsm2: fsm_int1.int1_fsm2(),
-                fsm3: fsm_int1.int1_fsm3(),
-                fsm4: fsm_int1.int1_fsm4(),
-                fsm5: fsm_int1.int1_fsm5(),
-                fsm6: fsm_int1.int1_fsm6(),
-                fsm7: fsm_int1.int1_fsm7(),
-                fsm8: fsm_int1.int1_fsm8(),
-                mlc1: mlc_int1.int1_mlc1(),
-                mlc2: mlc_int1.int1_mlc2(),
-                mlc3: mlc_int1.int1_mlc3(),
-                mlc4: mlc_int1.int1_mlc4(),
-                mlc5: mlc_int1.int1_mlc5(),
-                mlc6: mlc_int1.int1_mlc6(),
-                mlc7: mlc_int1.int1_mlc7(),
-                mlc8: mlc_int1.int1_mlc8(),
-            })
+            let mut val = IntRouteEmb::empty();
+            val.set(
+                IntRouteEmb::STEP_DETECTOR,
+                emb_func_int1.int1_step_detector() == 1,
+            );
+            val.set(IntRouteEmb::TILT, emb_func_int1.int1_tilt() == 1);
+            val.set(IntRouteEmb::SIG_MOT, emb_func_int1.int1_sig_mot() == 1);
+            val.set(IntRouteEmb::FSM1, fsm_int1.int1_fsm1() == 1);
+            val.set(IntRouteEmb::FSM2, fsm_int1.int1_fsm2() == 1);
+            val.set(IntRouteEmb::FSM3, fsm_int1.int1_fsm3() == 1);
+            val.set(IntRouteEmb::FSM4, fsm_int1.int1_fsm4() == 1);
+            val.set(IntRouteEmb::FSM5, fsm_int1.int1_fsm5() == 1);
+            val.set(IntRouteEmb::FSM6, fsm_int1.int1_fsm6() == 1);
+            val.set(IntRouteEmb::FSM7, fsm_int1.int1_fsm7() == 1);
+            val.set(IntRouteEmb::FSM8, fsm_int1.int1_fsm8() == 1);
+            val.set(IntRouteEmb::MLC1, mlc_int1.int1_mlc1() == 1);
+            val.set(IntRouteEmb::MLC2, mlc_int1.int1_mlc2() == 1);
+            val.set(IntRouteEmb::MLC3, mlc_int1.int1_mlc3() == 1);
+            val.set(IntRouteEmb::MLC4, mlc_int1.int1_mlc4() == 1);
+            val.set(IntRouteEmb::MLC5, mlc_int1.int1_mlc5() == 1);
+            val.set(IntRouteEmb::MLC6, mlc_int1.int1_mlc6() == 1);
+            val.set(IntRouteEmb::MLC7, mlc_int1.int1_mlc7() == 1);
+            val.set(IntRouteEmb::MLC8, mlc_int1.int1_mlc8() == 1);
+
+            Ok(val)
         })
         .await
     }
 
+    /// Report the signals that are routed on int1 pad.
+    #[deprecated(since = "2.1.0", note = "please use int1_route_embedded_get")]
+    pub async fn pin_int1_route_embedded_get(&mut self) -> Result<PinIntRouteEmb, Error<B::Error>> {
+        self.int1_route_embedded_get()
+            .await
+            .map(PinIntRouteEmb::from)
+    }
+
     /// Select the signals that need to be routed on int2 pad. (embedded events)
-    pub async fn pin_int2_route_embedded_set(
+    pub async fn int2_route_embedded_set(
         &mut self,
-        val: &PinIntRouteEmb,
+        val: IntRouteEmb,
     ) -> Result<(), Error<B::Error>> {
         let mut md2_cfg = Md2Cfg::read(self).await?;
         md2_cfg.set_int2_emb_func(1);
@@ -1597,77 +2225,157 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
 
         self.operate_over_embed(async |state| {
             let mut emb_func_int2 = EmbFuncInt2::read(state).await?;
-            emb_func_int2.set_int2_step_detector(val.step_detector);
-            emb_func_int2.set_int2_tilt(val.tilt);
-            emb_func_int2.set_int2_sig_mot(val.sig_mot);
+            emb_func_int2.set_int2_step_detector(val.bit(IntRouteEmb::STEP_DETECTOR));
+            emb_func_int2.set_int2_tilt(val.bit(IntRouteEmb::TILT));
+            emb_func_int2.set_int2_sig_mot(val.bit(IntRouteEmb::SIG_MOT));
             emb_func_int2.write(state).await?;
 
             let mut fsm_int2 = FsmInt2::read(state).await?;
-            fsm_int2.set_int2_fsm1(val.fsm1);
-            fsm_int2.set_int2_fsm2(val.fsm2);
-            fsm_int2.set_int2_fsm3(val.fsm3);
-            fsm_int2.set_int2_fsm4(val.fsm4);
-            fsm_int2.set_int2_fsm5(val.fsm5);
-            fsm_int2.set_int2_fsm6(val.fsm6);
-            fsm_int2.set_int2_fsm7(val.fsm7);
-            fsm_int2.set_int2_fsm8(val.fsm8);
+            fsm_int2.set_int2_fsm1(val.bit(IntRouteEmb::FSM1));
+            fsm_int2.set_int2_fsm2(val.bit(IntRouteEmb::FSM2));
+            fsm_int2.set_int2_fsm3(val.bit(IntRouteEmb::FSM3));
+            fsm_int2.set_int2_fsm4(val.bit(IntRouteEmb::FSM4));
+            fsm_int2.set_int2_fsm5(val.bit(IntRouteEmb::FSM5));
+            fsm_int2.set_int2_fsm6(val.bit(IntRouteEmb::FSM6));
+            fsm_int2.set_int2_fsm7(val.bit(IntRouteEmb::FSM7));
+            fsm_int2.set_int2_fsm8(val.bit(IntRouteEmb::FSM8));
             fsm_int2.write(state).await?;
 
             let mut mlc_int2 = MlcInt2::read(state).await?;
-            mlc_int2.set_int2_mlc1(val.mlc1);
-            mlc_int2.set_int2_mlc2(val.mlc2);
-            mlc_int2.set_int2_mlc3(val.mlc3);
-            mlc_int2.set_int2_mlc4(val.mlc4);
-            mlc_int2.set_int2_mlc5(val.mlc5);
-            mlc_int2.set_int2_mlc6(val.mlc6);
-            mlc_int2.set_int2_mlc7(val.mlc7);
-            mlc_int2.set_int2_mlc8(val.mlc8);
+            mlc_int2.set_int2_mlc1(val.bit(IntRouteEmb::MLC1));
+            mlc_int2.set_int2_mlc2(val.bit(IntRouteEmb::MLC2));
+            mlc_int2.set_int2_mlc3(val.bit(IntRouteEmb::MLC3));
+            mlc_int2.set_int2_mlc4(val.bit(IntRouteEmb::MLC4));
+            mlc_int2.set_int2_mlc5(val.bit(IntRouteEmb::MLC5));
+            mlc_int2.set_int2_mlc6(val.bit(IntRouteEmb::MLC6));
+            mlc_int2.set_int2_mlc7(val.bit(IntRouteEmb::MLC7));
+            mlc_int2.set_int2_mlc8(val.bit(IntRouteEmb::MLC8));
             mlc_int2.write(state).await
         })
         .await
     }
 
+    /// Select the signals that need to be routed on int2 pad. (embedded events)
+    #[deprecated(since = "2.1.0", note = "please use int2_route_embedded_set")]
+    pub async fn pin_int2_route_embedded_set(
+        &mut self,
+        val: &PinIntRouteEmb,
+    ) -> Result<(), Error<B::Error>> {
+        self.int2_route_embedded_set(IntRouteEmb::from(val)).await
+    }
+
     /// Report the signals that are routed on int2 pad.
-    pub async fn pin_int2_route_embedded_get(&mut self) -> Result<PinIntRouteEmb, Error<B::Error>> {
+    pub async fn int2_route_embedded_get(&mut self) -> Result<IntRouteEmb, Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let emb_func_int2 = EmbFuncInt2::read(state).await?;
             let fsm_int2 = FsmInt2::read(state).await?;
             let mlc_int2 = MlcInt2::read(state).await?;
 
-            Ok(PinIntRouteEmb {
-                step_detector: emb_func_int2.int2_step_detector(),
-                tilt: emb_func_int2.int2_tilt(),
-                sig_mot: emb_func_int2.int2_sig_mot(),
-                fsm1: fsm_int2.int2_fsm1(),
-                fsm2: fsm_int2.int2_fsm2(),
-                fsm3: fsm_int2.int2_fsm3(),
-                fsm4: fsm_int2.int2_fsm4(),
-                fsm5: fsm_int2.int2_fsm5(),
-                fsm6: fsm_int2.int2_fsm6(),
-                fsm7: fsm_int2.int2_fsm7(),
-                fsm8: fsm_int2.int2_fsm8(),
-                mlc1: mlc_int2.int2_mlc1(),
-                mlc2: mlc_int2.int2_mlc2(),
-                mlc3: mlc_int2.int2_mlc3(),
-                mlc4: mlc_int2.int2_mlc4(),
-                mlc5: mlc_int2.int2_mlc5(),
-                mlc6: mlc_int2.int2_mlc6(),
-                mlc7: mlc_int2.int2_mlc7(),
-                mlc8: mlc_int2.int2_mlc8(),
-            })
+            let mut val = IntRouteEmb::empty();
+            val.set(
+                IntRouteEmb::STEP_DETECTOR,
+                emb_func_int2.int2_step_detector() == 1,
+            );
+            val.set(IntRouteEmb::TILT, emb_func_int2.int2_tilt() == 1);
+            val.set(IntRouteEmb::SIG_MOT, emb_func_int2.int2_sig_mot() == 1);
+            val.set(IntRouteEmb::FSM1, fsm_int2.int2_fsm1() == 1);
+            val.set(IntRouteEmb::FSM2, fsm_int2.int2_fsm2() == 1);
+            val.set(IntRouteEmb::FSM3, fsm_int2.int2_fsm3() == 1);
+            val.set(IntRouteEmb::FSM4, fsm_int2.int2_fsm4() == 1);
+            val.set(IntRouteEmb::FSM5, fsm_int2.int2_fsm5() == 1);
+            val.set(IntRouteEmb::FSM6, fsm_int2.int2_fsm6() == 1);
+            val.set(IntRouteEmb::FSM7, fsm_int2.int2_fsm7() == 1);
+            val.set(IntRouteEmb::FSM8, fsm_int2.int2_fsm8() == 1);
+            val.set(IntRouteEmb::MLC1, mlc_int2.int2_mlc1() == 1);
+            val.set(IntRouteEmb::MLC2, mlc_int2.int2_mlc2() == 1);
+            val.set(IntRouteEmb::MLC3, mlc_int2.int2_mlc3() == 1);
+            val.set(IntRouteEmb::MLC4, mlc_int2.int2_mlc4() == 1);
+            val.set(IntRouteEmb::MLC5, mlc_int2.int2_mlc5() == 1);
+            val.set(IntRouteEmb::MLC6, mlc_int2.int2_mlc6() == 1);
+            val.set(IntRouteEmb::MLC7, mlc_int2.int2_mlc7() == 1);
+            val.set(IntRouteEmb::MLC8, mlc_int2.int2_mlc8() == 1);
+
+            Ok(val)
         })
         .await
     }
 
+    /// Report the signals that are routed on int2 pad.
+    #[deprecated(since = "2.1.0", note = "please use int2_route_embedded_get")]
+    pub async fn pin_int2_route_embedded_get(&mut self) -> Result<PinIntRouteEmb, Error<B::Error>> {
+        self.int2_route_embedded_get()
+            .await
+            .map(PinIntRouteEmb::from)
+    }
+
+    /// Apply the routing of the interrupt sources on the INT1 and INT2 pads.
+    ///
+    /// All the sources are written, so the ones not routed by `val` are
+    /// removed from both pads. Embedded functions events are enabled on a
+    /// pad (INT1_EMB_FUNC/INT2_EMB_FUNC) only if at least one of them is
+    /// routed on it.
+    ///
+    /// Returns `Error::InvalidConfiguration` if a source is routed on a pad
+    /// it does not support.
+    pub async fn interrupt_routing_set(
+        &mut self,
+        val: &InterruptRouting,
+    ) -> Result<(), Error<B::Error>> {
+        if val.unsupported().is_some() {
+            return Err(Error::InvalidConfiguration);
+        }
+
+        /* 1. embedded functions events (embedded functions bank) */
+        self.int1_route_embedded_set(val.int1_emb).await?;
+        self.int2_route_embedded_set(val.int2_emb).await?;
+
+        /* 2. main page events (INT1_CTRL, INT2_CTRL, CTRL4, MD1_CFG, MD2_CFG) */
+        self.int1_route_set(val.int1).await?;
+        self.int2_route_set(val.int2).await?;
+
+        let mut md1_cfg = Md1Cfg::read(self).await?;
+        md1_cfg.set_int1_emb_func(!val.int1_emb.is_empty() as u8);
+        md1_cfg.write(self).await?;
+
+        let mut md2_cfg = Md2Cfg::read(self).await?;
+        md2_cfg.set_int2_emb_func(!val.int2_emb.is_empty() as u8);
+        md2_cfg.write(self).await?;
+
+        /* 3. high-g events (CTRL7, HG_FUNCTIONS_ENABLE, INACTIVITY_THS) */
+        self.int1_route_hg_set(val.int1_hg).await?;
+        self.int2_route_hg_set(val.int2_hg).await
+    }
+
+    /// Get the routing of the interrupt sources on the INT1 and INT2 pads.
+    ///
+    /// Embedded functions events are reported only if embedded functions
+    /// events are enabled on the pad (INT1_EMB_FUNC/INT2_EMB_FUNC).
+    pub async fn interrupt_routing_get(&mut self) -> Result<InterruptRouting, Error<B::Error>> {
+        let md1_cfg = Md1Cfg::read(self).await?;
+        let md2_cfg = Md2Cfg::read(self).await?;
+
+        let mut val = InterruptRouting::new();
+        val.int1 = self.int1_route_get().await?;
+        val.int2 = self.int2_route_get().await?;
+        val.int1_hg = self.int1_route_hg_get().await?;
+        val.int2_hg = self.int2_route_hg_get().await?;
+        if md1_cfg.int1_emb_func() == 1 {
+            val.int1_emb = self.int1_route_embedded_get().await?;
+        }
+        if md2_cfg.int2_emb_func() == 1 {
+            val.int2_emb = self.int2_route_embedded_get().await?;
+        }
+
+        Ok(val)
+    }
+
     /// Get the status of all the interrupt sources.
     ///
-    /// Values NOT set includes:
-    ///     - emb_func_stand_by,
-    ///     - emb_func_time_exceed
-    ///     - sh_endop
-    ///     - sh_target*_nack
-    ///     - sh_wr_once
-    pub async fn all_sources_get(&mut self) -> Result<AllSources, Error<B::Error>> {
+    /// Main page status registers are read in two burst reads, while the
+    /// embedded functions status registers (EMB_FUNC_EXEC_STATUS,
+    /// EMB_FUNC_STATUS, FSM_STATUS, MLC_STATUS, EMB_FUNC_SRC) are read
+    /// with a single switch to the embedded functions memory bank.
+    pub async fn interrupt_sources_get(&mut self) -> Result<InterruptSources, Error<B::Error>> {
         let mut functions_enable = FunctionsEnable::read(self).await?;
         functions_enable.set_dis_rst_lir_all_int(1);
         functions_enable.write(self).await?;
@@ -1684,7 +2392,7 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         functions_enable.set_dis_rst_lir_all_int(0);
         functions_enable.write(self).await?;
 
-        let mut buff: [u8; 8] = [0; 8];
+        let mut buff: [u8; 9] = [0; 9];
         self.read_from_register(Reg::UiStatusRegOis as u8, &mut buff)
             .await?;
 
@@ -1692,82 +2400,175 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         let wake_up_src = WakeUpSrc::from_bits(buff[1]);
         let tap_src = TapSrc::from_bits(buff[2]);
         let d6d_src = D6dSrc::from_bits(buff[3]);
-        let emb_func_status_mainpage = EmbFuncStatusMainpage::from_bits(buff[5]);
-        let fsm_status_mainpage = FsmStatusMainpage::from_bits(buff[6]);
-        let mlc_status_mainpage = MlcStatusMainpage::from_bits(buff[7]);
-
-        let val = AllSources {
-            fifo_ovr: fifo_status2.fifo_ovr_ia(),
-            fifo_bdr: fifo_status2.counter_bdr_ia(),
-            fifo_full: fifo_status2.fifo_full_ia(),
-            fifo_th: fifo_status2.fifo_wtm_ia(),
-            hg: all_int_src.hg_ia(),
-            free_fall: all_int_src.ff_ia(),
-            wake_up: all_int_src.wu_ia(),
-            six_d: all_int_src.d6d_ia(),
-            drdy_xl: status_reg.xlda(),
-            drdy_gy: status_reg.gda(),
-            drdy_temp: status_reg.tda(),
-            drdy_xlhgda: status_reg.xlhgda(),
-            drdy_eis: status_reg.gda_eis(),
-            drdy_ois: status_reg.ois_drdy(),
-            gy_settling: status_reg_ois.gyro_settling(),
-            timestamp: status_reg.timestamp_endcount(),
-            sleep_change: wake_up_src.sleep_change_ia(),
-            wake_up_x: wake_up_src.x_wu(),
-            wake_up_y: wake_up_src.y_wu(),
-            wake_up_z: wake_up_src.z_wu(),
-            sleep_state: wake_up_src.sleep_state(),
-            tap_x: tap_src.x_tap(),
-            tap_y: tap_src.y_tap(),
-            tap_z: tap_src.z_tap(),
-            tap_sign: tap_src.tap_sign(),
-            double_tap: tap_src.double_tap(),
-            single_tap: tap_src.single_tap(),
-            six_d_zl: d6d_src.zl(),
-            six_d_zh: d6d_src.zh(),
-            six_d_yl: d6d_src.yl(),
-            six_d_yh: d6d_src.yh(),
-            six_d_xl: d6d_src.xl(),
-            six_d_xh: d6d_src.xh(),
-            step_detector: emb_func_status_mainpage.is_step_det(),
-            tilt: emb_func_status_mainpage.is_tilt(),
-            sig_mot: emb_func_status_mainpage.is_sigmot(),
-            fsm_lc: emb_func_status_mainpage.is_fsm_lc(),
-            fsm1: fsm_status_mainpage.is_fsm1(),
-            fsm2: fsm_status_mainpage.is_fsm2(),
-            fsm3: fsm_status_mainpage.is_fsm3(),
-            fsm4: fsm_status_mainpage.is_fsm4(),
-            fsm5: fsm_status_mainpage.is_fsm5(),
-            fsm6: fsm_status_mainpage.is_fsm6(),
-            fsm7: fsm_status_mainpage.is_fsm7(),
-            fsm8: fsm_status_mainpage.is_fsm8(),
-            mlc1: mlc_status_mainpage.is_mlc1(),
-            mlc2: mlc_status_mainpage.is_mlc2(),
-            mlc3: mlc_status_mainpage.is_mlc3(),
-            mlc4: mlc_status_mainpage.is_mlc4(),
-            mlc5: mlc_status_mainpage.is_mlc5(),
-            mlc6: mlc_status_mainpage.is_mlc6(),
-            mlc7: mlc_status_mainpage.is_mlc7(),
-            mlc8: mlc_status_mainpage.is_mlc8(),
-            ..Default::default()
+        let status_controller = StatusControllerMainpage::from_bits(buff[4]);
+        let hg_wake_up_src = HgWakeUpSrc::from_bits(buff[8]);
+
+        let (emb_func_exec_status, emb_func_status, fsm_status, mlc_status, emb_func_src) = self
+            .operate_over_embed(async |state| {
+                let emb_func_exec_status = EmbFuncExecStatus::read(state).await?;
+
+                let mut buff: [u8; 4] = [0; 4];
+                state
+                    .read_from_register(EmbReg::EmbFuncStatus as u8, &mut buff)
+                    .await?;
+
+                let emb_func_src = EmbFuncSrc::read(state).await?;
+
+                Ok((
+                    emb_func_exec_status,
+                    EmbFuncStatus::from_bits(buff[0]),
+                    FsmStatus::from_bits(buff[1]),
+                    MlcStatus::from_bits(buff[3]),
+                    emb_func_src,
+                ))
+            })
+            .await?;
+
+        let val = InterruptSources {
+            fifo_ovr: fifo_status2.fifo_ovr_ia() == 1,
+            fifo_bdr: fifo_status2.counter_bdr_ia() == 1,
+            fifo_full: fifo_status2.fifo_full_ia() == 1,
+            fifo_th: fifo_status2.fifo_wtm_ia() == 1,
+            hg: all_int_src.hg_ia() == 1,
+            free_fall: all_int_src.ff_ia() == 1,
+            wake_up: all_int_src.wu_ia() == 1,
+            six_d: all_int_src.d6d_ia() == 1,
+            drdy_xl: status_reg.xlda() == 1,
+            drdy_gy: status_reg.gda() == 1,
+            drdy_temp: status_reg.tda() == 1,
+            drdy_xlhgda: status_reg.xlhgda() == 1,
+            drdy_eis: status_reg.gda_eis() == 1,
+            drdy_ois: status_reg.ois_drdy() == 1,
+            gy_settling: status_reg_ois.gyro_settling() == 1,
+            timestamp: status_reg.timestamp_endcount() == 1,
+            sleep_change: wake_up_src.sleep_change_ia() == 1,
+            wake_up_x: wake_up_src.x_wu() == 1,
+            wake_up_y: wake_up_src.y_wu() == 1,
+            wake_up_z: wake_up_src.z_wu() == 1,
+            sleep_state: wake_up_src.sleep_state() == 1,
+            hg_wake_up: hg_wake_up_src.hg_wu_ia() == 1,
+            hg_wake_up_x: hg_wake_up_src.hg_x_wu() == 1,
+            hg_wake_up_y: hg_wake_up_src.hg_y_wu() == 1,
+            hg_wake_up_z: hg_wake_up_src.hg_z_wu() == 1,
+            hg_wake_up_change: hg_wake_up_src.hg_wu_change_ia() == 1,
+            hg_shock_state: hg_wake_up_src.hg_shock_state() == 1,
+            hg_shock_change: hg_wake_up_src.hg_shock_change_ia() == 1,
+            tap_x: tap_src.x_tap() == 1,
+            tap_y: tap_src.y_tap() == 1,
+            tap_z: tap_src.z_tap() == 1,
+            tap_sign: tap_src.tap_sign() == 1,
+            double_tap: tap_src.double_tap() == 1,
+            single_tap: tap_src.single_tap() == 1,
+            six_d_zl: d6d_src.zl() == 1,
+            six_d_zh: d6d_src.zh() == 1,
+            six_d_yl: d6d_src.yl() == 1,
+            six_d_yh: d6d_src.yh() == 1,
+            six_d_xl: d6d_src.xl() == 1,
+            six_d_xh: d6d_src.xh() == 1,
+            step_detector: emb_func_status.is_step_det() == 1,
+            step_count_inc: emb_func_src.stepcounter_bit_set() == 1,
+            step_count_overflow: emb_func_src.step_overflow() == 1,
+            step_on_delta_time: emb_func_src.step_count_delta_ia() == 1,
+            emb_func_stand_by: emb_func_exec_status.emb_func_endop() == 1,
+            emb_func_time_exceed: emb_func_exec_status.emb_func_exec_ovr() == 1,
+            tilt: emb_func_status.is_tilt() == 1,
+            sig_mot: emb_func_status.is_sigmot() == 1,
+            fsm_lc: emb_func_status.is_fsm_lc() == 1,
+            fsm1: fsm_status.is_fsm1() == 1,
+            fsm2: fsm_status.is_fsm2() == 1,
+            fsm3: fsm_status.is_fsm3() == 1,
+            fsm4: fsm_status.is_fsm4() == 1,
+            fsm5: fsm_status.is_fsm5() == 1,
+            fsm6: fsm_status.is_fsm6() == 1,
+            fsm7: fsm_status.is_fsm7() == 1,
+            fsm8: fsm_status.is_fsm8() == 1,
+            mlc1: mlc_status.is_mlc1() == 1,
+            mlc2: mlc_status.is_mlc2() == 1,
+            mlc3: mlc_status.is_mlc3() == 1,
+            mlc4: mlc_status.is_mlc4() == 1,
+            mlc5: mlc_status.is_mlc5() == 1,
+            mlc6: mlc_status.is_mlc6() == 1,
+            mlc7: mlc_status.is_mlc7() == 1,
+            mlc8: mlc_status.is_mlc8() == 1,
+            sh_endop: status_controller.sens_hub_endop() == 1,
+            sh_target0_nack: status_controller.target0_nack() == 1,
+            sh_target1_nack: status_controller.target1_nack() == 1,
+            sh_target2_nack: status_controller.target2_nack() == 1,
+            sh_target3_nack: status_controller.target3_nack() == 1,
+            sh_wr_once: status_controller.wr_once_done() == 1,
         };
 
         Ok(val)
     }
 
+    /// Get the status of all the interrupt sources.
+    #[deprecated(since = "2.1.0", note = "please use interrupt_sources_get")]
+    pub async fn all_sources_get(&mut self) -> Result<AllSources, Error<B::Error>> {
+        self.interrupt_sources_get().await.map(AllSources::from)
+    }
+
+    /// Get the interrupt events.
+    ///
+    /// All the interrupt sources are read at once; FSM outputs and MLC
+    /// decision tree results are read only if at least one FSM or MLC interrupt
+    /// is active.
+    pub async fn events_get(&mut self) -> Result<Events, Error<B::Error>> {
+        let sources = self.interrupt_sources_get().await?;
+
+        let fsm = [
+            sources.fsm1,
+            sources.fsm2,
+            sources.fsm3,
+            sources.fsm4,
+            sources.fsm5,
+            sources.fsm6,
+            sources.fsm7,
+            sources.fsm8,
+        ];
+        let fsm_out = if fsm.contains(&true) {
+            self.fsm_out_get().await?
+        } else {
+            FsmOut::default()
+        };
+
+        let mlc = [
+            sources.mlc1,
+            sources.mlc2,
+            sources.mlc3,
+            sources.mlc4,
+            sources.mlc5,
+            sources.mlc6,
+            sources.mlc7,
+            sources.mlc8,
+        ];
+        let mlc_out = if mlc.contains(&true) {
+            self.mlc_out_get().await?
+        } else {
+            MlcOut::default()
+        };
+
+        Ok(Events::new(&sources, &fsm_out, &mlc_out))
+    }
+
     /// Get Flag data ready
     ///
     /// Return status about: hgxl, xl, gy, temp; data ready
-    pub async fn flag_data_ready_get(&mut self) -> Result<DataReady, Error<B::Error>> {
+    pub async fn data_ready_get(&mut self) -> Result<DataReadyFlags, Error<B::Error>> {
         let status = StatusReg::read(self).await?;
 
-        Ok(DataReady {
-            drdy_hgxl: status.xlhgda(),
-            drdy_xl: status.xlda(),
-            drdy_gy: status.gda(),
-            drdy_temp: status.tda(),
-        })
+        let mut val = DataReadyFlags::empty();
+        val.set(DataReadyFlags::DRDY_HGXL, status.xlhgda() == 1);
+        val.set(DataReadyFlags::DRDY_XL, status.xlda() == 1);
+        val.set(DataReadyFlags::DRDY_GY, status.gda() == 1);
+        val.set(DataReadyFlags::DRDY_TEMP, status.tda() == 1);
+
+        Ok(val)
+    }
+
+    /// Get Flag data ready
+    #[deprecated(since = "2.1.0", note = "please use data_ready_get")]
+    pub async fn flag_data_ready_get(&mut self) -> Result<DataReady, Error<B::Error>> {
+        self.data_ready_get().await.map(DataReady::from)
     }
 
     /// Set Mask status bit reset
@@ -1791,6 +2592,14 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         OutTemp::read(self).await.map(|reg| reg.0)
     }
 
+    /// Get the Temperature in Celsius.
+    pub async fn temperature_celsius_get(
+        &mut self,
+    ) -> Result<Temperature<Celsius>, Error<B::Error>> {
+        let raw = self.temperature_raw_get().await?;
+        Ok(Temperature::from_lsb(raw))
+    }
+
     /// Get the Angular rate raw data.
     pub async fn angular_rate_raw_get(&mut self) -> Result<[i16; 3], Error<B::Error>> {
         let arr = OutXYZG::read(self).await?;
@@ -1828,13 +2637,86 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
 
     /// Get the High-G linear acceleration raw data.
     ///
-    /// Require to enable reg_out_en parameter in `hg_xl_data_rate_set` function.
+    /// Require to enable reg_out_en parameter in `hg_xl_setup` function.
     pub async fn hg_acceleration_raw_get(&mut self) -> Result<[i16; 3], Error<B::Error>> {
         let val = UiOutXYZAOisHg::read(self).await?;
 
         Ok([val.x, val.y, val.z])
     }
 
+    /// Get the Linear acceleration in mg.
+    ///
+    /// The actual full scale is read from the device to convert the raw data.
+    pub async fn acceleration_mg_get(
+        &mut self,
+    ) -> Result<Vector3<Acceleration<MilliG>>, Error<B::Error>> {
+        let fs = self.xl_full_scale_get().await?;
+        let raw = self.acceleration_raw_get().await?;
+
+        Ok(Vector3::from(raw).map(|lsb| fs.to_acceleration(lsb)))
+    }
+
+    /// Get the High-G linear acceleration in mg.
+    ///
+    /// The actual full scale is read from the device to convert the raw data.
+    /// Require to enable reg_out_en parameter in `hg_xl_setup` function.
+    pub async fn hg_acceleration_mg_get(
+        &mut self,
+    ) -> Result<Vector3<Acceleration<MilliG>>, Error<B::Error>> {
+        let fs = self.hg_xl_full_scale_get().await?;
+        let raw = self.hg_acceleration_raw_get().await?;
+
+        Ok(Vector3::from(raw).map(|lsb| fs.to_acceleration(lsb)))
+    }
+
+    /// Get the Angular rate in mdps.
+    ///
+    /// The actual full scale is read from the device to convert the raw data.
+    pub async fn angular_rate_mdps_get(
+        &mut self,
+    ) -> Result<Vector3<AngularRate<MilliDps>>, Error<B::Error>> {
+        let fs = self.gy_full_scale_get().await?;
+        let raw = self.angular_rate_raw_get().await?;
+
+        Ok(Vector3::from(raw).map(|lsb| fs.to_angular_rate(lsb)))
+    }
+
+    /// Get the OIS Angular rate in mdps.
+    ///
+    /// The actual OIS full scale is read from the device to convert the raw data.
+    pub async fn ois_angular_rate_mdps_get(
+        &mut self,
+    ) -> Result<Vector3<AngularRate<MilliDps>>, Error<B::Error>> {
+        let fs = self.ois_gy_full_scale_get().await?;
+        let raw = self.ois_angular_rate_raw_get().await?;
+
+        Ok(Vector3::from(raw).map(|lsb| fs.to_angular_rate(lsb)))
+    }
+
+    /// Get the OIS Linear acceleration in mg.
+    ///
+    /// The actual OIS full scale is read from the device to convert the raw data.
+    pub async fn ois_acceleration_mg_get(
+        &mut self,
+    ) -> Result<Vector3<Acceleration<MilliG>>, Error<B::Error>> {
+        let fs = self.ois_xl_full_scale_get().await?;
+        let raw = self.ois_acceleration_raw_get().await?;
+
+        Ok(Vector3::from(raw).map(|lsb| fs.to_acceleration(lsb)))
+    }
+
+    /// Get the EIS Angular rate in mdps.
+    ///
+    /// The actual EIS full scale is read from the device to convert the raw data.
+    pub async fn eis_angular_rate_mdps_get(
+        &mut self,
+    ) -> Result<Vector3<AngularRate<MilliDps>>, Error<B::Error>> {
+        let fs = self.eis_gy_full_scale_get().await?;
+        let raw = self.ois_eis_angular_rate_raw_get().await?;
+
+        Ok(Vector3::from(raw).map(|lsb| fs.to_angular_rate(lsb)))
+    }
+
     /// Get the SFLP gbias raw array.
     pub async fn sflp_gbias_raw_get(&mut self) -> Result<[i16; 3], Error<B::Error>> {
         let val = self
@@ -1848,6 +2730,18 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(val)
     }
 
+    /// Get the SFLP gyroscope bias estimate in mdps.
+    ///
+    /// The value can be written back with `sflp_gbias_set` to initialize the
+    /// next run.
+    pub async fn sflp_gbias_get(
+        &mut self,
+    ) -> Result<Vector3<AngularRate<MilliDps>>, Error<B::Error>> {
+        let raw = self.sflp_gbias_raw_get().await?;
+
+        Ok(Vector3::from(raw).map(AngularRate::from_gbias_lsb))
+    }
+
     /// Get the SFLP gravity raw array.
     pub async fn sflp_gravity_raw_get(&mut self) -> Result<[i16; 3], Error<B::Error>> {
         let val = self
@@ -1871,18 +2765,26 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         .await
     }
 
-    // Reset SFLP Game Rotation Vector Logic (6x).
-    //
-    // If val set to 1: SFLP game algorithm initialization request
-    pub async fn sflp_game_rotation_reset(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    /// Reset SFLP Game Rotation Vector Logic (6x).
+    ///
+    /// If val is true: SFLP game algorithm initialization request
+    pub async fn sflp_game_rotation_init_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_init_a = EmbFuncInitA::read(state).await?;
-            emb_func_init_a.set_sflp_game_init(val);
+            emb_func_init_a.set_sflp_game_init(val as u8);
             emb_func_init_a.write(state).await
         })
         .await
     }
 
+    // Reset SFLP Game Rotation Vector Logic (6x).
+    //
+    // If val set to 1: SFLP game algorithm initialization request
+    #[deprecated(since = "2.1.0", note = "please use sflp_game_rotation_init_set")]
+    pub async fn sflp_game_rotation_reset(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.sflp_game_rotation_init_set(val & 0x01 == 1).await
+    }
+
     /// Get the SFLP quaternions array.
     ///
     /// After the conversion bit to float.
@@ -1910,18 +2812,34 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
 
     /// Disable/Enable Embedded functions.
     ///
-    /// If val equals to 1 disable the embedded functions
-    pub async fn disable_embedded_function_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    /// If val is true disable the embedded functions
+    pub async fn emb_function_disable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut emb_func_cfg = EmbFuncCfg::read(self).await?;
-        emb_func_cfg.set_emb_func_disable(val & 0x1);
+        emb_func_cfg.set_emb_func_disable(val as u8);
         emb_func_cfg.write(self).await
     }
 
+    /// Disable/Enable Embedded functions.
+    ///
+    /// If val equals to 1 disable the embedded functions
+    #[deprecated(since = "2.1.0", note = "please use emb_function_disable_set")]
+    pub async fn disable_embedded_function_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.emb_function_disable_set(val & 0x01 == 1).await
+    }
+
     /// Get the actual value (enable/disable) for Embedded functions.
-    pub async fn disable_embedded_function_get(&mut self) -> Result<u8, Error<B::Error>> {
+    ///
+    /// Returns true if the embedded functions are disabled.
+    pub async fn emb_function_disable_get(&mut self) -> Result<bool, Error<B::Error>> {
         EmbFuncCfg::read(self)
             .await
-            .map(|reg| reg.emb_func_disable())
+            .map(|reg| reg.emb_func_disable() == 1)
+    }
+
+    /// Get the actual value (enable/disable) for Embedded functions.
+    #[deprecated(since = "2.1.0", note = "please use emb_function_disable_get")]
+    pub async fn disable_embedded_function_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.emb_function_disable_get().await.map(u8::from)
     }
 
     /// Enable/Disable embedded function sensor conversion.
@@ -1958,15 +2876,29 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     /// Enable/Disable debug mode for embedded functions
     ///
     /// If val is 1 Enable debug mode for embedded functions
-    pub async fn emb_function_dbg_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn emb_function_dbg_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ctrl10 = Ctrl10::read(self).await?;
-        ctrl10.set_emb_func_debug(val);
+        ctrl10.set_emb_func_debug(val as u8);
         ctrl10.write(self).await
     }
 
+    /// Enable/Disable debug mode for embedded functions
+    #[deprecated(since = "2.1.0", note = "please use emb_function_dbg_enable_set")]
+    pub async fn emb_function_dbg_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.emb_function_dbg_enable_set(val & 0x01 == 1).await
+    }
+
+    /// Get configuration (enable/disable) debug mode for embedded functions
+    pub async fn emb_function_dbg_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        Ctrl10::read(self)
+            .await
+            .map(|reg| reg.emb_func_debug() == 1)
+    }
+
     /// Get configuration (enable/disable) debug mode for embedded functions
+    #[deprecated(since = "2.1.0", note = "please use emb_function_dbg_enable_get")]
     pub async fn emb_function_dbg_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ctrl10::read(self).await.map(|reg| reg.emb_func_debug())
+        self.emb_function_dbg_enable_get().await.map(u8::from)
     }
 
     /// It changes the polarity of INT2 pin input trigger for data enable (DEN) or embedded functions.
@@ -1983,6 +2915,23 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(val)
     }
 
+    /// Set the active level of the interrupt pins (INT1, INT2).
+    pub async fn int_pin_polarity_set(
+        &mut self,
+        val: IntPinPolarity,
+    ) -> Result<(), Error<B::Error>> {
+        let mut if_cfg = IfCfg::read(self).await?;
+        if_cfg.set_h_lactive((val as u8) & 0x1);
+        if_cfg.write(self).await
+    }
+
+    /// Get the active level of the interrupt pins (INT1, INT2).
+    pub async fn int_pin_polarity_get(&mut self) -> Result<IntPinPolarity, Error<B::Error>> {
+        let if_cfg = IfCfg::read(self).await?;
+        let val = IntPinPolarity::try_from(if_cfg.h_lactive()).unwrap_or_default();
+        Ok(val)
+    }
+
     /// Gyroscope full-scale selection for EIS channel.
     ///
     /// WARNING: 4000dps will be available only if also User Interface chain is set to 4000dps.
@@ -2026,19 +2975,31 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     /// Enables routing of gyroscope EIS outputs on IF2 (OIS interface).
     ///
     /// The gyroscope data on IF2 (OIS interface) cannot be read from User Interface (UI).
-    pub async fn eis_gy_on_if2_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn eis_gy_on_if2_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ctrl_eis = CtrlEis::read(self).await?;
-        ctrl_eis.set_g_eis_on_g_ois_out_reg(val);
+        ctrl_eis.set_g_eis_on_g_ois_out_reg(val as u8);
         ctrl_eis.write(self).await
     }
 
+    /// Enables routing of gyroscope EIS outputs on IF2 (OIS interface).
+    #[deprecated(since = "2.1.0", note = "please use eis_gy_on_if2_enable_set")]
+    pub async fn eis_gy_on_if2_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.eis_gy_on_if2_enable_set(val & 0x01 == 1).await
+    }
+
     /// Enables routing of gyroscope EIS outputs on IF2 (OIS interface).
     ///
     /// The gyroscope data on IF2 (OIS interface) cannot be read from User Interface (UI).
-    pub async fn eis_gy_on_if2_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn eis_gy_on_if2_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         CtrlEis::read(self)
             .await
-            .map(|reg| reg.g_eis_on_g_ois_out_reg())
+            .map(|reg| reg.g_eis_on_g_ois_out_reg() == 1)
+    }
+
+    /// Enables routing of gyroscope EIS outputs on IF2 (OIS interface).
+    #[deprecated(since = "2.1.0", note = "please use eis_gy_on_if2_enable_get")]
+    pub async fn eis_gy_on_if2_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.eis_gy_on_if2_enable_get().await.map(u8::from)
     }
 
     /// Enables and selects the ODR of the gyroscope EIS channel.
@@ -2111,53 +3072,119 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enables ODR CHANGE virtual sensor to be batched in FIFO.
-    pub async fn fifo_virtual_sens_odr_chg_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn fifo_virtual_sens_odr_chg_enable_set(
+        &mut self,
+        val: bool,
+    ) -> Result<(), Error<B::Error>> {
         let mut fifo_ctrl2 = FifoCtrl2::read(self).await?;
-        fifo_ctrl2.set_odr_chg_en(val);
+        fifo_ctrl2.set_odr_chg_en(val as u8);
         fifo_ctrl2.write(self).await
     }
 
+    /// Enables ODR CHANGE virtual sensor to be batched in FIFO.
+    #[deprecated(
+        since = "2.1.0",
+        note = "please use fifo_virtual_sens_odr_chg_enable_set"
+    )]
+    pub async fn fifo_virtual_sens_odr_chg_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.fifo_virtual_sens_odr_chg_enable_set(val & 0x01 == 1)
+            .await
+    }
+
+    /// Get the configuration (enable/disable) of ODR CHANGE virtual sensor to be batched in FIFO.
+    pub async fn fifo_virtual_sens_odr_chg_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        FifoCtrl2::read(self).await.map(|reg| reg.odr_chg_en() == 1)
+    }
+
     /// Get the configuration (enable/disable) of ODR CHANGE virtual sensor to be batched in FIFO.
+    #[deprecated(
+        since = "2.1.0",
+        note = "please use fifo_virtual_sens_odr_chg_enable_get"
+    )]
     pub async fn fifo_virtual_sens_odr_chg_get(&mut self) -> Result<u8, Error<B::Error>> {
-        FifoCtrl2::read(self).await.map(|reg| reg.odr_chg_en())
+        self.fifo_virtual_sens_odr_chg_enable_get()
+            .await
+            .map(u8::from)
     }
 
     /// Enables/Disables compression algorithm runtime.
     ///
-    /// If val is 1: compression algorithm is active at runtime
-    pub async fn fifo_compress_algo_real_time_set(
+    /// If val is true: compression algorithm is active at runtime
+    pub async fn fifo_compress_algo_real_time_enable_set(
         &mut self,
-        val: u8,
+        val: bool,
     ) -> Result<(), Error<B::Error>> {
         let mut fifo_ctrl2 = FifoCtrl2::read(self).await?;
-        fifo_ctrl2.set_fifo_compr_rt_en(val);
+        fifo_ctrl2.set_fifo_compr_rt_en(val as u8);
         fifo_ctrl2.write(self).await?;
 
         self.operate_over_embed(async |state| {
             let mut emb_func_en_b = EmbFuncEnB::read(state).await?;
-            emb_func_en_b.set_fifo_compr_en(val);
+            emb_func_en_b.set_fifo_compr_en(val as u8);
             emb_func_en_b.write(state).await
         })
         .await
     }
 
+    /// Enables/Disables compression algorithm runtime.
+    ///
+    /// If val is 1: compression algorithm is active at runtime
+    #[deprecated(
+        since = "2.1.0",
+        note = "please use fifo_compress_algo_real_time_enable_set"
+    )]
+    pub async fn fifo_compress_algo_real_time_set(
+        &mut self,
+        val: u8,
+    ) -> Result<(), Error<B::Error>> {
+        self.fifo_compress_algo_real_time_enable_set(val & 0x01 == 1)
+            .await
+    }
+
     /// Get the configuration (enable/disable) compression algorithm runtime.
-    pub async fn fifo_compress_algo_real_time_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn fifo_compress_algo_real_time_enable_get(
+        &mut self,
+    ) -> Result<bool, Error<B::Error>> {
         FifoCtrl2::read(self)
             .await
-            .map(|reg| reg.fifo_compr_rt_en())
+            .map(|reg| reg.fifo_compr_rt_en() == 1)
+    }
+
+    /// Get the configuration (enable/disable) compression algorithm runtime.
+    #[deprecated(
+        since = "2.1.0",
+        note = "please use fifo_compress_algo_real_time_enable_get"
+    )]
+    pub async fn fifo_compress_algo_real_time_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.fifo_compress_algo_real_time_enable_get()
+            .await
+            .map(u8::from)
     }
 
     /// Sensing chain FIFO stop values memorization at threshold level.
-    pub async fn fifo_stop_on_wtm_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn fifo_stop_on_wtm_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut fifo_ctrl2 = FifoCtrl2::read(self).await?;
-        fifo_ctrl2.set_stop_on_wtm(val);
+        fifo_ctrl2.set_stop_on_wtm(val as u8);
         fifo_ctrl2.write(self).await
     }
 
+    /// Sensing chain FIFO stop values memorization at threshold level.
+    #[deprecated(since = "2.1.0", note = "please use fifo_stop_on_wtm_enable_set")]
+    pub async fn fifo_stop_on_wtm_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.fifo_stop_on_wtm_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the configuration (enable/disable) for sensing chain FIFO stop values memorization at threshold level.
+    pub async fn fifo_stop_on_wtm_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        FifoCtrl2::read(self)
+            .await
+            .map(|reg| reg.stop_on_wtm() == 1)
+    }
+
+    /// Get the configuration (enable/disable) for sensing chain FIFO stop values memorization at threshold level.
+    #[deprecated(since = "2.1.0", note = "please use fifo_stop_on_wtm_enable_get")]
     pub async fn fifo_stop_on_wtm_get(&mut self) -> Result<u8, Error<B::Error>> {
-        FifoCtrl2::read(self).await.map(|reg| reg.stop_on_wtm())
+        self.fifo_stop_on_wtm_enable_get().await.map(u8::from)
     }
 
     /// Selects Batch Data Rate (write frequency in FIFO) for accelerometer data.
@@ -2191,15 +3218,29 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     /// Get FIFO status
     ///
     /// Return a `FifoStatus` object
-    pub async fn fifo_status_get(&mut self) -> Result<FifoStatus, Error<B::Error>> {
+    pub async fn fifo_state_get(&mut self) -> Result<FifoState, Error<B::Error>> {
         let status = FifoStatusReg::read(self).await?;
 
+        Ok(FifoState {
+            level: status.diff_fifo(),
+            bdr: status.counter_bdr_ia() == 1,
+            full: status.fifo_full_ia() == 1,
+            overrun: status.fifo_ovr_ia() == 1,
+            watermark: status.fifo_wtm_ia() == 1,
+        })
+    }
+
+    /// Get FIFO status
+    #[deprecated(since = "2.1.0", note = "please use fifo_state_get")]
+    pub async fn fifo_status_get(&mut self) -> Result<FifoStatus, Error<B::Error>> {
+        let val = self.fifo_state_get().await?;
+
         Ok(FifoStatus {
-            fifo_bdr: status.counter_bdr_ia(),
-            fifo_ovr: status.fifo_ovr_ia(),
-            fifo_full: status.fifo_full_ia(),
-            fifo_th: status.fifo_wtm_ia(),
-            fifo_level: status.diff_fifo(),
+            fifo_level: val.level,
+            fifo_bdr: val.bdr as u8,
+            fifo_full: val.full as u8,
+            fifo_ovr: val.overrun as u8,
+            fifo_th: val.watermark as u8,
         })
     }
 
@@ -2208,19 +3249,31 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     /// # Arguments
     ///
     /// * `val`: 0 (disable) / 1 (enabled)
-    pub async fn fifo_hg_xl_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn fifo_hg_xl_batch_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut cbdr_reg = CounterBdrReg1::read(self).await?;
-        cbdr_reg.set_xl_hg_batch_en(val & 0x01);
+        cbdr_reg.set_xl_hg_batch_en(val as u8);
         cbdr_reg.write(self).await
     }
 
+    /// Enable FIFO Batch for hg XL data.
+    #[deprecated(since = "2.1.0", note = "please use fifo_hg_xl_batch_enable_set")]
+    pub async fn fifo_hg_xl_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.fifo_hg_xl_batch_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get actual configuration of FIFO Batch for hg XL data.
     ///
     /// If returns 1, it's enabled
-    pub async fn fifo_hg_xl_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn fifo_hg_xl_batch_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         CounterBdrReg1::read(self)
             .await
-            .map(|reg| reg.xl_hg_batch_en())
+            .map(|reg| reg.xl_hg_batch_en() == 1)
+    }
+
+    /// Get actual configuration of FIFO Batch for hg XL data.
+    #[deprecated(since = "2.1.0", note = "please use fifo_hg_xl_batch_enable_get")]
+    pub async fn fifo_hg_xl_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.fifo_hg_xl_batch_enable_get().await.map(u8::from)
     }
 
     /// Set the FIFO mode.
@@ -2240,15 +3293,29 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     /// Enables/Disables FIFO batching of EIS gyroscope output values.
     ///
     /// If val is 1 FIFO batching is enabled
-    pub async fn fifo_gy_eis_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn fifo_gy_eis_batch_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut fifo_ctrl4 = FifoCtrl4::read(self).await?;
-        fifo_ctrl4.set_g_eis_fifo_en(val);
+        fifo_ctrl4.set_g_eis_fifo_en(val as u8);
         fifo_ctrl4.write(self).await
     }
 
+    /// Enables/Disables FIFO batching of EIS gyroscope output values.
+    #[deprecated(since = "2.1.0", note = "please use fifo_gy_eis_batch_enable_set")]
+    pub async fn fifo_gy_eis_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.fifo_gy_eis_batch_enable_set(val & 0x01 == 1).await
+    }
+
+    /// Get the configuration (enabled/disabled) for FIFO batching of EIS gyroscope output values.
+    pub async fn fifo_gy_eis_batch_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        FifoCtrl4::read(self)
+            .await
+            .map(|reg| reg.g_eis_fifo_en() == 1)
+    }
+
     /// Get the configuration (enabled/disabled) for FIFO batching of EIS gyroscope output values.
+    #[deprecated(since = "2.1.0", note = "please use fifo_gy_eis_batch_enable_get")]
     pub async fn fifo_gy_eis_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
-        FifoCtrl4::read(self).await.map(|reg| reg.g_eis_fifo_en())
+        self.fifo_gy_eis_batch_enable_get().await.map(u8::from)
     }
 
     /// Set batch data rate (write frequency in FIFO) for temperature data.
@@ -2290,6 +3357,23 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(val)
     }
 
+    /// Get a FifoTimeAligner for the actual FIFO batching configuration.
+    ///
+    /// It reads the accelerometer/gyroscope batch data rates, the high-g
+    /// accelerometer ODR (if batched) and the ODR calibration value.
+    pub async fn fifo_time_aligner_get(&mut self) -> Result<FifoTimeAligner, Error<B::Error>> {
+        let xl = self.fifo_xl_batch_get().await?;
+        let gy = self.fifo_gy_batch_get().await?;
+        let hg_xl = if self.fifo_hg_xl_batch_enable_get().await? {
+            self.hg_xl_setup_get().await?.0
+        } else {
+            HgXlDataRate::Off
+        };
+        let odr_cal = self.odr_cal_reg_get().await?;
+
+        Ok(FifoTimeAligner::new(xl, gy, hg_xl, odr_cal))
+    }
+
     /// Set the threshold for the internal counter of batch events.
     ///
     /// When this counter reaches the threshold, the counter is reset and the interrupt flag is set to 1.
@@ -2339,85 +3423,204 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(FifoOutRaw { tag, cnt, data })
     }
 
+    /// Get the next FIFO word decoded as a FifoFrame.
+    ///
+    /// Words with a tag that is not known are returned as FifoFrame::Unknown.
+    pub async fn fifo_out_get(&mut self) -> Result<FifoFrame, Error<B::Error>> {
+        let mut buf = [0u8; FIFO_WORD_SIZE];
+        self.read_from_register(Reg::FifoDataOutTag as u8, &mut buf)
+            .await?;
+        Ok(FifoFrame::from_bytes(&buf).unwrap_or(FifoFrame::Empty))
+    }
+
+    /// Read the FIFO words available, up to `buf.len()`, with burst reads.
+    ///
+    /// Each word is 7 bytes (tag + data); when FIFO_DATA_OUT_Z_H (0x7E) is
+    /// reached the address rolls back to FIFO_DATA_OUT_TAG (0x78), so several
+    /// words are read in the same transaction (at most CHUNK_SIZE bytes).
+    ///
+    /// Returns the number of words stored in `buf`.
+    pub async fn fifo_read_batch(
+        &mut self,
+        buf: &mut [FifoOutRaw],
+    ) -> Result<usize, Error<B::Error>> {
+        let level = self.fifo_state_get().await?.level as usize;
+        let num = level.min(buf.len());
+
+        let mut raw = [0u8; (CHUNK_SIZE / FIFO_WORD_SIZE) * FIFO_WORD_SIZE];
+        for words in buf[..num].chunks_mut(CHUNK_SIZE / FIFO_WORD_SIZE) {
+            let bytes = &mut raw[..words.len() * FIFO_WORD_SIZE];
+            self.read_from_register(Reg::FifoDataOutTag as u8, bytes)
+                .await?;
+
+            for (word, data) in words.iter_mut().zip(bytes.chunks_exact(FIFO_WORD_SIZE)) {
+                *word = FifoOutRaw::from_bytes(data).unwrap_or_default();
+            }
+        }
+
+        Ok(num)
+    }
+
+    /// Read the FIFO words available into a byte buffer with a single burst read.
+    ///
+    /// The buffer is filled with 7 bytes (tag + data) per word, as they are
+    /// read from FIFO_DATA_OUT_TAG (0x78), and can be decoded with
+    /// FifoOutRaw::from_bytes or FifoFrame::from_bytes.
+    ///
+    /// Returns the number of words stored in `buf`.
+    pub async fn fifo_read_batch_raw(&mut self, buf: &mut [u8]) -> Result<usize, Error<B::Error>> {
+        let level = self.fifo_state_get().await?.level;
+        self.fifo_words_read_raw(level, buf).await
+    }
+
+    /// Read up to `level` FIFO words into a byte buffer with a single burst read,
+    /// when the FIFO level is already known.
+    ///
+    /// Returns the number of words stored in `buf`.
+    pub(crate) async fn fifo_words_read_raw(
+        &mut self,
+        level: u16,
+        buf: &mut [u8],
+    ) -> Result<usize, Error<B::Error>> {
+        let num = (level as usize).min(buf.len() / FIFO_WORD_SIZE);
+
+        if num > 0 {
+            self.read_from_register(Reg::FifoDataOutTag as u8, &mut buf[..num * FIFO_WORD_SIZE])
+                .await?;
+        }
+
+        Ok(num)
+    }
+
     /// Set the batching in FIFO buffer of step counter value.
-    pub async fn fifo_stpcnt_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn fifo_stpcnt_batch_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_fifo_en_a = EmbFuncFifoEnA::read(state).await?;
-            emb_func_fifo_en_a.set_step_counter_fifo_en(val);
+            emb_func_fifo_en_a.set_step_counter_fifo_en(val as u8);
             emb_func_fifo_en_a.write(state).await
         })
         .await
     }
 
+    /// Set the batching in FIFO buffer of step counter value.
+    #[deprecated(since = "2.1.0", note = "please use fifo_stpcnt_batch_enable_set")]
+    pub async fn fifo_stpcnt_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.fifo_stpcnt_batch_enable_set(val & 0x01 == 1).await
+    }
+
+    /// Get the acutal batching in FIFO buffer of step counter value.
+    pub async fn fifo_stpcnt_batch_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        let emb_func_fifo_en_a = self.operate_over_embed(EmbFuncFifoEnA::read).await?;
+
+        Ok(emb_func_fifo_en_a.step_counter_fifo_en() == 1)
+    }
+
     /// Get the acutal batching in FIFO buffer of step counter value.
+    #[deprecated(since = "2.1.0", note = "please use fifo_stpcnt_batch_enable_get")]
     pub async fn fifo_stpcnt_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
-        let emb_func_fifo_en_a = self.operate_over_embed(EmbFuncFifoEnA::read).await?;
-
-        let val: u8 = emb_func_fifo_en_a.step_counter_fifo_en();
-        Ok(val)
+        self.fifo_stpcnt_batch_enable_get().await.map(u8::from)
     }
 
     /// Set Batching in FIFO buffer of finite state machine results.
-    pub async fn fifo_fsm_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn fifo_fsm_batch_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_fifo_en_b = EmbFuncFifoEnB::read(state).await?;
-            emb_func_fifo_en_b.set_fsm_fifo_en(val);
+            emb_func_fifo_en_b.set_fsm_fifo_en(val as u8);
             emb_func_fifo_en_b.write(state).await
         })
         .await
     }
 
+    /// Set Batching in FIFO buffer of finite state machine results.
+    #[deprecated(since = "2.1.0", note = "please use fifo_fsm_batch_enable_set")]
+    pub async fn fifo_fsm_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.fifo_fsm_batch_enable_set(val & 0x01 == 1).await
+    }
+
     /// Batching in FIFO buffer of finite state machine results.
-    pub async fn fifo_fsm_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn fifo_fsm_batch_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         self.operate_over_embed(async |state| {
             EmbFuncFifoEnB::read(state)
                 .await
-                .map(|reg| reg.fsm_fifo_en())
+                .map(|reg| reg.fsm_fifo_en() == 1)
         })
         .await
     }
 
+    /// Batching in FIFO buffer of finite state machine results.
+    #[deprecated(since = "2.1.0", note = "please use fifo_fsm_batch_enable_get")]
+    pub async fn fifo_fsm_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.fifo_fsm_batch_enable_get().await.map(u8::from)
+    }
+
     /// Enables/Disables batching in FIFO buffer of machine learning core results.
-    pub async fn fifo_mlc_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn fifo_mlc_batch_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_fifo_en_a = EmbFuncFifoEnA::read(state).await?;
-            emb_func_fifo_en_a.set_mlc_fifo_en(val);
+            emb_func_fifo_en_a.set_mlc_fifo_en(val as u8);
             emb_func_fifo_en_a.write(state).await
         })
         .await
     }
 
+    /// Enables/Disables batching in FIFO buffer of machine learning core results.
+    #[deprecated(since = "2.1.0", note = "please use fifo_mlc_batch_enable_set")]
+    pub async fn fifo_mlc_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.fifo_mlc_batch_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the configuration (enables/disables) batching in FIFO buffer of machine learning core results.
-    pub async fn fifo_mlc_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn fifo_mlc_batch_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         self.operate_over_embed(async |state| {
             EmbFuncFifoEnA::read(state)
                 .await
-                .map(|reg| reg.mlc_fifo_en())
+                .map(|reg| reg.mlc_fifo_en() == 1)
         })
         .await
     }
 
+    /// Get the configuration (enables/disables) batching in FIFO buffer of machine learning core results.
+    #[deprecated(since = "2.1.0", note = "please use fifo_mlc_batch_enable_get")]
+    pub async fn fifo_mlc_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.fifo_mlc_batch_enable_get().await.map(u8::from)
+    }
+
     /// Enables batching in FIFO buffer of machine learning core filters and features.
-    pub async fn fifo_mlc_filt_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn fifo_mlc_filt_batch_enable_set(
+        &mut self,
+        val: bool,
+    ) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_fifo_en_b = EmbFuncFifoEnB::read(state).await?;
-            emb_func_fifo_en_b.set_mlc_filter_feature_fifo_en(val);
+            emb_func_fifo_en_b.set_mlc_filter_feature_fifo_en(val as u8);
             emb_func_fifo_en_b.write(state).await
         })
         .await
     }
 
+    /// Enables batching in FIFO buffer of machine learning core filters and features.
+    #[deprecated(since = "2.1.0", note = "please use fifo_mlc_filt_batch_enable_set")]
+    pub async fn fifo_mlc_filt_batch_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.fifo_mlc_filt_batch_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the configuration (enable/disable) of batching in FIFO buffer
     /// of machine learning core filters and features.
-    pub async fn fifo_mlc_filt_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn fifo_mlc_filt_batch_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         self.operate_over_embed(async |state| {
             EmbFuncFifoEnB::read(state)
                 .await
-                .map(|reg| reg.mlc_filter_feature_fifo_en())
+                .map(|reg| reg.mlc_filter_feature_fifo_en() == 1)
         })
         .await
     }
 
+    /// Get the configuration (enable/disable) of batching in FIFO buffer
+    #[deprecated(since = "2.1.0", note = "please use fifo_mlc_filt_batch_enable_get")]
+    pub async fn fifo_mlc_filt_batch_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.fifo_mlc_filt_batch_enable_get().await.map(u8::from)
+    }
+
     /// Enable FIFO data batching of target idx.
     pub async fn fifo_sh_batch_target_set(
         &mut self,
@@ -2576,15 +3779,27 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enables/Disable gyroscope digital LPF1 filter.
-    pub async fn filt_gy_lp1_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn filt_gy_lp1_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ctrl7 = Ctrl7::read(self).await?;
-        ctrl7.set_lpf1_g_en(val);
+        ctrl7.set_lpf1_g_en(val as u8);
         ctrl7.write(self).await
     }
 
+    /// Enables/Disable gyroscope digital LPF1 filter.
+    #[deprecated(since = "2.1.0", note = "please use filt_gy_lp1_enable_set")]
+    pub async fn filt_gy_lp1_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.filt_gy_lp1_enable_set(val & 0x01 == 1).await
+    }
+
+    /// Get the configuration (enables/disables) gyroscope digital LPF1 filter.
+    pub async fn filt_gy_lp1_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        Ctrl7::read(self).await.map(|ctrl7| ctrl7.lpf1_g_en() == 1)
+    }
+
     /// Get the configuration (enables/disables) gyroscope digital LPF1 filter.
+    #[deprecated(since = "2.1.0", note = "please use filt_gy_lp1_enable_get")]
     pub async fn filt_gy_lp1_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ctrl7::read(self).await.map(|ctrl7| ctrl7.lpf1_g_en())
+        self.filt_gy_lp1_enable_get().await.map(u8::from)
     }
 
     /// Setup xl filter pipeline for lpf1 filter to UI
@@ -2645,41 +3860,82 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enable/Disable accelerometer LPS2 (Low Pass Filter 2) filtering stage.
-    pub async fn filt_xl_lp2_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn filt_xl_lp2_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ctrl9 = Ctrl9::read(self).await?;
-        ctrl9.set_lpf2_xl_en(val);
+        ctrl9.set_lpf2_xl_en(val as u8);
         ctrl9.write(self).await
     }
 
+    /// Enable/Disable accelerometer LPS2 (Low Pass Filter 2) filtering stage.
+    #[deprecated(since = "2.1.0", note = "please use filt_xl_lp2_enable_set")]
+    pub async fn filt_xl_lp2_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.filt_xl_lp2_enable_set(val & 0x01 == 1).await
+    }
+
+    /// Get the accelerometer LPS2 (Low Pass Filter 2) filtering stage.
+    pub async fn filt_xl_lp2_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        Ctrl9::read(self).await.map(|ctrl9| ctrl9.lpf2_xl_en() == 1)
+    }
+
     /// Get the accelerometer LPS2 (Low Pass Filter 2) filtering stage.
+    #[deprecated(since = "2.1.0", note = "please use filt_xl_lp2_enable_get")]
     pub async fn filt_xl_lp2_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ctrl9::read(self).await.map(|ctrl9| ctrl9.lpf2_xl_en())
+        self.filt_xl_lp2_enable_get().await.map(u8::from)
     }
 
     /// Accelerometer slope filter / high-pass filter selection.
-    pub async fn filt_xl_hp_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn filt_xl_hp_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ctrl9 = Ctrl9::read(self).await?;
-        ctrl9.set_hp_slope_xl_en(val);
+        ctrl9.set_hp_slope_xl_en(val as u8);
         ctrl9.write(self).await
     }
 
+    /// Accelerometer slope filter / high-pass filter selection.
+    #[deprecated(since = "2.1.0", note = "please use filt_xl_hp_enable_set")]
+    pub async fn filt_xl_hp_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.filt_xl_hp_enable_set(val & 0x01 == 1).await
+    }
+
+    /// Get the Accelerometer slope filter / high-pass filter selection.
+    pub async fn filt_xl_hp_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        Ctrl9::read(self)
+            .await
+            .map(|ctrl9| ctrl9.hp_slope_xl_en() == 1)
+    }
+
     /// Get the Accelerometer slope filter / high-pass filter selection.
+    #[deprecated(since = "2.1.0", note = "please use filt_xl_hp_enable_get")]
     pub async fn filt_xl_hp_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ctrl9::read(self).await.map(|ctrl9| ctrl9.hp_slope_xl_en())
+        self.filt_xl_hp_enable_get().await.map(u8::from)
     }
 
     /// Enables accelerometer LPF2 and HPF fast-settling mode. The filter sets the first sample.
-    pub async fn filt_xl_fast_settling_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn filt_xl_fast_settling_enable_set(
+        &mut self,
+        val: bool,
+    ) -> Result<(), Error<B::Error>> {
         let mut ctrl9 = Ctrl9::read(self).await?;
-        ctrl9.set_xl_fastsettl_mode(val);
+        ctrl9.set_xl_fastsettl_mode(val as u8);
         ctrl9.write(self).await
     }
 
+    /// Enables accelerometer LPF2 and HPF fast-settling mode. The filter sets the first sample.
+    #[deprecated(since = "2.1.0", note = "please use filt_xl_fast_settling_enable_set")]
+    pub async fn filt_xl_fast_settling_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.filt_xl_fast_settling_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get accelerometer LPF2 and HPF fast-settling mode. The filter sets the first sample.
-    pub async fn filt_xl_fast_settling_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn filt_xl_fast_settling_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         Ctrl9::read(self)
             .await
-            .map(|ctrl9| ctrl9.xl_fastsettl_mode())
+            .map(|ctrl9| ctrl9.xl_fastsettl_mode() == 1)
+    }
+
+    /// Get accelerometer LPF2 and HPF fast-settling mode. The filter sets the first sample.
+    #[deprecated(since = "2.1.0", note = "please use filt_xl_fast_settling_enable_get")]
+    pub async fn filt_xl_fast_settling_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.filt_xl_fast_settling_enable_get().await.map(u8::from)
     }
 
     /// Set Accelerometer high-pass filter mode.
@@ -2733,19 +3989,34 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     /// Mask hw function triggers when xl is settling.
     ///
     /// If val is 1 it enables the masking
-    pub async fn mask_trigger_xl_settl_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn mask_trigger_xl_settl_enable_set(
+        &mut self,
+        val: bool,
+    ) -> Result<(), Error<B::Error>> {
         let mut tap_cfg0 = TapCfg0::read(self).await?;
-        tap_cfg0.set_hw_func_mask_xl_settl(val & 0x01);
+        tap_cfg0.set_hw_func_mask_xl_settl(val as u8);
         tap_cfg0.write(self).await
     }
 
+    /// Mask hw function triggers when xl is settling.
+    #[deprecated(since = "2.1.0", note = "please use mask_trigger_xl_settl_enable_set")]
+    pub async fn mask_trigger_xl_settl_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.mask_trigger_xl_settl_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get current configuration (enable/disable) of mask hw function
     ///
     /// triggers when xl is settling.
-    pub async fn mask_trigger_xl_settl_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn mask_trigger_xl_settl_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         TapCfg0::read(self)
             .await
-            .map(|tap_cfg0| tap_cfg0.hw_func_mask_xl_settl())
+            .map(|tap_cfg0| tap_cfg0.hw_func_mask_xl_settl() == 1)
+    }
+
+    /// Get current configuration (enable/disable) of mask hw function
+    #[deprecated(since = "2.1.0", note = "please use mask_trigger_xl_settl_enable_get")]
+    pub async fn mask_trigger_xl_settl_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.mask_trigger_xl_settl_enable_get().await.map(u8::from)
     }
 
     /// Configure the LPF2 filter on 6D (sixd) function.
@@ -2859,34 +4130,21 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enable Finite State Machine (FSM) feature.
-    pub async fn fsm_mode_set(&mut self, val: FsmMode) -> Result<(), Error<B::Error>> {
+    pub async fn fsm_enable_set(&mut self, val: FsmSet) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_en_b = EmbFuncEnB::read(state).await?;
             let mut fsm_enable = FsmEnable::read(state).await?;
 
-            if (val.fsm1_en
-                | val.fsm2_en
-                | val.fsm3_en
-                | val.fsm4_en
-                | val.fsm5_en
-                | val.fsm6_en
-                | val.fsm7_en
-                | val.fsm8_en)
-                == 1
-            {
-                emb_func_en_b.set_fsm_en(1);
-            } else {
-                emb_func_en_b.set_fsm_en(0);
-            }
+            emb_func_en_b.set_fsm_en(!val.is_empty() as u8);
 
-            fsm_enable.set_fsm1_en(val.fsm1_en);
-            fsm_enable.set_fsm2_en(val.fsm2_en);
-            fsm_enable.set_fsm3_en(val.fsm3_en);
-            fsm_enable.set_fsm4_en(val.fsm4_en);
-            fsm_enable.set_fsm5_en(val.fsm5_en);
-            fsm_enable.set_fsm6_en(val.fsm6_en);
-            fsm_enable.set_fsm7_en(val.fsm7_en);
-            fsm_enable.set_fsm8_en(val.fsm8_en);
+            fsm_enable.set_fsm1_en(val.bit(FsmSet::FSM1));
+            fsm_enable.set_fsm2_en(val.bit(FsmSet::FSM2));
+            fsm_enable.set_fsm3_en(val.bit(FsmSet::FSM3));
+            fsm_enable.set_fsm4_en(val.bit(FsmSet::FSM4));
+            fsm_enable.set_fsm5_en(val.bit(FsmSet::FSM5));
+            fsm_enable.set_fsm6_en(val.bit(FsmSet::FSM6));
+            fsm_enable.set_fsm7_en(val.bit(FsmSet::FSM7));
+            fsm_enable.set_fsm8_en(val.bit(FsmSet::FSM8));
 
             fsm_enable.write(state).await?;
             emb_func_en_b.write(state).await?;
@@ -2896,23 +4154,34 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         .await
     }
 
+    /// Enable Finite State Machine (FSM) feature.
+    #[deprecated(since = "2.1.0", note = "please use fsm_enable_set")]
+    pub async fn fsm_mode_set(&mut self, val: FsmMode) -> Result<(), Error<B::Error>> {
+        self.fsm_enable_set(FsmSet::from(&val)).await
+    }
+
     /// Get the enabled Finite State Machine (FSM) feature.
-    pub async fn fsm_mode_get(&mut self) -> Result<FsmMode, Error<B::Error>> {
+    pub async fn fsm_enable_get(&mut self) -> Result<FsmSet, Error<B::Error>> {
         let fsm_enable = self.operate_over_embed(FsmEnable::read).await?;
 
-        let val = FsmMode {
-            fsm1_en: fsm_enable.fsm1_en(),
-            fsm2_en: fsm_enable.fsm2_en(),
-            fsm3_en: fsm_enable.fsm3_en(),
-            fsm4_en: fsm_enable.fsm4_en(),
-            fsm5_en: fsm_enable.fsm5_en(),
-            fsm6_en: fsm_enable.fsm6_en(),
-            fsm7_en: fsm_enable.fsm7_en(),
-            fsm8_en: fsm_enable.fsm8_en(),
-        };
+        let mut val = FsmSet::empty();
+        val.set(FsmSet::FSM1, fsm_enable.fsm1_en() == 1);
+        val.set(FsmSet::FSM2, fsm_enable.fsm2_en() == 1);
+        val.set(FsmSet::FSM3, fsm_enable.fsm3_en() == 1);
+        val.set(FsmSet::FSM4, fsm_enable.fsm4_en() == 1);
+        val.set(FsmSet::FSM5, fsm_enable.fsm5_en() == 1);
+        val.set(FsmSet::FSM6, fsm_enable.fsm6_en() == 1);
+        val.set(FsmSet::FSM7, fsm_enable.fsm7_en() == 1);
+        val.set(FsmSet::FSM8, fsm_enable.fsm8_en() == 1);
         Ok(val)
     }
 
+    /// Get the enabled Finite State Machine (FSM) feature.
+    #[deprecated(since = "2.1.0", note = "please use fsm_enable_get")]
+    pub async fn fsm_mode_get(&mut self) -> Result<FsmMode, Error<B::Error>> {
+        self.fsm_enable_get().await.map(FsmMode::from)
+    }
+
     /// Set the FSM long counter status register.
     ///
     /// Long counter value is an unsigned integer value (16-bit format).
@@ -2958,33 +4227,45 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
         Ok(val)
     }
 
-    /// Set SFLP GBIAS value for x/y/z axis.
+    /// Set SFLP GBIAS value for x/y/z axis, in dps.
     ///
     /// The register value is expressed as half-precision
     /// floating-point format: SEEEEEFFFFFFFFFF (S: 1 sign bit; E: 5 exponent
     /// bits; F: 10 fraction bits).
+    #[deprecated(since = "2.1.0", note = "please use sflp_gbias_set")]
     pub async fn sflp_game_gbias_set(&mut self, val: &SflpGbias) -> Result<(), Error<B::Error>> {
-        let sflp_odr = self.sflp_data_rate_get().await?;
-
-        let k = match sflp_odr {
-            SflpDataRate::_30hz => 0.02,
-            SflpDataRate::_60hz => 0.01,
-            SflpDataRate::_120hz => 0.005,
-            SflpDataRate::_240hz => 0.0025,
-            SflpDataRate::_480hz => 0.00125,
-            _ => 0.04, // SflpDataRate::_15hz => 0.04,
-        };
+        self.sflp_gbias_set(Vector3::from(val.clone())).await
+    }
 
-        self.operate_over_embed(async |state| {
-            // compute gbias as half precision float in order to be put in embedded advanced feature register
-            let gbias = SflpGbiasXYZInit {
-                x: super::npy_float_to_half(val.gbias_x * (core::f32::consts::PI / 180.0) / k),
-                y: super::npy_float_to_half(val.gbias_y * (core::f32::consts::PI / 180.0) / k),
-                z: super::npy_float_to_half(val.gbias_z * (core::f32::consts::PI / 180.0) / k),
-            };
-            gbias.write(state).await
-        })
-        .await
+    /// Set the SFLP gyroscope bias initialization value in mdps.
+    ///
+    /// The unit matches `sflp_gbias_get`, so the estimate read at the end of
+    /// a run can be written back to initialize the next one. The value is
+    /// scaled with the actual SFLP data rate, which must be set first.
+    pub async fn sflp_gbias_set(
+        &mut self,
+        val: Vector3<AngularRate<MilliDps>>,
+    ) -> Result<(), Error<B::Error>> {
+        let k = sflp_gbias_scale(self.sflp_data_rate_get().await?);
+        let [x, y, z] =
+            [val.x, val.y, val.z].map(|rate| super::npy_float_to_half(rate.to_rad_s().value() / k));
+
+        self.operate_over_embed(async |state| SflpGbiasXYZInit { x, y, z }.write(state).await)
+            .await
+    }
+
+    /// Get the SFLP gyroscope bias initialization value in mdps.
+    ///
+    /// The value is scaled with the actual SFLP data rate.
+    pub async fn sflp_gbias_init_get(
+        &mut self,
+    ) -> Result<Vector3<AngularRate<MilliDps>>, Error<B::Error>> {
+        let k = sflp_gbias_scale(self.sflp_data_rate_get().await?);
+        let init = self.operate_over_embed(SflpGbiasXYZInit::read).await?;
+
+        Ok(Vector3::new(init.x, init.y, init.z).map(|half| {
+            AngularRate::<RadiansPerSecond>::new(super::npy_half_to_float(half) * k).to_mdps()
+        }))
     }
 
     /// Set the External sensor sensitivity value register for the Finite State Machine.
@@ -3163,19 +4444,34 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     /// Enable/disable High-g accelerometer peak tracking.
     ///
     /// If val equals to 1 enables the feature
-    pub async fn xl_hg_peak_tracking_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn xl_hg_peak_tracking_enable_set(
+        &mut self,
+        val: bool,
+    ) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_init_b = EmbFuncInitB::read(state).await?;
-            emb_func_init_b.set_pt_init(val);
+            emb_func_init_b.set_pt_init(val as u8);
             emb_func_init_b.write(state).await
         })
         .await
     }
 
+    /// Enable/disable High-g accelerometer peak tracking.
+    #[deprecated(since = "2.1.0", note = "please use xl_hg_peak_tracking_enable_set")]
+    pub async fn xl_hg_peak_tracking_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.xl_hg_peak_tracking_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the configuration (enable/disable) for High-g accelerometer peak tracking enable.
-    pub async fn xl_hg_peak_tracking_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn xl_hg_peak_tracking_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         let emb_func_init_b = self.operate_over_embed(EmbFuncInitB::read).await?;
-        Ok(emb_func_init_b.pt_init())
+        Ok(emb_func_init_b.pt_init() == 1)
+    }
+
+    /// Get the configuration (enable/disable) for High-g accelerometer peak tracking enable.
+    #[deprecated(since = "2.1.0", note = "please use xl_hg_peak_tracking_enable_get")]
+    pub async fn xl_hg_peak_tracking_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.xl_hg_peak_tracking_enable_get().await.map(u8::from)
     }
 
     /// Set the High-g accelerometer sensitivity value register for FSM and MLC.
@@ -3502,19 +4798,31 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     /// In User Interface (UI) full control mode, enables IF2 (OIS Interface) for reading OIS data.
     ///
     /// This function works also on OIS (UI_CTRL1_OIS = IF2_CTRL1_OIS).
-    pub async fn ois_on_if2_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn ois_on_if2_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ui_ctrl1_ois = UiCtrl1Ois::read(self).await?;
-        ui_ctrl1_ois.set_if2_spi_read_en(val);
+        ui_ctrl1_ois.set_if2_spi_read_en(val as u8);
         ui_ctrl1_ois.write(self).await
     }
 
+    /// In User Interface (UI) full control mode, enables IF2 (OIS Interface) for reading OIS data.
+    #[deprecated(since = "2.1.0", note = "please use ois_on_if2_enable_set")]
+    pub async fn ois_on_if2_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.ois_on_if2_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get User Interface (UI) full control mode, enables IF2 (OIS Interface) for reading OIS data.
     ///
     /// This function works also on OIS (UI_CTRL1_OIS = IF2_CTRL1_OIS).
-    pub async fn ois_on_if2_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn ois_on_if2_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         UiCtrl1Ois::read(self)
             .await
-            .map(|reg| reg.if2_spi_read_en())
+            .map(|reg| reg.if2_spi_read_en() == 1)
+    }
+
+    /// Get User Interface (UI) full control mode, enables IF2 (OIS Interface) for reading OIS data.
+    #[deprecated(since = "2.1.0", note = "please use ois_on_if2_enable_get")]
+    pub async fn ois_on_if2_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.ois_on_if2_enable_get().await.map(u8::from)
     }
 
     /// Enables gyroscope/accelerometer OIS chain.
@@ -3592,17 +4900,29 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     /// Enables/Disables 4D orientation detection.
     ///
     /// Z-axis position detection is disabled.
-    pub async fn four_d_mode_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn four_d_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut tap_ths_6d = TapThs6d::read(self).await?;
-        tap_ths_6d.set_d4d_en(val);
+        tap_ths_6d.set_d4d_en(val as u8);
         tap_ths_6d.write(self).await
     }
 
+    /// Enables/Disables 4D orientation detection.
+    #[deprecated(since = "2.1.0", note = "please use four_d_enable_set")]
+    pub async fn four_d_mode_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.four_d_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the configuration for 4D orientation detection enable.
     ///
     /// Z-axis position detection is disabled.
+    pub async fn four_d_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        TapThs6d::read(self).await.map(|reg| reg.d4d_en() == 1)
+    }
+
+    /// Get the configuration for 4D orientation detection enable.
+    #[deprecated(since = "2.1.0", note = "please use four_d_enable_get")]
     pub async fn four_d_mode_get(&mut self) -> Result<u8, Error<B::Error>> {
-        TapThs6d::read(self).await.map(|reg| reg.d4d_en())
+        self.four_d_enable_get().await.map(u8::from)
     }
 
     /// Set I3C configuration.
@@ -3680,43 +5000,67 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enables/disables sensor hub I2C controller.
-    pub async fn sh_controller_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn sh_controller_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         self.operate_over_sensor_hub(async |state| {
             let mut controller_config = ControllerConfig::read(state).await?;
-            controller_config.set_controller_on(val);
+            controller_config.set_controller_on(val as u8);
             controller_config.write(state).await
         })
         .await
     }
 
+    /// Enables/disables sensor hub I2C controller.
+    #[deprecated(since = "2.1.0", note = "please use sh_controller_enable_set")]
+    pub async fn sh_controller_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.sh_controller_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the Sensor hub I2C controller configuration.
-    pub async fn sh_controller_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn sh_controller_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         self.operate_over_sensor_hub(async |state| {
             let reg = ControllerConfig::read(state).await?;
-            Ok(reg.controller_on())
+            Ok(reg.controller_on() == 1)
         })
         .await
     }
 
+    /// Get the Sensor hub I2C controller configuration.
+    #[deprecated(since = "2.1.0", note = "please use sh_controller_enable_get")]
+    pub async fn sh_controller_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.sh_controller_enable_get().await.map(u8::from)
+    }
+
     /// Enable/disable I2C interface pass-through mode.
-    pub async fn sh_pass_through_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn sh_pass_through_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         self.operate_over_sensor_hub(async |state| {
             let mut controller_config = ControllerConfig::read(state).await?;
-            controller_config.set_pass_through_mode(val);
+            controller_config.set_pass_through_mode(val as u8);
             controller_config.write(state).await
         })
         .await
     }
 
+    /// Enable/disable I2C interface pass-through mode.
+    #[deprecated(since = "2.1.0", note = "please use sh_pass_through_enable_set")]
+    pub async fn sh_pass_through_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.sh_pass_through_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get I2C interface pass-through configuration.
-    pub async fn sh_pass_through_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn sh_pass_through_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         self.operate_over_sensor_hub(async |state| {
             let cfg = ControllerConfig::read(state).await?;
-            Ok(cfg.pass_through_mode())
+            Ok(cfg.pass_through_mode() == 1)
         })
         .await
     }
 
+    /// Get I2C interface pass-through configuration.
+    #[deprecated(since = "2.1.0", note = "please use sh_pass_through_enable_get")]
+    pub async fn sh_pass_through_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.sh_pass_through_enable_get().await.map(u8::from)
+    }
+
     /// Set Sensor hub trigger signal.
     pub async fn sh_syncro_mode_set(&mut self, val: ShSyncroMode) -> Result<(), Error<B::Error>> {
         self.operate_over_sensor_hub(async |state| {
@@ -3874,15 +5218,27 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enables/Disables pull-up on SDO pin of UI (User Interface).
-    pub async fn ui_sdo_pull_up_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn ui_sdo_pull_up_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut pin_ctrl = PinCtrl::read(self).await?;
-        pin_ctrl.set_sdo_pu_en(val);
+        pin_ctrl.set_sdo_pu_en(val as u8);
         pin_ctrl.write(self).await
     }
 
+    /// Enables/Disables pull-up on SDO pin of UI (User Interface).
+    #[deprecated(since = "2.1.0", note = "please use ui_sdo_pull_up_enable_set")]
+    pub async fn ui_sdo_pull_up_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.ui_sdo_pull_up_enable_set(val & 0x01 == 1).await
+    }
+
+    /// Get the pull-up on SDO pin of UI (User Interface) configuration.
+    pub async fn ui_sdo_pull_up_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        PinCtrl::read(self).await.map(|reg| reg.sdo_pu_en() == 1)
+    }
+
     /// Get the pull-up on SDO pin of UI (User Interface) configuration.
+    #[deprecated(since = "2.1.0", note = "please use ui_sdo_pull_up_enable_get")]
     pub async fn ui_sdo_pull_up_get(&mut self) -> Result<u8, Error<B::Error>> {
-        PinCtrl::read(self).await.map(|reg| reg.sdo_pu_en())
+        self.ui_sdo_pull_up_enable_get().await.map(u8::from)
     }
 
     /// Set Pad strength.
@@ -3931,15 +5287,27 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enables/Disables pull-up on SDA pin.
-    pub async fn ui_sda_pull_up_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn ui_sda_pull_up_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut if_cfg = IfCfg::read(self).await?;
-        if_cfg.set_sda_pu_en(val);
+        if_cfg.set_sda_pu_en(val as u8);
         if_cfg.write(self).await
     }
 
+    /// Enables/Disables pull-up on SDA pin.
+    #[deprecated(since = "2.1.0", note = "please use ui_sda_pull_up_enable_set")]
+    pub async fn ui_sda_pull_up_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.ui_sda_pull_up_enable_set(val & 0x01 == 1).await
+    }
+
+    /// Get pull-up configuration on SDA pin.
+    pub async fn ui_sda_pull_up_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
+        IfCfg::read(self).await.map(|reg| reg.sda_pu_en() == 1)
+    }
+
     /// Get pull-up configuration on SDA pin.
+    #[deprecated(since = "2.1.0", note = "please use ui_sda_pull_up_enable_get")]
     pub async fn ui_sda_pull_up_get(&mut self) -> Result<u8, Error<B::Error>> {
-        IfCfg::read(self).await.map(|reg| reg.sda_pu_en())
+        self.ui_sda_pull_up_enable_get().await.map(u8::from)
     }
 
     /// Set IF2 (OIS Interface) Serial Interface Mode.
@@ -3962,25 +5330,37 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enables/Disables significant motion detection function.
-    pub async fn sigmot_mode_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn sigmot_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_en_a = EmbFuncEnA::read(state).await?;
-            emb_func_en_a.set_sign_motion_en(val);
+            emb_func_en_a.set_sign_motion_en(val as u8);
             emb_func_en_a.write(state).await
         })
         .await
     }
 
+    /// Enables/Disables significant motion detection function.
+    #[deprecated(since = "2.1.0", note = "please use sigmot_enable_set")]
+    pub async fn sigmot_mode_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.sigmot_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the significant motion detection configuration.
-    pub async fn sigmot_mode_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn sigmot_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         self.operate_over_embed(async |state| {
             EmbFuncEnA::read(state)
                 .await
-                .map(|reg| reg.sign_motion_en())
+                .map(|reg| reg.sign_motion_en() == 1)
         })
         .await
     }
 
+    /// Get the significant motion detection configuration.
+    #[deprecated(since = "2.1.0", note = "please use sigmot_enable_get")]
+    pub async fn sigmot_mode_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.sigmot_enable_get().await.map(u8::from)
+    }
+
     /// Set step counter mode
     pub async fn stpcnt_mode_set(&mut self, val: StpcntMode) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
@@ -4067,23 +5447,40 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enables/Disables SFLP Game Rotation Vector (6x).
-    pub async fn sflp_game_rotation_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn sflp_game_rotation_enable_set(
+        &mut self,
+        val: bool,
+    ) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_en_a = EmbFuncEnA::read(state).await?;
-            emb_func_en_a.set_sflp_game_en(val);
+            emb_func_en_a.set_sflp_game_en(val as u8);
             emb_func_en_a.write(state).await
         })
         .await
     }
 
+    /// Enables/Disables SFLP Game Rotation Vector (6x).
+    #[deprecated(since = "2.1.0", note = "please use sflp_game_rotation_enable_set")]
+    pub async fn sflp_game_rotation_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.sflp_game_rotation_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the configuration (enable/disable) for SFLP Game Rotation Vector (6x).
-    pub async fn sflp_game_rotation_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn sflp_game_rotation_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         self.operate_over_embed(async |state| {
-            EmbFuncEnA::read(state).await.map(|reg| reg.sflp_game_en())
+            EmbFuncEnA::read(state)
+                .await
+                .map(|reg| reg.sflp_game_en() == 1)
         })
         .await
     }
 
+    /// Get the configuration (enable/disable) for SFLP Game Rotation Vector (6x).
+    #[deprecated(since = "2.1.0", note = "please use sflp_game_rotation_enable_get")]
+    pub async fn sflp_game_rotation_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.sflp_game_rotation_enable_get().await.map(u8::from)
+    }
+
     /// Set SFLP Data Rate (ODR).
     pub async fn sflp_data_rate_set(&mut self, val: SflpDataRate) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
@@ -4105,27 +5502,50 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Enable axis for Tap - Double Tap detection.
-    pub async fn tap_detection_set(&mut self, val: TapDetection) -> Result<(), Error<B::Error>> {
+    pub async fn tap_axes_set(&mut self, val: Axes) -> Result<(), Error<B::Error>> {
         let mut tap_cfg0 = TapCfg0::read(self).await?;
-        tap_cfg0.set_tap_x_en(val.tap_x_en);
-        tap_cfg0.set_tap_y_en(val.tap_y_en);
-        tap_cfg0.set_tap_z_en(val.tap_z_en);
+        tap_cfg0.set_tap_x_en(val.x as u8);
+        tap_cfg0.set_tap_y_en(val.y as u8);
+        tap_cfg0.set_tap_z_en(val.z as u8);
         tap_cfg0.write(self).await
     }
 
+    /// Enable axis for Tap - Double Tap detection.
+    #[deprecated(since = "2.1.0", note = "please use tap_axes_set")]
+    pub async fn tap_detection_set(&mut self, val: TapDetection) -> Result<(), Error<B::Error>> {
+        self.tap_axes_set(Axes {
+            x: val.tap_x_en & 0x01 == 1,
+            y: val.tap_y_en & 0x01 == 1,
+            z: val.tap_z_en & 0x01 == 1,
+        })
+        .await
+    }
+
     /// Get configuration for Tap on each axis - Double Tap detection.
-    pub async fn tap_detection_get(&mut self) -> Result<TapDetection, Error<B::Error>> {
+    pub async fn tap_axes_get(&mut self) -> Result<Axes, Error<B::Error>> {
         let tap_cfg0 = TapCfg0::read(self).await?;
 
-        let val = TapDetection {
-            tap_x_en: tap_cfg0.tap_x_en(),
-            tap_y_en: tap_cfg0.tap_y_en(),
-            tap_z_en: tap_cfg0.tap_z_en(),
+        let val = Axes {
+            x: tap_cfg0.tap_x_en() == 1,
+            y: tap_cfg0.tap_y_en() == 1,
+            z: tap_cfg0.tap_z_en() == 1,
         };
 
         Ok(val)
     }
 
+    /// Get configuration for Tap on each axis - Double Tap detection.
+    #[deprecated(since = "2.1.0", note = "please use tap_axes_get")]
+    pub async fn tap_detection_get(&mut self) -> Result<TapDetection, Error<B::Error>> {
+        let val = self.tap_axes_get().await?;
+
+        Ok(TapDetection {
+            tap_x_en: val.x as u8,
+            tap_y_en: val.y as u8,
+            tap_z_en: val.z as u8,
+        })
+    }
+
     /// Set Double Tap recognition thresholds - axis Tap
     pub async fn tap_thresholds_set(&mut self, val: TapThresholds) -> Result<(), Error<B::Error>> {
         let mut tap_cfg1 = TapCfg1::read(self).await?;
@@ -4233,43 +5653,67 @@ impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
     }
 
     /// Set Tilt mode.
-    pub async fn tilt_mode_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn tilt_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let mut emb_func_en_a = EmbFuncEnA::read(state).await?;
-            emb_func_en_a.set_tilt_en(val);
+            emb_func_en_a.set_tilt_en(val as u8);
             emb_func_en_a.write(state).await
         })
         .await
     }
 
+    /// Set Tilt mode.
+    #[deprecated(since = "2.1.0", note = "please use tilt_enable_set")]
+    pub async fn tilt_mode_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.tilt_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get Tilt mode.
-    pub async fn tilt_mode_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn tilt_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         self.operate_over_embed(async |state| {
             let reg = EmbFuncEnA::read(state).await?;
-            Ok(reg.tilt_en())
+            Ok(reg.tilt_en() == 1)
         })
         .await
     }
 
+    /// Get Tilt mode.
+    #[deprecated(since = "2.1.0", note = "please use tilt_enable_get")]
+    pub async fn tilt_mode_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.tilt_enable_get().await.map(u8::from)
+    }
+
     /// Get Timestamp raw data
     pub async fn timestamp_raw_get(&mut self) -> Result<u32, Error<B::Error>> {
         Timestamp::read(self).await.map(|time| time.0)
     }
 
     /// Enables timestamp counter.
-    pub async fn timestamp_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    pub async fn timestamp_enable_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut functions_enable = FunctionsEnable::read(self).await?;
-        functions_enable.set_timestamp_en(val);
+        functions_enable.set_timestamp_en(val as u8);
         functions_enable.write(self).await
     }
 
+    /// Enables timestamp counter.
+    #[deprecated(since = "2.1.0", note = "please use timestamp_enable_set")]
+    pub async fn timestamp_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+        self.timestamp_enable_set(val & 0x01 == 1).await
+    }
+
     /// Get the actual timestamp counter configuration.
     ///
     /// If return 1 timestamp counter is active
-    pub async fn timestamp_get(&mut self) -> Result<u8, Error<B::Error>> {
+    pub async fn timestamp_enable_get(&mut self) -> Result<bool, Error<B::Error>> {
         FunctionsEnable::read(self)
             .await
-            .map(|reg| reg.timestamp_en())
+            .map(|reg| reg.timestamp_en() == 1)
+    }
+
+    /// Get the actual timestamp counter configuration.
+    #[deprecated(since = "2.1.0", note = "please use timestamp_enable_get")]
+    pub async fn timestamp_get(&mut self) -> Result<u8, Error<B::Error>> {
+        self.timestamp_enable_get().await.map(u8::from)
     }
 
     /// Configure activity/inactivity (sleep)
@@ -4505,6 +5949,19 @@ pub fn from_gravity_lsb_to_mg(lsb: i16) -> f32 {
     (lsb as f32) * 0.061
 }
 
+/// Scale of the SFLP gyroscope bias initialization value, depending on the
+/// SFLP data rate.
+fn sflp_gbias_scale(val: SflpDataRate) -> f32 {
+    match val {
+        SflpDataRate::_15hz => 0.04,
+        SflpDataRate::_30hz => 0.02,
+        SflpDataRate::_60hz => 0.01,
+        SflpDataRate::_120hz => 0.005,
+        SflpDataRate::_240hz => 0.0025,
+        SflpDataRate::_480hz => 0.00125,
+    }
+}
+
 /// Converts quaternion LSB to float.
 pub fn from_quaternion_lsb_to_float(lsb: u16) -> f32 {
     super::npy_half_to_float(lsb)
@@ -4578,6 +6035,153 @@ pub struct AllSources {
     pub fifo_th: u8,
 }
 
+/// Status of all the interrupt sources, see `interrupt_sources_get`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct InterruptSources {
+    pub drdy_xl: bool,
+    pub drdy_gy: bool,
+    pub drdy_temp: bool,
+    pub drdy_xlhgda: bool,
+    pub drdy_eis: bool,
+    pub drdy_ois: bool,
+    pub gy_settling: bool,
+    pub timestamp: bool,
+    pub hg: bool,
+    pub free_fall: bool,
+    pub wake_up: bool,
+    pub wake_up_z: bool,
+    pub wake_up_y: bool,
+    pub wake_up_x: bool,
+    pub single_tap: bool,
+    pub double_tap: bool,
+    pub tap_z: bool,
+    pub tap_y: bool,
+    pub tap_x: bool,
+    pub tap_sign: bool,
+    pub six_d: bool,
+    pub six_d_xl: bool,
+    pub six_d_xh: bool,
+    pub six_d_yl: bool,
+    pub six_d_yh: bool,
+    pub six_d_zl: bool,
+    pub six_d_zh: bool,
+    pub sleep_change: bool,
+    pub sleep_state: bool,
+    pub hg_wake_up: bool,
+    pub hg_wake_up_z: bool,
+    pub hg_wake_up_y: bool,
+    pub hg_wake_up_x: bool,
+    pub hg_wake_up_change: bool,
+    pub hg_shock_state: bool,
+    pub hg_shock_change: bool,
+    pub step_detector: bool,
+    pub step_count_inc: bool,
+    pub step_count_overflow: bool,
+    pub step_on_delta_time: bool,
+    pub emb_func_stand_by: bool,
+    pub emb_func_time_exceed: bool,
+    pub tilt: bool,
+    pub sig_mot: bool,
+    pub fsm_lc: bool,
+    pub fsm1: bool,
+    pub fsm2: bool,
+    pub fsm3: bool,
+    pub fsm4: bool,
+    pub fsm5: bool,
+    pub fsm6: bool,
+    pub fsm7: bool,
+    pub fsm8: bool,
+    pub mlc1: bool,
+    pub mlc2: bool,
+    pub mlc3: bool,
+    pub mlc4: bool,
+    pub mlc5: bool,
+    pub mlc6: bool,
+    pub mlc7: bool,
+    pub mlc8: bool,
+    pub sh_endop: bool,
+    pub sh_target0_nack: bool,
+    pub sh_target1_nack: bool,
+    pub sh_target2_nack: bool,
+    pub sh_target3_nack: bool,
+    pub sh_wr_once: bool,
+    pub fifo_bdr: bool,
+    pub fifo_full: bool,
+    pub fifo_ovr: bool,
+    pub fifo_th: bool,
+}
+
+impl From<InterruptSources> for AllSources {
+    fn from(val: InterruptSources) -> Self {
+        AllSources {
+            drdy_xl: val.drdy_xl as u8,
+            drdy_gy: val.drdy_gy as u8,
+            drdy_temp: val.drdy_temp as u8,
+            drdy_xlhgda: val.drdy_xlhgda as u8,
+            drdy_eis: val.drdy_eis as u8,
+            drdy_ois: val.drdy_ois as u8,
+            gy_settling: val.gy_settling as u8,
+            timestamp: val.timestamp as u8,
+            hg: val.hg as u8,
+            free_fall: val.free_fall as u8,
+            wake_up: val.wake_up as u8,
+            wake_up_z: val.wake_up_z as u8,
+            wake_up_y: val.wake_up_y as u8,
+            wake_up_x: val.wake_up_x as u8,
+            single_tap: val.single_tap as u8,
+            double_tap: val.double_tap as u8,
+            tap_z: val.tap_z as u8,
+            tap_y: val.tap_y as u8,
+            tap_x: val.tap_x as u8,
+            tap_sign: val.tap_sign as u8,
+            six_d: val.six_d as u8,
+            six_d_xl: val.six_d_xl as u8,
+            six_d_xh: val.six_d_xh as u8,
+            six_d_yl: val.six_d_yl as u8,
+            six_d_yh: val.six_d_yh as u8,
+            six_d_zl: val.six_d_zl as u8,
+            six_d_zh: val.six_d_zh as u8,
+            sleep_change: val.sleep_change as u8,
+            sleep_state: val.sleep_state as u8,
+            step_detector: val.step_detector as u8,
+            step_count_inc: val.step_count_inc as u8,
+            step_count_overflow: val.step_count_overflow as u8,
+            step_on_delta_time: val.step_on_delta_time as u8,
+            emb_func_stand_by: val.emb_func_stand_by as u8,
+            emb_func_time_exceed: val.emb_func_time_exceed as u8,
+            tilt: val.tilt as u8,
+            sig_mot: val.sig_mot as u8,
+            fsm_lc: val.fsm_lc as u8,
+            fsm1: val.fsm1 as u8,
+            fsm2: val.fsm2 as u8,
+            fsm3: val.fsm3 as u8,
+            fsm4: val.fsm4 as u8,
+            fsm5: val.fsm5 as u8,
+            fsm6: val.fsm6 as u8,
+            fsm7: val.fsm7 as u8,
+            fsm8: val.fsm8 as u8,
+            mlc1: val.mlc1 as u8,
+            mlc2: val.mlc2 as u8,
+            mlc3: val.mlc3 as u8,
+            mlc4: val.mlc4 as u8,
+            mlc5: val.mlc5 as u8,
+            mlc6: val.mlc6 as u8,
+            mlc7: val.mlc7 as u8,
+            mlc8: val.mlc8 as u8,
+            sh_endop: val.sh_endop as u8,
+            sh_target0_nack: val.sh_target0_nack as u8,
+            sh_target1_nack: val.sh_target1_nack as u8,
+            sh_target2_nack: val.sh_target2_nack as u8,
+            sh_target3_nack: val.sh_target3_nack as u8,
+            sh_wr_once: val.sh_wr_once as u8,
+            fifo_bdr: val.fifo_bdr as u8,
+            fifo_full: val.fifo_full as u8,
+            fifo_ovr: val.fifo_ovr as u8,
+            fifo_th: val.fifo_th as u8,
+        }
+    }
+}
+
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq)]
 pub enum I2CAddress {
@@ -4589,3 +6193,25 @@ pub enum I2CAddress {
 pub const ID: u8 = 0x73;
 
 pub const CHUNK_SIZE: usize = 256;
+
+/// Number of samples averaged by the self-test procedures.
+pub const SELF_TEST_SAMPLES: usize = 5;
+
+/// Accelerometer self-test output change limits (mg), at ±8 g.
+pub const XL_SELF_TEST_MIN_MG: f32 = 50.0;
+pub const XL_SELF_TEST_MAX_MG: f32 = 1700.0;
+
+/// Gyroscope self-test output change limits (mdps), at ±2000 dps.
+pub const GY_SELF_TEST_MIN_MDPS: f32 = 150000.0;
+pub const GY_SELF_TEST_MAX_MDPS: f32 = 700000.0;
+
+/// High-G accelerometer self-test output change limits (mg), at ±32 g.
+pub const HG_XL_SELF_TEST_MIN_MG: f32 = 2000.0;
+pub const HG_XL_SELF_TEST_MAX_MG: f32 = 15000.0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum SelfTestSensor {
+    Xl,
+    Gy,
+    HgXl,
+}
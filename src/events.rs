@@ -0,0 +1,661 @@
+use super::prelude::*;
+use super::{
+    BusOperation, DelayNs, Error, InterruptSources, Lsm6dsv320x, bisync, only_async, only_sync,
+};
+
+/// Maximum number of events that can be reported by a single interrupt snapshot.
+pub const MAX_EVENTS: usize = 64;
+
+/// Sensor axis.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+/// Set of axes involved in an event.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct Axes {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+}
+
+/// Sign of the acceleration that triggered an event.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Sign {
+    Positive,
+    Negative,
+}
+
+/// Orientation detected by the 6D function.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SixDOrientation {
+    XLow,
+    XHigh,
+    YLow,
+    YHigh,
+    ZLow,
+    ZHigh,
+}
+
+/// Interrupt event.
+///
+/// Events are built from the interrupt status registers; see
+/// `Lsm6dsv320x::events_get`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Event {
+    /// Low-g accelerometer data ready.
+    DataReadyXl,
+    /// Gyroscope data ready.
+    DataReadyGy,
+    /// Gyroscope output in the settling phase.
+    GySettling,
+    /// Temperature data ready.
+    DataReadyTemp,
+    /// High-g accelerometer data ready.
+    DataReadyHgXl,
+    /// EIS gyroscope data ready.
+    DataReadyEis,
+    /// OIS data ready.
+    DataReadyOis,
+    /// Single or double tap, `axis` is None if no axis has been reported.
+    Tap {
+        axis: Option<Axis>,
+        sign: Sign,
+        double: bool,
+    },
+    /// Wake-up on the reported axes.
+    WakeUp { axes: Axes },
+    /// Activity/inactivity change, `sleep` is the new state.
+    SleepChange { sleep: bool },
+    /// Free-fall.
+    FreeFall,
+    /// Orientation change.
+    SixD(SixDOrientation),
+    /// High-g wake-up on the reported axes.
+    HgWakeUp { axes: Axes },
+    /// High-g wake-up change.
+    HgWakeUpChange,
+    /// High-g shock state change, `shock` is the new state.
+    HgShock { shock: bool },
+    /// Step detected.
+    StepDetected,
+    /// Step counter increased.
+    StepCountIncrement,
+    /// Step counter overflow.
+    StepCountOverflow,
+    /// Step count delta time reached.
+    StepCountDeltaTime,
+    /// Tilt.
+    Tilt,
+    /// Significant motion.
+    SignificantMotion,
+    /// FSM long counter timeout.
+    FsmLongCounter,
+    /// FSM `n` (1 - 8) interrupt, with the FSM output value.
+    FsmOutput(u8, u8),
+    /// MLC `n` (1 - 8) interrupt, with the decision tree class.
+    MlcClass(u8, u8),
+    /// Embedded functions execution ended, no function running.
+    EmbFuncEndOp,
+    /// Embedded functions execution time exceeded.
+    EmbFuncTimeExceed,
+    /// Sensor hub communication concluded.
+    SensorHubEndOp,
+    /// Sensor hub target `n` (0 - 3) not acknowledged.
+    SensorHubNack(u8),
+    /// Sensor hub write once done.
+    SensorHubWriteOnce,
+    /// Timestamp end count.
+    Timestamp,
+    /// FIFO watermark reached.
+    FifoWatermark,
+    /// FIFO overrun.
+    FifoOverrun,
+    /// FIFO full.
+    FifoFull,
+    /// FIFO batch counter threshold reached.
+    FifoBdr,
+}
+
+/// Events reported by a single interrupt snapshot.
+#[derive(Clone, Debug)]
+pub struct Events {
+    buf: [Event; MAX_EVENTS],
+    len: usize,
+    pos: usize,
+}
+
+impl Events {
+    /// Build the events from the interrupt sources and the FSM/MLC outputs.
+    pub fn new(sources: &InterruptSources, fsm_out: &FsmOut, mlc_out: &MlcOut) -> Self {
+        let mut events = Self {
+            buf: [Event::FreeFall; MAX_EVENTS],
+            len: 0,
+            pos: 0,
+        };
+
+        let flags = [
+            (sources.drdy_xl, Event::DataReadyXl),
+            (sources.drdy_gy, Event::DataReadyGy),
+            (sources.gy_settling, Event::GySettling),
+            (sources.drdy_temp, Event::DataReadyTemp),
+            (sources.drdy_xlhgda, Event::DataReadyHgXl),
+            (sources.drdy_eis, Event::DataReadyEis),
+            (sources.drdy_ois, Event::DataReadyOis),
+        ];
+        events.push_flags(&flags);
+
+        if sources.single_tap || sources.double_tap {
+            let axis = if sources.tap_x {
+                Some(Axis::X)
+            } else if sources.tap_y {
+                Some(Axis::Y)
+            } else if sources.tap_z {
+                Some(Axis::Z)
+            } else {
+                None
+            };
+            let sign = if sources.tap_sign {
+                Sign::Negative
+            } else {
+                Sign::Positive
+            };
+            events.push(Event::Tap {
+                axis,
+                sign,
+                double: sources.double_tap,
+            });
+        }
+
+        if sources.wake_up {
+            events.push(Event::WakeUp {
+                axes: Axes {
+                    x: sources.wake_up_x,
+                    y: sources.wake_up_y,
+                    z: sources.wake_up_z,
+                },
+            });
+        }
+
+        if sources.sleep_change {
+            events.push(Event::SleepChange {
+                sleep: sources.sleep_state,
+            });
+        }
+
+        if sources.free_fall {
+            events.push(Event::FreeFall);
+        }
+
+        if sources.six_d {
+            let orientations = [
+                (sources.six_d_xl, Event::SixD(SixDOrientation::XLow)),
+                (sources.six_d_xh, Event::SixD(SixDOrientation::XHigh)),
+                (sources.six_d_yl, Event::SixD(SixDOrientation::YLow)),
+                (sources.six_d_yh, Event::SixD(SixDOrientation::YHigh)),
+                (sources.six_d_zl, Event::SixD(SixDOrientation::ZLow)),
+                (sources.six_d_zh, Event::SixD(SixDOrientation::ZHigh)),
+            ];
+            events.push_flags(&orientations);
+        }
+
+        if sources.hg_wake_up {
+            events.push(Event::HgWakeUp {
+                axes: Axes {
+                    x: sources.hg_wake_up_x,
+                    y: sources.hg_wake_up_y,
+                    z: sources.hg_wake_up_z,
+                },
+            });
+        }
+
+        if sources.hg_wake_up_change {
+            events.push(Event::HgWakeUpChange);
+        }
+
+        if sources.hg_shock_change {
+            events.push(Event::HgShock {
+                shock: sources.hg_shock_state,
+            });
+        }
+
+        let flags = [
+            (sources.step_detector, Event::StepDetected),
+            (sources.step_count_inc, Event::StepCountIncrement),
+            (sources.step_count_overflow, Event::StepCountOverflow),
+            (sources.step_on_delta_time, Event::StepCountDeltaTime),
+            (sources.tilt, Event::Tilt),
+            (sources.sig_mot, Event::SignificantMotion),
+            (sources.fsm_lc, Event::FsmLongCounter),
+        ];
+        events.push_flags(&flags);
+
+        let fsm = [
+            (sources.fsm1, fsm_out.fsm_outs1),
+            (sources.fsm2, fsm_out.fsm_outs2),
+            (sources.fsm3, fsm_out.fsm_outs3),
+            (sources.fsm4, fsm_out.fsm_outs4),
+            (sources.fsm5, fsm_out.fsm_outs5),
+            (sources.fsm6, fsm_out.fsm_outs6),
+            (sources.fsm7, fsm_out.fsm_outs7),
+            (sources.fsm8, fsm_out.fsm_outs8),
+        ];
+        for (n, &(flag, value)) in (1..).zip(fsm.iter()) {
+            if flag {
+                events.push(Event::FsmOutput(n, value));
+            }
+        }
+
+        let mlc = [
+            (sources.mlc1, mlc_out.mlc1_src),
+            (sources.mlc2, mlc_out.mlc2_src),
+            (sources.mlc3, mlc_out.mlc3_src),
+            (sources.mlc4, mlc_out.mlc4_src),
+            (sources.mlc5, mlc_out.mlc5_src),
+            (sources.mlc6, mlc_out.mlc6_src),
+            (sources.mlc7, mlc_out.mlc7_src),
+            (sources.mlc8, mlc_out.mlc8_src),
+        ];
+        for (n, &(flag, class)) in (1..).zip(mlc.iter()) {
+            if flag {
+                events.push(Event::MlcClass(n, class));
+            }
+        }
+
+        let flags = [
+            (sources.emb_func_stand_by, Event::EmbFuncEndOp),
+            (sources.emb_func_time_exceed, Event::EmbFuncTimeExceed),
+            (sources.sh_endop, Event::SensorHubEndOp),
+            (sources.sh_target0_nack, Event::SensorHubNack(0)),
+            (sources.sh_target1_nack, Event::SensorHubNack(1)),
+            (sources.sh_target2_nack, Event::SensorHubNack(2)),
+            (sources.sh_target3_nack, Event::SensorHubNack(3)),
+            (sources.sh_wr_once, Event::SensorHubWriteOnce),
+            (sources.timestamp, Event::Timestamp),
+            (sources.fifo_th, Event::FifoWatermark),
+            (sources.fifo_ovr, Event::FifoOverrun),
+            (sources.fifo_full, Event::FifoFull),
+            (sources.fifo_bdr, Event::FifoBdr),
+        ];
+        events.push_flags(&flags);
+
+        events
+    }
+
+    /// Get the number of events not yet consumed.
+    pub fn len(&self) -> usize {
+        self.len - self.pos
+    }
+
+    /// Check if all the events have been consumed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push(&mut self, event: Event) {
+        if self.len < MAX_EVENTS {
+            self.buf[self.len] = event;
+            self.len += 1;
+        }
+    }
+
+    fn push_flags(&mut self, flags: &[(bool, Event)]) {
+        for &(flag, event) in flags {
+            if flag {
+                self.push(event);
+            }
+        }
+    }
+}
+
+impl Iterator for Events {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        if self.pos == self.len {
+            return None;
+        }
+
+        let event = self.buf[self.pos];
+        self.pos += 1;
+        Some(event)
+    }
+}
+
+/// Handler of interrupt events, see `EventDispatcher`.
+///
+/// It is implemented for closures taking an `Event`.
+#[bisync]
+#[allow(async_fn_in_trait)]
+pub trait EventHandler {
+    /// Handle a single event.
+    async fn handle(&mut self, event: Event);
+}
+
+#[only_async]
+impl<F: AsyncFnMut(Event)> EventHandler for F {
+    async fn handle(&mut self, event: Event) {
+        self(event).await
+    }
+}
+
+#[only_sync]
+impl<F: FnMut(Event)> EventHandler for F {
+    fn handle(&mut self, event: Event) {
+        self(event)
+    }
+}
+
+/// Handler ignoring all the events.
+///
+/// Start point of a dispatcher with handlers registered only for some events.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct IgnoreEvents;
+
+#[bisync]
+impl EventHandler for IgnoreEvents {
+    async fn handle(&mut self, _event: Event) {}
+}
+
+/// Handler registered for the events selected by a filter, see
+/// `EventDispatcher::on`.
+///
+/// Events not selected by the filter are forwarded to `next`.
+pub struct On<H, N> {
+    filter: fn(&Event) -> bool,
+    handler: H,
+    next: N,
+}
+
+#[bisync]
+impl<H: EventHandler, N: EventHandler> EventHandler for On<H, N> {
+    async fn handle(&mut self, event: Event) {
+        if (self.filter)(&event) {
+            self.handler.handle(event).await
+        } else {
+            self.next.handle(event).await
+        }
+    }
+}
+
+/// Reads the interrupt events from the device and forwards them to a handler.
+///
+/// Typical use is in the interrupt loop:
+///
+/// ```rust,ignore
+/// let mut dispatcher = EventDispatcher::new(MyHandler::default());
+/// loop {
+///     int_pin.wait_for_event().await;
+///     dispatcher.dispatch(&mut sensor).await.unwrap();
+/// }
+/// ```
+///
+/// Handlers can also be registered for single events with `on`; each event
+/// is given to the last registered handler selecting it, or to the handler
+/// given to `new`:
+///
+/// ```rust,ignore
+/// let mut dispatcher = EventDispatcher::new(IgnoreEvents)
+///     .on(|e| matches!(e, Event::Tap { .. }), async |e| info!("{:?}", e))
+///     .on(|e| matches!(e, Event::FsmOutput(1, _)), async |e| info!("{:?}", e));
+/// ```
+pub struct EventDispatcher<H> {
+    handler: H,
+}
+
+impl<H> EventDispatcher<H> {
+    /// Create a dispatcher forwarding the events to `handler`.
+    pub fn new(handler: H) -> Self {
+        Self { handler }
+    }
+
+    /// Register `handler` for the events selected by `filter`.
+    ///
+    /// The events selected are no more given to the handlers registered
+    /// before.
+    pub fn on<K>(self, filter: fn(&Event) -> bool, handler: K) -> EventDispatcher<On<K, H>> {
+        EventDispatcher {
+            handler: On {
+                filter,
+                handler,
+                next: self.handler,
+            },
+        }
+    }
+
+    /// Get the handler.
+    pub fn handler(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    /// Release the handler.
+    pub fn release(self) -> H {
+        self.handler
+    }
+}
+
+#[bisync]
+impl<H: EventHandler> EventDispatcher<H> {
+    /// Read the events from the device and call the handler for each of them.
+    ///
+    /// Returns the number of events handled.
+    pub async fn dispatch<B: BusOperation, T: DelayNs>(
+        &mut self,
+        sensor: &mut Lsm6dsv320x<B, T, MainBank>,
+    ) -> Result<usize, Error<B::Error>> {
+        let events = sensor.events_get().await?;
+        let len = events.len();
+
+        for event in events {
+            self.handler.handle(event).await;
+        }
+
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::*;
+
+    fn events_of(sources: &InterruptSources) -> ([Option<Event>; MAX_EVENTS], usize) {
+        let mut out = [None; MAX_EVENTS];
+        let mut len = 0;
+        for event in Events::new(sources, &FsmOut::default(), &MlcOut::default()) {
+            out[len] = Some(event);
+            len += 1;
+        }
+        (out, len)
+    }
+
+    fn assert_events(sources: &InterruptSources, expected: &[Event]) {
+        let (events, len) = events_of(sources);
+        assert_eq!(len, expected.len(), "{:?}", &events[..len]);
+        for (event, expected) in events.iter().zip(expected) {
+            assert_eq!(event.as_ref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn no_sources_no_events() {
+        let events = Events::new(
+            &InterruptSources::default(),
+            &FsmOut::default(),
+            &MlcOut::default(),
+        );
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn tap_axis_and_sign() {
+        let sources = InterruptSources {
+            single_tap: true,
+            tap_y: true,
+            tap_sign: true,
+            ..Default::default()
+        };
+        assert_events(
+            &sources,
+            &[Event::Tap {
+                axis: Some(Axis::Y),
+                sign: Sign::Negative,
+                double: false,
+            }],
+        );
+
+        let sources = InterruptSources {
+            double_tap: true,
+            ..Default::default()
+        };
+        assert_events(
+            &sources,
+            &[Event::Tap {
+                axis: None,
+                sign: Sign::Positive,
+                double: true,
+            }],
+        );
+    }
+
+    #[test]
+    fn axes_and_states() {
+        let sources = InterruptSources {
+            wake_up: true,
+            wake_up_x: true,
+            wake_up_z: true,
+            sleep_change: true,
+            sleep_state: true,
+            six_d: true,
+            six_d_yh: true,
+            hg_wake_up: true,
+            hg_wake_up_y: true,
+            hg_wake_up_change: true,
+            hg_shock_change: true,
+            ..Default::default()
+        };
+        assert_events(
+            &sources,
+            &[
+                Event::WakeUp {
+                    axes: Axes {
+                        x: true,
+                        y: false,
+                        z: true,
+                    },
+                },
+                Event::SleepChange { sleep: true },
+                Event::SixD(SixDOrientation::YHigh),
+                Event::HgWakeUp {
+                    axes: Axes {
+                        x: false,
+                        y: true,
+                        z: false,
+                    },
+                },
+                Event::HgWakeUpChange,
+                Event::HgShock { shock: false },
+            ],
+        );
+    }
+
+    #[test]
+    fn status_flags() {
+        let sources = InterruptSources {
+            drdy_gy: true,
+            gy_settling: true,
+            step_count_inc: true,
+            step_on_delta_time: true,
+            emb_func_stand_by: true,
+            fifo_th: true,
+            ..Default::default()
+        };
+        assert_events(
+            &sources,
+            &[
+                Event::DataReadyGy,
+                Event::GySettling,
+                Event::StepCountIncrement,
+                Event::StepCountDeltaTime,
+                Event::EmbFuncEndOp,
+                Event::FifoWatermark,
+            ],
+        );
+    }
+
+    #[test]
+    fn fsm_and_mlc_outputs() {
+        let sources = InterruptSources {
+            fsm2: true,
+            fsm8: true,
+            mlc5: true,
+            ..Default::default()
+        };
+        let fsm_out = FsmOut {
+            fsm_outs2: 0x20,
+            fsm_outs8: 0x80,
+            ..Default::default()
+        };
+        let mlc_out = MlcOut {
+            mlc5_src: 3,
+            ..Default::default()
+        };
+
+        let mut events = Events::new(&sources, &fsm_out, &mlc_out);
+        assert_eq!(events.len(), 3);
+        assert_eq!(events.next(), Some(Event::FsmOutput(2, 0x20)));
+        assert_eq!(events.next(), Some(Event::FsmOutput(8, 0x80)));
+        assert_eq!(events.next(), Some(Event::MlcClass(5, 3)));
+        assert_eq!(events.next(), None);
+        assert!(events.is_empty());
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        events: [Option<Event>; 4],
+        len: usize,
+    }
+
+    #[bisync]
+    impl EventHandler for Recorder {
+        async fn handle(&mut self, event: Event) {
+            self.events[self.len] = Some(event);
+            self.len += 1;
+        }
+    }
+
+    #[test]
+    fn dispatch_to_registered_handlers() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+        let main = MemBank::MainMemBank;
+        let all_int_src = AllIntSrc::new().with_ff_ia(1).into_bits();
+        sensor.bus.reg_set(main, Reg::AllIntSrc as u8, all_int_src);
+        let status = StatusReg::new().with_xlda(1).into_bits();
+        sensor.bus.reg_set(main, Reg::StatusReg as u8, status);
+        let tap_src = TapSrc::new().with_single_tap(1).with_x_tap(1).into_bits();
+        sensor.bus.reg_set(main, Reg::TapSrc as u8, tap_src);
+
+        let mut dispatcher = EventDispatcher::new(Recorder::default())
+            .on(|e| matches!(e, Event::Tap { .. }), Recorder::default())
+            .on(|e| matches!(e, Event::FreeFall), Recorder::default());
+        assert_eq!(block_on(dispatcher.dispatch(&mut sensor)).unwrap(), 3);
+
+        let free_fall = dispatcher.release();
+        let tap = free_fall.next;
+        let others = tap.next;
+        assert_eq!(free_fall.handler.events[..1], [Some(Event::FreeFall)]);
+        assert_eq!(
+            tap.handler.events[..1],
+            [Some(Event::Tap {
+                axis: Some(Axis::X),
+                sign: Sign::Positive,
+                double: false,
+            })]
+        );
+        assert_eq!(others.events[..1], [Some(Event::DataReadyXl)]);
+        assert_eq!(free_fall.handler.len + tap.handler.len + others.len, 3);
+    }
+}
@@ -0,0 +1,856 @@
+use super::prelude::*;
+
+/// Size in bytes of a single FIFO word: tag byte followed by 6 data bytes.
+pub const FIFO_WORD_SIZE: usize = 7;
+
+/// Decoded FIFO word.
+///
+/// Each variant maps to a FIFO `Tag` and carries its payload already unpacked
+/// from the 6 little-endian data bytes. Sensor samples are kept in LSB; use the
+/// `from_*` conversion functions to obtain physical units.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FifoFrame {
+    /// FIFO empty.
+    Empty,
+    /// Low-g accelerometer uncompressed sample (x, y, z).
+    XlNc { cnt: u8, xyz: [i16; 3] },
+    /// High-g accelerometer sample (x, y, z).
+    XlHg { cnt: u8, xyz: [i16; 3] },
+    /// Gyroscope uncompressed sample (x, y, z).
+    GyNc { cnt: u8, xyz: [i16; 3] },
+    /// Gyroscope enhanced EIS sample (x, y, z).
+    GyEnhancedEis { cnt: u8, xyz: [i16; 3] },
+    /// Temperature sample.
+    Temperature { cnt: u8, temp: i16 },
+    /// Timestamp value.
+    Timestamp { cnt: u8, timestamp: u32 },
+    /// Sensor configuration change, raw content of the word.
+    CfgChange { cnt: u8, data: [u8; 6] },
+    /// Step counter value and timestamp of the last step.
+    StepCounter { cnt: u8, steps: u16, timestamp: u32 },
+    /// SFLP game rotation vector (x, y, z) in half-precision float format.
+    SflpGameRotationVector { cnt: u8, xyz: [u16; 3] },
+    /// SFLP gyroscope bias (x, y, z).
+    SflpGyroscopeBias { cnt: u8, xyz: [i16; 3] },
+    /// SFLP gravity vector (x, y, z).
+    SflpGravityVector { cnt: u8, xyz: [i16; 3] },
+    /// High-g accelerometer peak (x, y, z).
+    HgXlPeak { cnt: u8, xyz: [i16; 3] },
+    /// MLC decision tree result and its index.
+    MlcResult { cnt: u8, value: u8, index: u8 },
+    /// MLC filter value (half-precision float) and its identifier.
+    MlcFilter { cnt: u8, value: u16, id: u16 },
+    /// MLC feature value (half-precision float) and its identifier.
+    MlcFeature { cnt: u8, value: u16, id: u16 },
+    /// FSM result and its index.
+    FsmResult { cnt: u8, value: u8, index: u8 },
+    /// Sensor hub target data (target 0 - 3).
+    SensorHub { cnt: u8, target: u8, data: [u8; 6] },
+    /// Sensor hub nack on target.
+    SensorHubNack { cnt: u8, target: u8 },
+    /// Compressed accelerometer/gyroscope word, see FIFO compression.
+    Compressed { tag: Tag, cnt: u8, data: [u8; 6] },
+    /// Tag not recognized.
+    Unknown { tag: u8, cnt: u8, data: [u8; 6] },
+}
+
+impl FifoFrame {
+    /// Decode a FIFO word as read from FIFO_DATA_OUT_TAG (0x78) onward.
+    ///
+    /// Returns None if `buf` is shorter than `FIFO_WORD_SIZE` bytes.
+    pub fn from_bytes(buf: &[u8]) -> Option<Self> {
+        let raw = FifoOutRaw::from_bytes(buf)?;
+        let tag = FifoDataOutTag::from_bits(buf[0]).tag_sensor();
+        if Tag::try_from(tag).is_err() {
+            return Some(FifoFrame::Unknown {
+                tag,
+                cnt: raw.cnt,
+                data: raw.data,
+            });
+        }
+
+        Some(Self::from(&raw))
+    }
+
+    /// Get the FIFO tag count of the word.
+    pub fn cnt(&self) -> u8 {
+        match *self {
+            FifoFrame::Empty => 0,
+            FifoFrame::XlNc { cnt, .. }
+            | FifoFrame::XlHg { cnt, .. }
+            | FifoFrame::GyNc { cnt, .. }
+            | FifoFrame::GyEnhancedEis { cnt, .. }
+            | FifoFrame::Temperature { cnt, .. }
+            | FifoFrame::Timestamp { cnt, .. }
+            | FifoFrame::CfgChange { cnt, .. }
+            | FifoFrame::StepCounter { cnt, .. }
+            | FifoFrame::SflpGameRotationVector { cnt, .. }
+            | FifoFrame::SflpGyroscopeBias { cnt, .. }
+            | FifoFrame::SflpGravityVector { cnt, .. }
+            | FifoFrame::HgXlPeak { cnt, .. }
+            | FifoFrame::MlcResult { cnt, .. }
+            | FifoFrame::MlcFilter { cnt, .. }
+            | FifoFrame::MlcFeature { cnt, .. }
+            | FifoFrame::FsmResult { cnt, .. }
+            | FifoFrame::SensorHub { cnt, .. }
+            | FifoFrame::SensorHubNack { cnt, .. }
+            | FifoFrame::Compressed { cnt, .. }
+            | FifoFrame::Unknown { cnt, .. } => cnt,
+        }
+    }
+
+    /// Decode the SFLP outputs in physical units; None for any other word.
+    pub fn sflp_sample(&self) -> Option<SflpSample> {
+        match *self {
+            FifoFrame::SflpGameRotationVector { xyz, .. } => {
+                Some(SflpSample::GameRotation(Quaternion::from_sflp_half(xyz)))
+            }
+            FifoFrame::SflpGravityVector { xyz, .. } => Some(SflpSample::Gravity(
+                Vector3::from(xyz).map(Acceleration::from_gravity_lsb),
+            )),
+            FifoFrame::SflpGyroscopeBias { xyz, .. } => Some(SflpSample::GyroBias(
+                Vector3::from(xyz).map(AngularRate::from_gbias_lsb),
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl From<&FifoOutRaw> for FifoFrame {
+    fn from(raw: &FifoOutRaw) -> Self {
+        let cnt = raw.cnt;
+        let d = raw.data;
+        let xyz = [
+            i16::from_le_bytes([d[0], d[1]]),
+            i16::from_le_bytes([d[2], d[3]]),
+            i16::from_le_bytes([d[4], d[5]]),
+        ];
+
+        match raw.tag {
+            Tag::FifoEmpty => FifoFrame::Empty,
+            Tag::XlNc => FifoFrame::XlNc { cnt, xyz },
+            Tag::XlHg => FifoFrame::XlHg { cnt, xyz },
+            Tag::GyNc => FifoFrame::GyNc { cnt, xyz },
+            Tag::GyEnhancedEis => FifoFrame::GyEnhancedEis { cnt, xyz },
+            Tag::Temperature => FifoFrame::Temperature { cnt, temp: xyz[0] },
+            Tag::Timestamp => FifoFrame::Timestamp {
+                cnt,
+                timestamp: u32::from_le_bytes([d[0], d[1], d[2], d[3]]),
+            },
+            Tag::CfgChange => FifoFrame::CfgChange { cnt, data: d },
+            Tag::StepCounter => FifoFrame::StepCounter {
+                cnt,
+                steps: u16::from_le_bytes([d[0], d[1]]),
+                timestamp: u32::from_le_bytes([d[2], d[3], d[4], d[5]]),
+            },
+            Tag::SflpGameRotationVector => FifoFrame::SflpGameRotationVector {
+                cnt,
+                xyz: [
+                    u16::from_le_bytes([d[0], d[1]]),
+                    u16::from_le_bytes([d[2], d[3]]),
+                    u16::from_le_bytes([d[4], d[5]]),
+                ],
+            },
+            Tag::SflpGyroscopeBias => FifoFrame::SflpGyroscopeBias { cnt, xyz },
+            Tag::SflpGravityVector => FifoFrame::SflpGravityVector { cnt, xyz },
+            Tag::HgXlPeak => FifoFrame::HgXlPeak { cnt, xyz },
+            Tag::MlcResult => FifoFrame::MlcResult {
+                cnt,
+                value: d[0],
+                index: d[1],
+            },
+            Tag::MlcFilter => FifoFrame::MlcFilter {
+                cnt,
+                value: u16::from_le_bytes([d[0], d[1]]),
+                id: u16::from_le_bytes([d[2], d[3]]),
+            },
+            Tag::MlcFeature => FifoFrame::MlcFeature {
+                cnt,
+                value: u16::from_le_bytes([d[0], d[1]]),
+                id: u16::from_le_bytes([d[2], d[3]]),
+            },
+            Tag::FsmResult => FifoFrame::FsmResult {
+                cnt,
+                value: d[0],
+                index: d[1],
+            },
+            Tag::SensorhubTarget0 => FifoFrame::SensorHub {
+                cnt,
+                target: 0,
+                data: d,
+            },
+            Tag::SensorhubTarget1 => FifoFrame::SensorHub {
+                cnt,
+                target: 1,
+                data: d,
+            },
+            Tag::SensorhubTarget2 => FifoFrame::SensorHub {
+                cnt,
+                target: 2,
+                data: d,
+            },
+            Tag::SensorhubTarget3 => FifoFrame::SensorHub {
+                cnt,
+                target: 3,
+                data: d,
+            },
+            Tag::SensorhubNack => FifoFrame::SensorHubNack {
+                cnt,
+                target: d[0] & 0x03,
+            },
+            Tag::XlNcT2
+            | Tag::XlNcT1
+            | Tag::Xl2Xc
+            | Tag::Xl3Xc
+            | Tag::GyNcT2
+            | Tag::GyNcT1
+            | Tag::Gy2Xc
+            | Tag::Gy3Xc => FifoFrame::Compressed {
+                tag: raw.tag,
+                cnt,
+                data: d,
+            },
+        }
+    }
+}
+
+impl From<FifoOutRaw> for FifoFrame {
+    fn from(raw: FifoOutRaw) -> Self {
+        Self::from(&raw)
+    }
+}
+
+impl FifoOutRaw {
+    /// Build a raw FIFO word from the 7 bytes read starting at FIFO_DATA_OUT_TAG (0x78).
+    ///
+    /// Returns None if `buf` is shorter than `FIFO_WORD_SIZE` bytes.
+    pub fn from_bytes(buf: &[u8]) -> Option<Self> {
+        if buf.len() < FIFO_WORD_SIZE {
+            return None;
+        }
+
+        let fifo_data_out_tag = FifoDataOutTag::from_bits(buf[0]);
+        let tag = Tag::try_from(fifo_data_out_tag.tag_sensor()).unwrap_or_default();
+        let cnt = fifo_data_out_tag.tag_cnt();
+        let mut data = [0u8; 6];
+        data.copy_from_slice(&buf[1..FIFO_WORD_SIZE]);
+
+        Some(FifoOutRaw { tag, cnt, data })
+    }
+}
+
+/// SFLP output decoded from a FIFO word, see `FifoFrame::sflp_sample`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum SflpSample {
+    /// Game rotation vector, as unit quaternion.
+    GameRotation(Quaternion),
+    /// Gravity vector in mg.
+    Gravity(Vector3<Acceleration<MilliG>>),
+    /// Gyroscope bias in mdps.
+    GyroBias(Vector3<AngularRate<MilliDps>>),
+}
+
+/// SFLP output with the time of its FIFO word, see
+/// `FifoTimeAligner::push_sflp`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TimedSflpSample {
+    /// Time of the word (ns), None until the first Timestamp word.
+    pub time_ns: Option<u64>,
+    pub sample: SflpSample,
+}
+
+/// Sensor of a decompressed FIFO sample.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CompressedSensor {
+    /// Low-g accelerometer.
+    Xl,
+    /// Gyroscope.
+    Gy,
+}
+
+/// Sample reconstructed by `FifoDecompressor`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DecompressedSample {
+    pub sensor: CompressedSensor,
+    /// Time slot of the sample, incremented at each FIFO batch event.
+    pub slot: u32,
+    /// Sample value (x, y, z) in LSB.
+    pub xyz: [i16; 3],
+}
+
+/// Up to three samples produced by a single FIFO word.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct DecompressedSamples {
+    samples: [Option<DecompressedSample>; 3],
+    len: usize,
+}
+
+impl DecompressedSamples {
+    fn push(&mut self, sample: DecompressedSample) {
+        self.samples[self.len] = Some(sample);
+        self.len += 1;
+    }
+
+    /// Number of reconstructed samples.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True if the FIFO word did not produce any sample.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterate over the reconstructed samples, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &DecompressedSample> {
+        self.samples[..self.len].iter().flatten()
+    }
+}
+
+/// Errors reported by `FifoDecompressor`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DecompressError {
+    /// A compressed word was received before any non-compressed reference
+    /// for the same sensor: the samples cannot be reconstructed.
+    MissingReference(Tag),
+}
+
+/// Stateful decoder of the FIFO compression algorithm.
+///
+/// Feed every FIFO word, in the order it is read, to `push`. The
+/// decompressor keeps the last reconstructed sample of the accelerometer and
+/// the gyroscope, which is the reference for the following compressed
+/// words, and tracks the time slot through the tag counter.
+///
+/// Non-compressed words (NC, NC_T_1, NC_T_2) refresh the reference, 2xC
+/// words carry two 8-bit deltas (slots t-2, t-1) and 3xC words carry three
+/// 5-bit deltas (slots t-2, t-1, t).
+#[derive(Clone, Default, Debug)]
+pub struct FifoDecompressor {
+    last_xl: Option<[i16; 3]>,
+    last_gy: Option<[i16; 3]>,
+    last_cnt: Option<u8>,
+    slot: u32,
+}
+
+impl FifoDecompressor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop the references and restart the time slot counting.
+    ///
+    /// Call it after the FIFO has been flushed or has overrun.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Get the time slot of the last word pushed.
+    pub fn slot(&self) -> u32 {
+        self.slot
+    }
+
+    /// Decode a FIFO word, returning the samples it contains.
+    ///
+    /// Words that are not accelerometer/gyroscope samples only advance the
+    /// time slot and return no samples.
+    pub fn push(&mut self, raw: &FifoOutRaw) -> Result<DecompressedSamples, DecompressError> {
+        if raw.tag == Tag::FifoEmpty {
+            return Ok(DecompressedSamples::default());
+        }
+
+        if let Some(last_cnt) = self.last_cnt {
+            let delta = raw.cnt.wrapping_sub(last_cnt) & 0x03;
+            self.slot = self.slot.wrapping_add(delta as u32);
+        }
+        self.last_cnt = Some(raw.cnt);
+
+        let d = raw.data;
+        let xyz = [
+            i16::from_le_bytes([d[0], d[1]]),
+            i16::from_le_bytes([d[2], d[3]]),
+            i16::from_le_bytes([d[4], d[5]]),
+        ];
+
+        let mut out = DecompressedSamples::default();
+        match raw.tag {
+            Tag::XlNc => self.reference(&mut out, CompressedSensor::Xl, 0, xyz),
+            Tag::XlNcT1 => self.reference(&mut out, CompressedSensor::Xl, 1, xyz),
+            Tag::XlNcT2 => self.reference(&mut out, CompressedSensor::Xl, 2, xyz),
+            Tag::GyNc => self.reference(&mut out, CompressedSensor::Gy, 0, xyz),
+            Tag::GyNcT1 => self.reference(&mut out, CompressedSensor::Gy, 1, xyz),
+            Tag::GyNcT2 => self.reference(&mut out, CompressedSensor::Gy, 2, xyz),
+            Tag::Xl2Xc | Tag::Gy2Xc => {
+                let mut diff = [[0i16; 3]; 2];
+                for (i, delta) in diff.iter_mut().enumerate() {
+                    for (j, val) in delta.iter_mut().enumerate() {
+                        *val = d[i * 3 + j] as i8 as i16;
+                    }
+                }
+                self.expand(&mut out, raw.tag, &diff)?;
+            }
+            Tag::Xl3Xc | Tag::Gy3Xc => {
+                let mut diff = [[0i16; 3]; 3];
+                for (i, delta) in diff.iter_mut().enumerate() {
+                    let word = u16::from_le_bytes([d[2 * i], d[2 * i + 1]]);
+                    for (j, val) in delta.iter_mut().enumerate() {
+                        let bits = ((word >> (5 * j)) & 0x1F) as i16;
+                        *val = if bits & 0x10 != 0 { bits - 32 } else { bits };
+                    }
+                }
+                self.expand(&mut out, raw.tag, &diff)?;
+            }
+            _ => {}
+        }
+
+        Ok(out)
+    }
+
+    fn reference(
+        &mut self,
+        out: &mut DecompressedSamples,
+        sensor: CompressedSensor,
+        age: u32,
+        xyz: [i16; 3],
+    ) {
+        match sensor {
+            CompressedSensor::Xl => self.last_xl = Some(xyz),
+            CompressedSensor::Gy => self.last_gy = Some(xyz),
+        }
+
+        out.push(DecompressedSample {
+            sensor,
+            slot: self.slot.wrapping_sub(age),
+            xyz,
+        });
+    }
+
+    fn expand(
+        &mut self,
+        out: &mut DecompressedSamples,
+        tag: Tag,
+        diff: &[[i16; 3]],
+    ) -> Result<(), DecompressError> {
+        let (sensor, last) = match tag {
+            Tag::Xl2Xc | Tag::Xl3Xc => (CompressedSensor::Xl, &mut self.last_xl),
+            _ => (CompressedSensor::Gy, &mut self.last_gy),
+        };
+
+        let mut sample = last.ok_or(DecompressError::MissingReference(tag))?;
+        for (i, delta) in diff.iter().enumerate() {
+            for (val, d) in sample.iter_mut().zip(delta.iter()) {
+                *val = val.wrapping_add(*d);
+            }
+
+            out.push(DecompressedSample {
+                sensor,
+                slot: self.slot.wrapping_sub(2 - i as u32),
+                xyz: sample,
+            });
+        }
+        *last = Some(sample);
+
+        Ok(())
+    }
+}
+
+/// Time alignment of the FIFO words.
+///
+/// The tag counter of each word identifies the time slot, which advances at
+/// the highest batch data rate among accelerometer, gyroscope and high-g
+/// accelerometer. Timestamp words anchor a time slot to the timestamp
+/// counter; the following slots are extrapolated with the batch period.
+///
+/// Both the timestamp and the batch period are corrected with the
+/// odr_cal_reg_get value (0.13% per LSB). The 32-bit timestamp rollover is
+/// handled by extending it to 64 bits.
+///
+/// If fed with the same words, the time slots match the ones reported by
+/// `FifoDecompressor`.
+#[derive(Clone, Debug)]
+pub struct FifoTimeAligner {
+    slot_period_ns: f64,
+    odr_scale: f64,
+    last_cnt: Option<u8>,
+    slot: u32,
+    last_ts: Option<u32>,
+    ts_rollover: u64,
+    anchor: Option<(u32, u64)>,
+}
+
+impl FifoTimeAligner {
+    /// Create the aligner from the FIFO batching configuration.
+    ///
+    /// Use `HgXlDataRate::Off` if the high-g accelerometer is not batched.
+    /// `odr_cal` is the value returned by odr_cal_reg_get.
+    pub fn new(xl: FifoBatch, gy: FifoBatch, hg_xl: HgXlDataRate, odr_cal: i8) -> Self {
+        let odr_scale = 1.0 + 0.0013 * odr_cal as f64;
+        let bdr = xl.to_hz().max(gy.to_hz()).max(hg_xl.to_hz()) as f64;
+        let slot_period_ns = if bdr > 0.0 {
+            1_000_000_000.0 / (bdr * odr_scale)
+        } else {
+            0.0
+        };
+
+        Self {
+            slot_period_ns,
+            odr_scale,
+            last_cnt: None,
+            slot: 0,
+            last_ts: None,
+            ts_rollover: 0,
+            anchor: None,
+        }
+    }
+
+    /// Drop the timestamp reference and restart the time slot counting.
+    pub fn reset(&mut self) {
+        self.last_cnt = None;
+        self.slot = 0;
+        self.last_ts = None;
+        self.ts_rollover = 0;
+        self.anchor = None;
+    }
+
+    /// Get the time slot of the last word pushed.
+    pub fn slot(&self) -> u32 {
+        self.slot
+    }
+
+    /// Process a FIFO word and return the time, in ns, of its time slot.
+    ///
+    /// Returns None until the first Timestamp word has been received.
+    pub fn push(&mut self, raw: &FifoOutRaw) -> Option<u64> {
+        if raw.tag == Tag::FifoEmpty {
+            return None;
+        }
+
+        if let Some(last_cnt) = self.last_cnt {
+            let delta = raw.cnt.wrapping_sub(last_cnt) & 0x03;
+            self.slot = self.slot.wrapping_add(delta as u32);
+        }
+        self.last_cnt = Some(raw.cnt);
+
+        if raw.tag == Tag::Timestamp {
+            let d = raw.data;
+            let ts = u32::from_le_bytes([d[0], d[1], d[2], d[3]]);
+            if self.last_ts.is_some_and(|last_ts| ts < last_ts) {
+                self.ts_rollover += 1 << 32;
+            }
+            self.last_ts = Some(ts);
+
+            let lsb = self.ts_rollover + ts as u64;
+            let ns = (super::from_lsb_to_nsec(1) as f64 * lsb as f64 / self.odr_scale) as u64;
+            self.anchor = Some((self.slot, ns));
+        }
+
+        self.slot_time_ns(self.slot)
+    }
+
+    /// Process a FIFO word and decode it if it is an SFLP output.
+    ///
+    /// All the words must be pushed, so the time slots keep being counted;
+    /// returns None for the words that are not SFLP outputs.
+    pub fn push_sflp(&mut self, raw: &FifoOutRaw) -> Option<TimedSflpSample> {
+        let time_ns = self.push(raw);
+        let sample = FifoFrame::from(raw).sflp_sample()?;
+
+        Some(TimedSflpSample { time_ns, sample })
+    }
+
+    /// Get the time, in ns, of a time slot.
+    ///
+    /// Returns None until the first Timestamp word has been received.
+    pub fn slot_time_ns(&self, slot: u32) -> Option<u64> {
+        let (anchor_slot, anchor_ns) = self.anchor?;
+        let offset = slot.wrapping_sub(anchor_slot) as i32 as f64 * self.slot_period_ns;
+
+        Some((anchor_ns as f64 + offset).max(0.0) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::from_lsb_to_nsec;
+    use super::*;
+
+    /// FIFO word with the tag byte built from its fields, so the bytes follow
+    /// the bit order of the registers.
+    fn word(tag: u8, cnt: u8, data: [u8; 6]) -> [u8; FIFO_WORD_SIZE] {
+        let mut word = [0; FIFO_WORD_SIZE];
+        word[0] = FifoDataOutTag::new()
+            .with_tag_sensor(tag)
+            .with_tag_cnt(cnt)
+            .into_bits();
+        word[1..].copy_from_slice(&data);
+        word
+    }
+
+    #[test]
+    fn unknown_tag_is_kept() {
+        let buf = word(0x14, 1, [1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(
+            FifoFrame::from_bytes(&buf),
+            Some(FifoFrame::Unknown {
+                tag: 0x14,
+                cnt: 1,
+                data: [1, 2, 3, 4, 5, 6],
+            })
+        );
+    }
+
+    #[test]
+    fn known_tag_is_decoded() {
+        let buf = word(Tag::XlNc as u8, 0, [0x01, 0x00, 0xFF, 0xFF, 0x00, 0x80]);
+
+        assert_eq!(
+            FifoFrame::from_bytes(&buf),
+            Some(FifoFrame::XlNc {
+                cnt: 0,
+                xyz: [1, -1, i16::MIN],
+            })
+        );
+    }
+
+    #[test]
+    fn sflp_sample() {
+        /* x = 0.5, y = -0.5, z = 0.5 in half-precision float, little endian */
+        let buf = word(
+            Tag::SflpGameRotationVector as u8,
+            1,
+            [0x00, 0x38, 0x00, 0xB8, 0x00, 0x38],
+        );
+        let frame = FifoFrame::from_bytes(&buf).unwrap();
+        assert_eq!(
+            frame.sflp_sample(),
+            Some(SflpSample::GameRotation(Quaternion::new(
+                0.5, 0.5, -0.5, 0.5
+            )))
+        );
+
+        /* 0.061 mg/LSB */
+        let frame = FifoFrame::SflpGravityVector {
+            cnt: 0,
+            xyz: [1000, -1000, 16393],
+        };
+        let Some(SflpSample::Gravity(gravity)) = frame.sflp_sample() else {
+            panic!("not a gravity sample");
+        };
+        assert_eq!(gravity.x.value(), 61.0);
+        assert_eq!(gravity.y.value(), -61.0);
+        assert!((gravity.z.value() - 999.973).abs() < 1e-3);
+
+        /* 4.375 mdps/LSB */
+        let frame = FifoFrame::SflpGyroscopeBias {
+            cnt: 0,
+            xyz: [100, -8, 0],
+        };
+        let Some(SflpSample::GyroBias(gbias)) = frame.sflp_sample() else {
+            panic!("not a gyroscope bias sample");
+        };
+        assert_eq!(
+            [gbias.x.value(), gbias.y.value(), gbias.z.value()],
+            [437.5, -35.0, 0.0]
+        );
+
+        let frame = FifoFrame::XlNc {
+            cnt: 0,
+            xyz: [0; 3],
+        };
+        assert_eq!(frame.sflp_sample(), None);
+    }
+
+    #[test]
+    fn short_buffer_is_rejected() {
+        assert_eq!(FifoFrame::from_bytes(&[0; FIFO_WORD_SIZE - 1]), None);
+    }
+
+    fn push(
+        decompressor: &mut FifoDecompressor,
+        word: [u8; FIFO_WORD_SIZE],
+    ) -> Result<DecompressedSamples, DecompressError> {
+        decompressor.push(&FifoOutRaw::from_bytes(&word).unwrap())
+    }
+
+    fn assert_samples(
+        out: &DecompressedSamples,
+        sensor: CompressedSensor,
+        expected: &[(u32, [i16; 3])],
+    ) {
+        assert_eq!(out.len(), expected.len());
+        for (sample, &(slot, xyz)) in out.iter().zip(expected) {
+            assert_eq!(*sample, DecompressedSample { sensor, slot, xyz });
+        }
+    }
+
+    #[test]
+    fn decompress_2xc_3xc() {
+        let mut decompressor = FifoDecompressor::new();
+
+        // XL_NC, cnt 0: (100, 200, -300)
+        let out = push(
+            &mut decompressor,
+            word(Tag::XlNc as u8, 0, [0x64, 0x00, 0xC8, 0x00, 0xD4, 0xFE]),
+        );
+        assert_samples(
+            &out.unwrap(),
+            CompressedSensor::Xl,
+            &[(0, [100, 200, -300])],
+        );
+
+        // XL_2XC, cnt 3: (1, 2, 3), (-1, -128, 127)
+        let out = push(
+            &mut decompressor,
+            word(Tag::Xl2Xc as u8, 3, [0x01, 0x02, 0x03, 0xFF, 0x80, 0x7F]),
+        );
+        assert_samples(
+            &out.unwrap(),
+            CompressedSensor::Xl,
+            &[(1, [101, 202, -297]), (2, [100, 74, -170])],
+        );
+
+        // XL_3XC, cnt 2: (1, -1, 15), (-16, 0, 2), (3, 3, -3)
+        let out = push(
+            &mut decompressor,
+            word(Tag::Xl3Xc as u8, 2, [0xE1, 0x3F, 0x10, 0x08, 0x63, 0x74]),
+        );
+        assert_samples(
+            &out.unwrap(),
+            CompressedSensor::Xl,
+            &[
+                (4, [101, 73, -155]),
+                (5, [85, 73, -153]),
+                (6, [88, 76, -156]),
+            ],
+        );
+        assert_eq!(decompressor.slot(), 6);
+    }
+
+    #[test]
+    fn decompress_nc_t_references() {
+        let mut decompressor = FifoDecompressor::new();
+
+        // GY_3XC, cnt 0, before any gyroscope reference
+        let out = push(
+            &mut decompressor,
+            word(Tag::Gy3Xc as u8, 0, [0x21, 0x04, 0x21, 0x04, 0x21, 0x04]),
+        );
+        assert_eq!(out, Err(DecompressError::MissingReference(Tag::Gy3Xc)));
+
+        // GY_NC_T_1, cnt 1: (10, 20, 30) at t-1
+        let out = push(
+            &mut decompressor,
+            word(Tag::GyNcT1 as u8, 1, [0x0A, 0x00, 0x14, 0x00, 0x1E, 0x00]),
+        );
+        assert_samples(&out.unwrap(), CompressedSensor::Gy, &[(0, [10, 20, 30])]);
+
+        // GY_NC_T_2, cnt 3: (-10, -20, -30) at t-2
+        let out = push(
+            &mut decompressor,
+            word(Tag::GyNcT2 as u8, 3, [0xF6, 0xFF, 0xEC, 0xFF, 0xE2, 0xFF]),
+        );
+        assert_samples(&out.unwrap(), CompressedSensor::Gy, &[(1, [-10, -20, -30])]);
+
+        // GY_3XC, cnt 0: (1, 1, 1) x 3, from the NC_T_2 reference
+        let out = push(
+            &mut decompressor,
+            word(Tag::Gy3Xc as u8, 0, [0x21, 0x04, 0x21, 0x04, 0x21, 0x04]),
+        );
+        assert_samples(
+            &out.unwrap(),
+            CompressedSensor::Gy,
+            &[
+                (2, [-9, -19, -29]),
+                (3, [-8, -18, -28]),
+                (4, [-7, -17, -27]),
+            ],
+        );
+
+        // XL_2XC, cnt 1, the accelerometer reference is still missing
+        let out = push(
+            &mut decompressor,
+            word(Tag::Xl2Xc as u8, 1, [0x01, 0x02, 0x03, 0xFF, 0x80, 0x7F]),
+        );
+        assert_eq!(out, Err(DecompressError::MissingReference(Tag::Xl2Xc)));
+    }
+
+    fn timestamp_word(cnt: u8, ts: u32) -> FifoOutRaw {
+        let mut data = [0; 6];
+        data[..4].copy_from_slice(&ts.to_le_bytes());
+        FifoOutRaw {
+            tag: Tag::Timestamp,
+            cnt,
+            data,
+        }
+    }
+
+    fn xl_word(cnt: u8) -> FifoOutRaw {
+        FifoOutRaw {
+            tag: Tag::XlNc,
+            cnt,
+            data: [0; 6],
+        }
+    }
+
+    fn assert_time(time_ns: Option<u64>, expected_ns: f64) {
+        let time_ns = time_ns.unwrap() as f64;
+        assert!(
+            (time_ns - expected_ns).abs() <= 1.0,
+            "{time_ns} != {expected_ns}"
+        );
+    }
+
+    #[test]
+    fn time_aligner_timestamp_rollover() {
+        let mut aligner = FifoTimeAligner::new(
+            FifoBatch::_120hz,
+            FifoBatch::NotBatched,
+            HgXlDataRate::Off,
+            0,
+        );
+        let lsb_ns = from_lsb_to_nsec(1) as f64;
+        let period_ns = 1e9 / 120.0;
+
+        assert_eq!(aligner.push(&xl_word(3)), None);
+
+        let before = 0xFFFF_FFF0_u32;
+        assert_time(
+            aligner.push(&timestamp_word(0, before)),
+            before as f64 * lsb_ns,
+        );
+        assert_time(
+            aligner.push(&xl_word(1)),
+            before as f64 * lsb_ns + period_ns,
+        );
+
+        // 32-bit counter rolled over between the two Timestamp words
+        let after = 0x10_u32;
+        let after_ns = ((1u64 << 32) + after as u64) as f64 * lsb_ns;
+        assert_time(aligner.push(&timestamp_word(2, after)), after_ns);
+        assert_time(aligner.push(&xl_word(3)), after_ns + period_ns);
+        assert_time(
+            aligner.slot_time_ns(aligner.slot() - 3),
+            after_ns - 2.0 * period_ns,
+        );
+    }
+
+    #[test]
+    fn time_aligner_odr_cal() {
+        let odr_cal = -20;
+        let mut aligner = FifoTimeAligner::new(
+            FifoBatch::_60hz,
+            FifoBatch::_240hz,
+            HgXlDataRate::Off,
+            odr_cal,
+        );
+        let scale = 1.0 + 0.0013 * odr_cal as f64;
+        let lsb_ns = from_lsb_to_nsec(1) as f64;
+
+        // the highest batch data rate (240 Hz) sets the slot period
+        let period_ns = 1e9 / (240.0 * scale);
+        let anchor_ns = 1_000_000.0 * lsb_ns / scale;
+        assert_time(aligner.push(&timestamp_word(0, 1_000_000)), anchor_ns);
+        assert_time(aligner.push(&xl_word(2)), anchor_ns + 2.0 * period_ns);
+    }
+}
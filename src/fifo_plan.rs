@@ -0,0 +1,339 @@
+use super::prelude::*;
+use super::{BusOperation, DelayNs, Error, Lsm6dsv320x, bisync};
+
+/// Number of words the FIFO can hold.
+///
+/// The FIFO level is reported by the 9-bit DIFF_FIFO field of
+/// FIFO_STATUS1/2, so the plan is computed on 512 words rather than on the
+/// FIFO size in bytes divided by the word size.
+pub const FIFO_DEPTH: u16 = 512;
+
+/// Samples stored in a compressed FIFO word in the best case (3xC).
+const MAX_COMPRESSED_SAMPLES: f32 = 3.0;
+
+/// Reason a `FifoPlan` can not be applied.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FifoPlanError {
+    /// Streams are batched but the FIFO is in bypass mode.
+    BypassWithStreams,
+    /// The FIFO is enabled but no stream is batched.
+    NoStream,
+    /// Timestamp is batched, but neither accelerometer nor gyroscope are.
+    TimestampWithoutXlGy,
+    /// Compression is enabled, but neither accelerometer nor gyroscope are
+    /// batched.
+    CompressionWithoutXlGy,
+    /// The FIFO fills faster than it can be read.
+    BandwidthExceeded,
+}
+
+/// Fill rate of the FIFO estimated by `FifoPlan::budget`.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct FifoBudget {
+    /// Words written per second, with each sample in its own word.
+    pub words_per_second: f32,
+    /// Words written per second with the best compression ratio; equal to
+    /// `words_per_second` if compression is disabled.
+    pub words_per_second_min: f32,
+    /// Seconds to reach the watermark; None if the watermark is disabled or
+    /// no stream is batched.
+    pub time_to_watermark_s: Option<f32>,
+    /// Seconds to fill the FIFO; None if no stream is batched.
+    pub time_to_full_s: Option<f32>,
+}
+
+/// FIFO configuration planner.
+///
+/// The plan collects the batched streams and their rates, estimates the fill
+/// rate of the FIFO (`budget`) and applies the whole configuration with
+/// `Lsm6dsv320x::fifo_plan_set`:
+///
+/// ```rust,ignore
+/// let plan = FifoPlan::new(FifoMode::Stream)
+///     .xl(FifoBatch::_960hz)
+///     .gy(FifoBatch::_960hz)
+///     .timestamp(FifoTimestampBatch::Dec32)
+///     .watermark(128);
+///
+/// let budget = plan.budget().unwrap();
+/// sensor.fifo_plan_set(&plan).unwrap();
+/// ```
+///
+/// Estimates assume the worst case (one word per sample) unless stated
+/// otherwise. The high-g accelerometer and SFLP rates are applied with the
+/// plan when their data are batched.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct FifoPlan {
+    mode: FifoMode,
+    xl: FifoBatch,
+    gy: FifoBatch,
+    hg_xl: HgXlDataRate,
+    temp: FifoTempBatch,
+    timestamp: FifoTimestampBatch,
+    sflp: FifoSflpRaw,
+    sflp_rate: SflpDataRate,
+    compression: FifoCompressAlgo,
+    compression_enable: bool,
+    watermark: u8,
+    bandwidth: Option<u32>,
+}
+
+impl FifoPlan {
+    /// Create a plan with the FIFO in `mode` and no stream batched.
+    pub fn new(mode: FifoMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// Batch the low-g accelerometer at `val`.
+    pub fn xl(mut self, val: FifoBatch) -> Self {
+        self.xl = val;
+        self
+    }
+
+    /// Batch the gyroscope at `val`.
+    pub fn gy(mut self, val: FifoBatch) -> Self {
+        self.gy = val;
+        self
+    }
+
+    /// Batch the high-g accelerometer and set its data rate to `val`.
+    pub fn hg_xl(mut self, val: HgXlDataRate) -> Self {
+        self.hg_xl = val;
+        self
+    }
+
+    /// Batch the temperature at `val`.
+    pub fn temp(mut self, val: FifoTempBatch) -> Self {
+        self.temp = val;
+        self
+    }
+
+    /// Batch the timestamp with decimation `val`.
+    pub fn timestamp(mut self, val: FifoTimestampBatch) -> Self {
+        self.timestamp = val;
+        self
+    }
+
+    /// Batch the SFLP outputs in `val` and set the SFLP data rate to `rate`.
+    pub fn sflp(mut self, val: FifoSflpRaw, rate: SflpDataRate) -> Self {
+        self.sflp = val;
+        self.sflp_rate = rate;
+        self
+    }
+
+    /// Enable the compression of accelerometer and gyroscope data.
+    pub fn compression_enable(mut self, val: bool) -> Self {
+        self.compression_enable = val;
+        self
+    }
+
+    /// Force an uncompressed word every `val` batched samples when
+    /// compression is enabled; `FifoCompressAlgo::Disable` never forces them.
+    pub fn compression(mut self, val: FifoCompressAlgo) -> Self {
+        self.compression = val;
+        self
+    }
+
+    /// Set the watermark threshold (words); 0 disables it.
+    pub fn watermark(mut self, val: u8) -> Self {
+        self.watermark = val;
+        self
+    }
+
+    /// Set the bytes per second the host can read from the FIFO, used to
+    /// reject plans filling the FIFO faster than it can be drained.
+    pub fn read_bandwidth(mut self, bytes_per_second: u32) -> Self {
+        self.bandwidth = Some(bytes_per_second);
+        self
+    }
+
+    /// Validate the plan and estimate the fill rate of the FIFO.
+    pub fn budget(&self) -> Result<FifoBudget, FifoPlanError> {
+        let xl = self.xl.to_hz();
+        let gy = self.gy.to_hz();
+        let xl_gy = xl + gy;
+
+        let timestamp = match self.timestamp {
+            FifoTimestampBatch::NotBatched => 0.0,
+            FifoTimestampBatch::Dec1 => xl.max(gy),
+            FifoTimestampBatch::Dec8 => xl.max(gy) / 8.0,
+            FifoTimestampBatch::Dec32 => xl.max(gy) / 32.0,
+        };
+        let sflp = [self.sflp.game_rotation, self.sflp.gravity, self.sflp.gbias]
+            .iter()
+            .filter(|&&en| en == 1)
+            .count() as f32
+            * self.sflp_rate.to_hz();
+        let others = self.hg_xl.to_hz() + self.temp.to_hz() + timestamp + sflp;
+
+        let words_per_second = xl_gy + others;
+        let uncompressed = match self.compression {
+            FifoCompressAlgo::Disable => 0.0,
+            FifoCompressAlgo::_8To1 => 1.0 / 8.0,
+            FifoCompressAlgo::_16To1 => 1.0 / 16.0,
+            FifoCompressAlgo::_32To1 => 1.0 / 32.0,
+        };
+        let xl_gy_min = if self.compression_enable {
+            xl_gy * uncompressed + xl_gy * (1.0 - uncompressed) / MAX_COMPRESSED_SAMPLES
+        } else {
+            xl_gy
+        };
+        let words_per_second_min = xl_gy_min + others;
+
+        /* reject impossible combinations */
+        if self.timestamp != FifoTimestampBatch::NotBatched && xl_gy == 0.0 {
+            return Err(FifoPlanError::TimestampWithoutXlGy);
+        }
+        if self.compression_enable && xl_gy == 0.0 {
+            return Err(FifoPlanError::CompressionWithoutXlGy);
+        }
+        if self.mode == FifoMode::Bypass && words_per_second > 0.0 {
+            return Err(FifoPlanError::BypassWithStreams);
+        }
+        if self.mode != FifoMode::Bypass && words_per_second == 0.0 {
+            return Err(FifoPlanError::NoStream);
+        }
+        let bytes_per_second = words_per_second * FIFO_WORD_SIZE as f32;
+        if self
+            .bandwidth
+            .is_some_and(|bandwidth| bytes_per_second > bandwidth as f32)
+        {
+            return Err(FifoPlanError::BandwidthExceeded);
+        }
+
+        let time_to = |words: u16| {
+            if words > 0 && words_per_second > 0.0 {
+                Some(words as f32 / words_per_second)
+            } else {
+                None
+            }
+        };
+
+        Ok(FifoBudget {
+            words_per_second,
+            words_per_second_min,
+            time_to_watermark_s: time_to(self.watermark as u16),
+            time_to_full_s: time_to(FIFO_DEPTH),
+        })
+    }
+}
+
+#[bisync]
+impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
+    /// Apply a FIFO configuration plan.
+    ///
+    /// The FIFO is set in bypass mode (flushing it) before configuring the
+    /// batched streams, then the mode of the plan is set.
+    ///
+    /// The high-g accelerometer and SFLP data rates of the plan are set if
+    /// their data are batched; otherwise their configuration is left as is.
+    ///
+    /// Returns `Error::InvalidFifoPlan` with the reason if the plan is
+    /// rejected by `FifoPlan::budget`.
+    pub async fn fifo_plan_set(&mut self, val: &FifoPlan) -> Result<(), Error<B::Error>> {
+        val.budget().map_err(Error::InvalidFifoPlan)?;
+
+        /* 1. flush the FIFO */
+        self.fifo_mode_set(FifoMode::Bypass).await?;
+
+        /* 2. batched streams */
+        self.fifo_xl_batch_set(val.xl).await?;
+        self.fifo_gy_batch_set(val.gy).await?;
+        self.fifo_hg_xl_batch_enable_set(val.hg_xl != HgXlDataRate::Off)
+            .await?;
+        self.fifo_temp_batch_set(val.temp).await?;
+        self.fifo_timestamp_batch_set(val.timestamp).await?;
+        self.fifo_sflp_batch_set(val.sflp.clone()).await?;
+
+        /* 3. data rates of the batched streams */
+        if val.hg_xl != HgXlDataRate::Off {
+            let (_, reg_out_en) = self.hg_xl_setup_get().await?;
+            self.hg_xl_setup(val.hg_xl, reg_out_en).await?;
+        }
+        if val.sflp != FifoSflpRaw::default() {
+            self.sflp_data_rate_set(val.sflp_rate).await?;
+        }
+
+        /* 4. compression and watermark */
+        self.fifo_compress_algo_set(val.compression).await?;
+        self.fifo_compress_algo_real_time_enable_set(val.compression_enable)
+            .await?;
+        self.fifo_watermark_set(val.watermark).await?;
+
+        /* 5. FIFO mode */
+        self.fifo_mode_set(val.mode).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::*;
+
+    #[test]
+    fn compression_without_forced_uncompressed_words() {
+        let plan = FifoPlan::new(FifoMode::Stream)
+            .xl(FifoBatch::_120hz)
+            .gy(FifoBatch::_120hz)
+            .compression_enable(true);
+        let budget = plan.budget().unwrap();
+        assert_eq!(budget.words_per_second, 240.0);
+        assert_eq!(budget.words_per_second_min, 80.0);
+
+        let budget = plan.compression(FifoCompressAlgo::_8To1).budget().unwrap();
+        assert_eq!(budget.words_per_second_min, 30.0 + 210.0 / 3.0);
+    }
+
+    #[test]
+    fn compression_disabled() {
+        let plan = FifoPlan::new(FifoMode::Stream)
+            .xl(FifoBatch::_120hz)
+            .compression(FifoCompressAlgo::_8To1);
+        let budget = plan.budget().unwrap();
+        assert_eq!(budget.words_per_second_min, budget.words_per_second);
+
+        let plan = FifoPlan::new(FifoMode::Stream)
+            .temp(FifoTempBatch::_15hz)
+            .compression_enable(true);
+        assert_eq!(plan.budget(), Err(FifoPlanError::CompressionWithoutXlGy));
+    }
+
+    #[test]
+    fn plan_set() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+
+        let plan = FifoPlan::new(FifoMode::Stream);
+        assert_eq!(
+            block_on(sensor.fifo_plan_set(&plan)),
+            Err(Error::InvalidFifoPlan(FifoPlanError::NoStream))
+        );
+
+        let sflp = FifoSflpRaw {
+            game_rotation: 1,
+            gravity: 0,
+            gbias: 0,
+        };
+        let plan = FifoPlan::new(FifoMode::Stream)
+            .xl(FifoBatch::_120hz)
+            .hg_xl(HgXlDataRate::_960hz)
+            .sflp(sflp.clone(), SflpDataRate::_120hz)
+            .watermark(64);
+        block_on(sensor.fifo_plan_set(&plan)).unwrap();
+
+        assert_eq!(
+            block_on(sensor.hg_xl_setup_get()).unwrap(),
+            (HgXlDataRate::_960hz, false)
+        );
+        assert_eq!(
+            block_on(sensor.sflp_data_rate_get()).unwrap(),
+            SflpDataRate::_120hz
+        );
+        assert!(block_on(sensor.fifo_hg_xl_batch_enable_get()).unwrap());
+        assert_eq!(block_on(sensor.fifo_sflp_batch_get()).unwrap(), sflp);
+        assert_eq!(block_on(sensor.fifo_watermark_get()).unwrap(), 64);
+        assert_eq!(block_on(sensor.fifo_mode_get()).unwrap(), FifoMode::Stream);
+    }
+}
@@ -0,0 +1,207 @@
+use super::prelude::*;
+use super::{BusOperation, DelayNs, Error, Lsm6dsv320x, bisync, only_async, only_sync};
+
+/// Number of FIFO words read by `FifoReader` with a single burst read.
+pub const FIFO_READER_WORDS: usize = 32;
+
+/// Item produced by `FifoReader`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FifoItem {
+    /// FIFO word.
+    Frame(FifoFrame),
+    /// FIFO full or overrun detected before the following frames.
+    ///
+    /// `lost_estimate` is the number of batch time slots lost, estimated from
+    /// the gap of the FIFO tag counter: the counter is 2 bits wide, so the
+    /// estimate is a lower bound (at least 1) on overrun, and 0 if the FIFO
+    /// is full but no data has been lost yet.
+    Overrun { lost_estimate: u16 },
+}
+
+/// Continuous reader of the FIFO.
+///
+/// The reader owns the driver: it waits for the FIFO watermark polling the
+/// FIFO status every `poll_ms` milliseconds, drains the FIFO with burst reads
+/// of up to `FIFO_READER_WORDS` words and yields the decoded words one by one.
+/// FIFO full and overrun are reported as `FifoItem::Overrun`, so gaps in the
+/// data can be detected.
+///
+/// ```rust,ignore
+/// let mut reader = FifoReader::new(sensor, 10);
+/// loop {
+///     match reader.next().await.unwrap() {
+///         FifoItem::Frame(frame) => log(frame),
+///         FifoItem::Overrun { lost_estimate } => log_gap(lost_estimate),
+///     }
+/// }
+/// ```
+///
+/// With the blocking API, `FifoReader` is an `Iterator` that never ends.
+pub struct FifoReader<B, T>
+where
+    B: BusOperation,
+    T: DelayNs,
+{
+    sensor: Lsm6dsv320x<B, T, MainBank>,
+    buf: [u8; FIFO_READER_WORDS * FIFO_WORD_SIZE],
+    len: usize,
+    pos: usize,
+    poll_ms: u32,
+    last_cnt: Option<u8>,
+}
+
+#[bisync]
+impl<B: BusOperation, T: DelayNs> FifoReader<B, T> {
+    /// Create a reader polling the FIFO status every `poll_ms` milliseconds
+    /// while waiting for the watermark.
+    ///
+    /// The FIFO (watermark, batching, mode) must be configured by the caller.
+    pub fn new(sensor: Lsm6dsv320x<B, T, MainBank>, poll_ms: u32) -> Self {
+        Self {
+            sensor,
+            buf: [0; FIFO_READER_WORDS * FIFO_WORD_SIZE],
+            len: 0,
+            pos: 0,
+            poll_ms,
+            last_cnt: None,
+        }
+    }
+
+    /// Release the driver.
+    ///
+    /// Words already read from the FIFO and not yet yielded are dropped.
+    pub fn release(self) -> Lsm6dsv320x<B, T, MainBank> {
+        self.sensor
+    }
+
+    /// Get the next item, waiting for the FIFO watermark if needed.
+    #[only_async]
+    pub async fn next(&mut self) -> Result<FifoItem, Error<B::Error>> {
+        self.item_read().await
+    }
+
+    async fn item_read(&mut self) -> Result<FifoItem, Error<B::Error>> {
+        loop {
+            while self.pos < self.len {
+                let start = self.pos * FIFO_WORD_SIZE;
+                self.pos += 1;
+
+                let frame = FifoFrame::from_bytes(&self.buf[start..start + FIFO_WORD_SIZE])
+                    .unwrap_or(FifoFrame::Empty);
+                if frame != FifoFrame::Empty {
+                    self.last_cnt = Some(frame.cnt());
+                    return Ok(FifoItem::Frame(frame));
+                }
+            }
+
+            /* keep draining if the last burst read filled the buffer */
+            let drain = self.len == FIFO_READER_WORDS;
+            let state = self.sensor.fifo_state_get().await?;
+            if !drain && !state.watermark && !state.full && !state.overrun {
+                self.sensor.tim.delay_ms(self.poll_ms).await;
+                continue;
+            }
+
+            self.len = self
+                .sensor
+                .fifo_words_read_raw(state.level, &mut self.buf)
+                .await?;
+            self.pos = 0;
+
+            if state.overrun || state.full {
+                let lost_estimate = if state.overrun {
+                    self.lost_estimate()
+                } else {
+                    0
+                };
+                return Ok(FifoItem::Overrun { lost_estimate });
+            }
+        }
+    }
+
+    /// Estimate the time slots lost between the last word yielded and the
+    /// first word read.
+    fn lost_estimate(&self) -> u16 {
+        let first = if self.len > 0 {
+            Some(FifoDataOutTag::from_bits(self.buf[0]).tag_cnt())
+        } else {
+            None
+        };
+
+        match (self.last_cnt, first) {
+            (Some(last), Some(first)) => {
+                let gap = first.wrapping_sub(last).wrapping_sub(1) & 0x03;
+                (gap as u16).max(1)
+            }
+            _ => 1,
+        }
+    }
+}
+
+#[only_sync]
+impl<B: BusOperation, T: DelayNs> Iterator for FifoReader<B, T> {
+    type Item = Result<FifoItem, Error<B::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.item_read())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::*;
+
+    fn status_set(reader: &mut FifoReader<Simulator, NoDelay>, status: FifoStatusReg) {
+        let bytes = status.into_bits().to_le_bytes();
+        let main = MemBank::MainMemBank;
+        let bus = &mut reader.sensor.bus;
+        bus.reg_set(main, Reg::FifoStatus1 as u8, bytes[0]);
+        bus.reg_set(main, Reg::FifoStatus2 as u8, bytes[1]);
+    }
+
+    fn xl_push(reader: &mut FifoReader<Simulator, NoDelay>, cnt: u8, x: i16) {
+        let mut data = [0; 6];
+        data[..2].copy_from_slice(&x.to_le_bytes());
+        let raw = FifoOutRaw {
+            tag: Tag::XlNc,
+            cnt,
+            data,
+        };
+        assert!(reader.sensor.bus.fifo_push_raw(&raw));
+    }
+
+    fn xl(cnt: u8, x: i16) -> FifoItem {
+        FifoItem::Frame(FifoFrame::XlNc {
+            cnt,
+            xyz: [x, 0, 0],
+        })
+    }
+
+    #[test]
+    fn overrun_lost_estimate() {
+        let sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+        let mut reader = FifoReader::new(sensor, 1);
+
+        status_set(&mut reader, FifoStatusReg::new().with_fifo_wtm_ia(1));
+        xl_push(&mut reader, 0, 10);
+        xl_push(&mut reader, 1, 11);
+        assert_eq!(block_on(reader.item_read()), Ok(xl(0, 10)));
+        assert_eq!(block_on(reader.item_read()), Ok(xl(1, 11)));
+        assert_eq!(reader.sensor.bus.fifo_len(), 0);
+
+        /* the slot with counter 2 has been overwritten */
+        status_set(&mut reader, FifoStatusReg::new().with_fifo_ovr_ia(1));
+        xl_push(&mut reader, 3, 13);
+        xl_push(&mut reader, 0, 14);
+        xl_push(&mut reader, 1, 15);
+        assert_eq!(
+            block_on(reader.item_read()),
+            Ok(FifoItem::Overrun { lost_estimate: 1 })
+        );
+        assert_eq!(block_on(reader.item_read()), Ok(xl(3, 13)));
+        assert_eq!(block_on(reader.item_read()), Ok(xl(0, 14)));
+        assert_eq!(block_on(reader.item_read()), Ok(xl(1, 15)));
+        assert_eq!(reader.sensor.bus.fifo_len(), 0);
+    }
+}
@@ -0,0 +1,343 @@
+use super::prelude::*;
+use super::{BusOperation, DelayNs, EmbAdvFunctions, Error, Lsm6dsv320x, bisync};
+
+/// First address of the FSM programs in the advanced pages.
+pub const FSM_START_ADDRESS: u16 = 0x35C;
+
+/// End (exclusive) of the advanced pages area available to the FSM programs.
+pub const FSM_END_ADDRESS: u16 = 0x800;
+
+/// Maximum number of FSM programs.
+pub const FSM_MAX_PROGRAMS: usize = 8;
+
+/// Size in bytes of the fixed part of the FSM program header.
+const FSM_HEADER_SIZE: usize = 6;
+
+/// FSMs in program order.
+const FSM_FLAGS: [FsmSet; FSM_MAX_PROGRAMS] = [
+    FsmSet::FSM1,
+    FsmSet::FSM2,
+    FsmSet::FSM3,
+    FsmSet::FSM4,
+    FsmSet::FSM5,
+    FsmSet::FSM6,
+    FsmSet::FSM7,
+    FsmSet::FSM8,
+];
+
+/// Reason an FSM program is rejected by `FsmProgram::new`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FsmProgramError {
+    /// The program is shorter than the fixed header.
+    TooShort,
+    /// SIZE differs from the length of the program.
+    SizeMismatch,
+    /// Thresholds, masks and timers exceed the program pointer.
+    VariableDataOverflow,
+    /// RESET or PROGRAM pointer outside the instructions.
+    InvalidPointer,
+}
+
+/// FSM program, as laid out in the advanced pages.
+///
+/// The program starts with the fixed header:
+/// - CONFIG_A: number of thresholds (bits 7:6), masks (bits 5:4), long timers
+///   (bits 3:2) and short timers (bits 1:0);
+/// - CONFIG_B, SIZE (program length in bytes), SETTINGS;
+/// - RESET and PROGRAM pointers, as offsets from the start of the program.
+///
+/// It is followed by the variable data, i.e. the thresholds (half-precision
+/// float), the masks (mask and temporary mask), the long timers (16 bits) and
+/// the short timers (8 bits), and then by the instructions, starting at the
+/// PROGRAM pointer.
+///
+/// ```rust,ignore
+/// let four_d = FsmProgram::new(FOUR_D_PROGRAM).unwrap();
+/// let tilt = FsmProgram::new(TILT_PROGRAM).unwrap();
+/// sensor.fsm_load_programs(&[four_d, tilt]).unwrap();
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FsmProgram<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> FsmProgram<'a> {
+    /// Parse and validate the program in `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, FsmProgramError> {
+        if bytes.len() < FSM_HEADER_SIZE {
+            return Err(FsmProgramError::TooShort);
+        }
+
+        let program = Self { bytes };
+        if program.size() as usize != bytes.len() {
+            return Err(FsmProgramError::SizeMismatch);
+        }
+        let program_pointer = program.program_pointer() as usize;
+        if program.variable_data_end() > program_pointer {
+            return Err(FsmProgramError::VariableDataOverflow);
+        }
+        if program_pointer >= bytes.len()
+            || (program.reset_pointer() as usize) < program_pointer
+            || program.reset_pointer() as usize >= bytes.len()
+        {
+            return Err(FsmProgramError::InvalidPointer);
+        }
+
+        Ok(program)
+    }
+
+    /// Get the whole program.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Get CONFIG_A.
+    pub fn config_a(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// Get CONFIG_B.
+    pub fn config_b(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    /// Get SIZE, the length of the program in bytes.
+    pub fn size(&self) -> u8 {
+        self.bytes[2]
+    }
+
+    /// Get SETTINGS.
+    pub fn settings(&self) -> u8 {
+        self.bytes[3]
+    }
+
+    /// Get the RESET pointer.
+    pub fn reset_pointer(&self) -> u8 {
+        self.bytes[4]
+    }
+
+    /// Get the PROGRAM pointer.
+    pub fn program_pointer(&self) -> u8 {
+        self.bytes[5]
+    }
+
+    /// Get the thresholds, in half-precision float format.
+    pub fn thresholds(&self) -> impl Iterator<Item = u16> + 'a {
+        self.section(0)
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    /// Get the masks, as (mask, temporary mask).
+    pub fn masks(&self) -> impl Iterator<Item = (u8, u8)> + 'a {
+        self.section(1).chunks_exact(2).map(|b| (b[0], b[1]))
+    }
+
+    /// Get the long timers.
+    pub fn long_timers(&self) -> impl Iterator<Item = u16> + 'a {
+        self.section(2)
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    /// Get the short timers.
+    pub fn timers(&self) -> impl Iterator<Item = u8> + 'a {
+        self.section(3).iter().copied()
+    }
+
+    /// Get the instructions, from the PROGRAM pointer.
+    pub fn instructions(&self) -> &'a [u8] {
+        &self.bytes[self.program_pointer() as usize..]
+    }
+
+    /// Get the size in bytes of each variable data section: thresholds,
+    /// masks, long timers and short timers.
+    fn section_sizes(&self) -> [usize; 4] {
+        let config_a = self.config_a();
+        [
+            ((config_a >> 6) & 0x03) as usize * 2,
+            ((config_a >> 4) & 0x03) as usize * 2,
+            ((config_a >> 2) & 0x03) as usize * 2,
+            (config_a & 0x03) as usize,
+        ]
+    }
+
+    fn variable_data_end(&self) -> usize {
+        FSM_HEADER_SIZE + self.section_sizes().iter().sum::<usize>()
+    }
+
+    fn section(&self, idx: usize) -> &'a [u8] {
+        let sizes = self.section_sizes();
+        let start = FSM_HEADER_SIZE + sizes[..idx].iter().sum::<usize>();
+        &self.bytes[start..start + sizes[idx]]
+    }
+}
+
+#[bisync]
+impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
+    /// Load FSM programs.
+    ///
+    /// The FSMs are disabled, the programs are written contiguously from
+    /// `FSM_START_ADDRESS` and the number of programs and start address are
+    /// set. Programs and configuration are then read back and, if they match,
+    /// the first `programs.len()` FSMs are enabled.
+    ///
+    /// Returns `Error::InvalidConfiguration` if more than `FSM_MAX_PROGRAMS`
+    /// programs are given or they do not fit before `FSM_END_ADDRESS`, and
+    /// `Error::UnexpectedValue` if the read-back fails.
+    pub async fn fsm_load_programs(
+        &mut self,
+        programs: &[FsmProgram<'_>],
+    ) -> Result<(), Error<B::Error>> {
+        let total: usize = programs.iter().map(|program| program.size() as usize).sum();
+        if programs.len() > FSM_MAX_PROGRAMS
+            || FSM_START_ADDRESS as usize + total > FSM_END_ADDRESS as usize
+        {
+            return Err(Error::InvalidConfiguration);
+        }
+
+        /* 1. disable the FSMs */
+        self.fsm_enable_set(FsmSet::empty()).await?;
+
+        /* 2. write the programs */
+        let mut address = FSM_START_ADDRESS;
+        for program in programs {
+            self.ln_pg_write(address, program.bytes(), program.size())
+                .await?;
+            address += program.size() as u16;
+        }
+        self.fsm_number_of_programs_set(programs.len() as u8)
+            .await?;
+        self.fsm_start_address_set(FSM_START_ADDRESS).await?;
+
+        /* 3. read back */
+        let mut buf = [0u8; 256];
+        let mut address = FSM_START_ADDRESS;
+        for program in programs {
+            let len = program.size() as usize;
+            self.ln_pg_read(address, &mut buf[..len], program.size())
+                .await?;
+            if buf[..len] != *program.bytes() {
+                return Err(Error::UnexpectedValue);
+            }
+            address += program.size() as u16;
+        }
+        if self.fsm_number_of_programs_get().await? != programs.len() as u8
+            || self.fsm_start_address_get().await? != FSM_START_ADDRESS
+        {
+            return Err(Error::UnexpectedValue);
+        }
+
+        /* 4. enable the FSMs */
+        let mut fsm = FsmSet::empty();
+        for &flag in FSM_FLAGS[..programs.len()].iter() {
+            fsm.insert(flag);
+        }
+        self.fsm_enable_set(fsm).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::*;
+
+    /// CONFIG_A, CONFIG_B, SIZE, SETTINGS, RESET and PROGRAM pointers, one
+    /// threshold, one short timer and two instructions.
+    const PROGRAM: [u8; 11] = [0x41, 0x00, 11, 0x00, 9, 9, 0x00, 0x3C, 0x05, 0x22, 0x00];
+
+    /// No variable data, one instruction.
+    const SHORT_PROGRAM: [u8; 7] = [0x00, 0x00, 7, 0x00, 6, 6, 0x22];
+
+    #[test]
+    fn program_parse() {
+        let program = FsmProgram::new(&PROGRAM).unwrap();
+
+        assert_eq!(program.size(), 11);
+        assert!(program.thresholds().eq([0x3C00]));
+        assert_eq!(program.masks().count(), 0);
+        assert_eq!(program.long_timers().count(), 0);
+        assert!(program.timers().eq([0x05]));
+        assert_eq!(program.instructions(), [0x22, 0x00]);
+    }
+
+    #[test]
+    fn program_header_rejected() {
+        assert_eq!(
+            FsmProgram::new(&SHORT_PROGRAM[..5]),
+            Err(FsmProgramError::TooShort)
+        );
+
+        let mut bytes = SHORT_PROGRAM;
+        bytes[2] = 8;
+        assert_eq!(FsmProgram::new(&bytes), Err(FsmProgramError::SizeMismatch));
+
+        /* one threshold, but the instructions start right after the header */
+        let mut bytes = SHORT_PROGRAM;
+        bytes[0] = 0x40;
+        assert_eq!(
+            FsmProgram::new(&bytes),
+            Err(FsmProgramError::VariableDataOverflow)
+        );
+
+        /* PROGRAM at the end, RESET before PROGRAM, RESET at the end */
+        for (reset, program) in [(7, 7), (5, 6), (7, 6)] {
+            let mut bytes = SHORT_PROGRAM;
+            bytes[4] = reset;
+            bytes[5] = program;
+            assert_eq!(
+                FsmProgram::new(&bytes),
+                Err(FsmProgramError::InvalidPointer)
+            );
+        }
+    }
+
+    #[test]
+    fn load_programs() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+        let programs = [
+            FsmProgram::new(&PROGRAM).unwrap(),
+            FsmProgram::new(&SHORT_PROGRAM).unwrap(),
+        ];
+        block_on(sensor.fsm_load_programs(&programs)).unwrap();
+
+        let second = FSM_START_ADDRESS + PROGRAM.len() as u16;
+        for (n, &val) in PROGRAM.iter().enumerate() {
+            assert_eq!(sensor.bus.page_get(FSM_START_ADDRESS + n as u16), val);
+        }
+        for (n, &val) in SHORT_PROGRAM.iter().enumerate() {
+            assert_eq!(sensor.bus.page_get(second + n as u16), val);
+        }
+        assert_eq!(block_on(sensor.fsm_number_of_programs_get()).unwrap(), 2);
+        assert_eq!(
+            block_on(sensor.fsm_start_address_get()).unwrap(),
+            FSM_START_ADDRESS
+        );
+        assert_eq!(
+            block_on(sensor.fsm_enable_get()).unwrap(),
+            FsmSet::FSM1 | FsmSet::FSM2
+        );
+    }
+
+    #[test]
+    fn load_programs_too_large() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+
+        let programs = [FsmProgram::new(&SHORT_PROGRAM).unwrap(); FSM_MAX_PROGRAMS + 1];
+        assert_eq!(
+            block_on(sensor.fsm_load_programs(&programs)),
+            Err(Error::InvalidConfiguration)
+        );
+
+        /* 5 programs of 255 bytes do not fit in the FSM program area */
+        let mut bytes = [0u8; 255];
+        bytes[..FSM_HEADER_SIZE].copy_from_slice(&[0x00, 0x00, 255, 0x00, 6, 6]);
+        let programs = [FsmProgram::new(&bytes).unwrap(); 5];
+        assert_eq!(
+            block_on(sensor.fsm_load_programs(&programs)),
+            Err(Error::InvalidConfiguration)
+        );
+        assert_eq!(sensor.bus.page_get(FSM_START_ADDRESS), 0x00);
+        assert_eq!(block_on(sensor.fsm_number_of_programs_get()).unwrap(), 0);
+    }
+}
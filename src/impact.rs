@@ -0,0 +1,223 @@
+use super::prelude::*;
+use super::{BusOperation, DelayNs, Error, Lsm6dsv320x, RegisterOperation, bisync};
+
+/// Maximum window (s) of the HIC15 criterion.
+const HIC15_WINDOW_S: f32 = 0.015;
+/// Maximum window (s) of the HIC36 criterion.
+const HIC36_WINDOW_S: f32 = 0.036;
+
+/// High-g accelerometer peak tracked on-chip, in g.
+///
+/// When peak tracking is enabled (`xl_hg_peak_tracking_enable_set`), the
+/// device writes the peak of each axis in FIFO with `Tag::HgXlPeak`.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct HgPeak {
+    pub x_g: f32,
+    pub y_g: f32,
+    pub z_g: f32,
+}
+
+impl HgPeak {
+    /// Decode a raw peak (x, y, z) with the high-g accelerometer full scale.
+    pub fn from_raw(xyz: [i16; 3], full_scale: HgXlFullScale) -> Self {
+        let [x_g, y_g, z_g] = xyz.map(|lsb| full_scale.to_mg(lsb) / 1000.0);
+        Self { x_g, y_g, z_g }
+    }
+
+    /// Decode the peak of a `FifoFrame::HgXlPeak` word; None for any other
+    /// word.
+    pub fn from_frame(frame: &FifoFrame, full_scale: HgXlFullScale) -> Option<Self> {
+        match frame {
+            FifoFrame::HgXlPeak { xyz, .. } => Some(Self::from_raw(*xyz, full_scale)),
+            _ => None,
+        }
+    }
+
+    /// Get the resultant of the peak (g).
+    pub fn resultant_g(&self) -> f32 {
+        libm::sqrtf(self.x_g * self.x_g + self.y_g * self.y_g + self.z_g * self.z_g)
+    }
+}
+
+/// Impact severity metrics over a high-g accelerometer window.
+///
+/// Metrics are computed on the resultant acceleration, sampled at a constant
+/// data rate:
+/// - `peak_g`: highest resultant acceleration (g);
+/// - `duration_above_s`: time spent above the threshold (s);
+/// - `delta_v_mps`: velocity change accumulated above the threshold (m/s);
+/// - `hic15`, `hic36`: Head Injury Criterion with a maximum window of 15 ms
+///   and 36 ms, with acceleration in g and time in seconds.
+///
+/// The resultant includes gravity, which is negligible for the high-g range.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct ImpactMetrics {
+    pub peak_g: f32,
+    pub duration_above_s: f32,
+    pub delta_v_mps: f32,
+    pub hic15: f32,
+    pub hic36: f32,
+}
+
+impl ImpactMetrics {
+    /// Compute the metrics over consecutive high-g accelerometer samples
+    /// (x, y, z) in LSB, acquired at `data_rate` with `full_scale`.
+    ///
+    /// Samples above `threshold_g` contribute to the duration and delta-V.
+    pub fn compute(
+        samples: &[[i16; 3]],
+        full_scale: HgXlFullScale,
+        data_rate: HgXlDataRate,
+        threshold_g: f32,
+    ) -> Self {
+        Self::from_resultants(
+            samples.iter().map(|xyz| resultant_g(xyz, full_scale)),
+            data_rate.to_hz(),
+            threshold_g,
+        )
+    }
+
+    pub(crate) fn from_resultants<I>(samples: I, hz: f32, threshold_g: f32) -> Self
+    where
+        I: Iterator<Item = f32> + Clone,
+    {
+        if hz <= 0.0 {
+            return Self::default();
+        }
+        let dt = 1.0 / hz;
+
+        let mut metrics = Self::default();
+        for val in samples.clone() {
+            metrics.peak_g = metrics.peak_g.max(val);
+            if val > threshold_g {
+                metrics.duration_above_s += dt;
+                metrics.delta_v_mps += val * STANDARD_GRAVITY * dt;
+            }
+        }
+        metrics.hic15 = hic(samples.clone(), dt, HIC15_WINDOW_S);
+        metrics.hic36 = hic(samples, dt, HIC36_WINDOW_S);
+
+        metrics
+    }
+}
+
+#[bisync]
+impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
+    /// Decode the peak of a `FifoFrame::HgXlPeak` word with the high-g
+    /// accelerometer full scale currently set.
+    ///
+    /// Returns None for any other word.
+    pub async fn hg_xl_peak_get(
+        &mut self,
+        frame: &FifoFrame,
+    ) -> Result<Option<HgPeak>, Error<B::Error>> {
+        let full_scale = self.hg_xl_full_scale_get().await?;
+        Ok(HgPeak::from_frame(frame, full_scale))
+    }
+
+    /// Reset the High-g accelerometer peak tracking.
+    ///
+    /// The tracker is initialized again, so the next peak is tracked from
+    /// the following samples.
+    pub async fn xl_hg_peak_tracking_reset(&mut self) -> Result<(), Error<B::Error>> {
+        self.operate_over_embed(async |state| {
+            let mut emb_func_init_b = EmbFuncInitB::read(state).await?;
+            emb_func_init_b.set_pt_init(0);
+            emb_func_init_b.write(state).await?;
+            emb_func_init_b.set_pt_init(1);
+            emb_func_init_b.write(state).await
+        })
+        .await
+    }
+}
+
+pub(crate) fn resultant_g(xyz: &[i16; 3], full_scale: HgXlFullScale) -> f32 {
+    HgPeak::from_raw(*xyz, full_scale).resultant_g()
+}
+
+/// Head Injury Criterion: the maximum over the windows [t1, t2] not longer
+/// than `window_s` of (t2 - t1) * (mean acceleration)^2.5.
+fn hic<I>(samples: I, dt: f32, window_s: f32) -> f32
+where
+    I: Iterator<Item = f32> + Clone,
+{
+    /* rounded, as window_s / dt is not exact (0.015 / 0.001 < 15) */
+    let max_len = (libm::roundf(window_s / dt) as usize).max(1);
+    let mut hic: f32 = 0.0;
+    let mut start = samples;
+
+    loop {
+        let mut integral = 0.0;
+        for (n, val) in start.clone().take(max_len).enumerate() {
+            integral += val * dt;
+            let t = (n + 1) as f32 * dt;
+            let mean = integral / t;
+            hic = hic.max(t * mean * mean * libm::sqrtf(mean));
+        }
+
+        if start.next().is_none() {
+            break;
+        }
+    }
+
+    hic
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HZ: f32 = 1000.0;
+
+    /// `len_ms` samples of `a_g` at 1 kHz, with 5 ms of rest before and after.
+    fn pulse(a_g: f32, len_ms: usize) -> impl Iterator<Item = f32> + Clone {
+        let rest = core::iter::repeat_n(0.0, 5);
+        rest.clone()
+            .chain(core::iter::repeat_n(a_g, len_ms))
+            .chain(rest)
+    }
+
+    fn assert_close(val: f32, expected: f32) {
+        assert!(
+            (val - expected).abs() <= expected * 1e-3,
+            "{val} != {expected}"
+        );
+    }
+
+    #[test]
+    fn hic_constant_pulse() {
+        /* T * a^2.5 = 0.01 s * 100^2.5 */
+        let hic15 = hic(pulse(100.0, 10), 1.0 / HZ, HIC15_WINDOW_S);
+        assert_close(hic15, 1000.0);
+
+        let hic15 = hic(pulse(20.0, 15), 1.0 / HZ, HIC15_WINDOW_S);
+        assert_close(hic15, 0.015 * libm::powf(20.0, 2.5));
+    }
+
+    #[test]
+    fn metrics_constant_pulse() {
+        let metrics = ImpactMetrics::from_resultants(pulse(100.0, 10), HZ, 50.0);
+
+        assert_eq!(metrics.peak_g, 100.0);
+        assert_close(metrics.duration_above_s, 0.010);
+        assert_close(metrics.delta_v_mps, 100.0 * STANDARD_GRAVITY * 0.010);
+        assert_close(metrics.hic15, 1000.0);
+        assert_close(metrics.hic36, 1000.0);
+    }
+
+    #[test]
+    fn metrics_window_clamped() {
+        let metrics = ImpactMetrics::from_resultants(pulse(100.0, 50), HZ, 50.0);
+
+        assert_close(metrics.duration_above_s, 0.050);
+        assert_close(metrics.delta_v_mps, 100.0 * STANDARD_GRAVITY * 0.050);
+        assert_close(metrics.hic15, 0.015 * 1e5);
+        assert_close(metrics.hic36, 0.036 * 1e5);
+    }
+
+    #[test]
+    fn metrics_no_data_rate() {
+        let metrics = ImpactMetrics::from_resultants(pulse(100.0, 10), 0.0, 50.0);
+        assert_eq!(metrics, ImpactMetrics::default());
+    }
+}
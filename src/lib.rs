@@ -12,10 +12,28 @@ pub mod asynchronous {
     use st_mems_bus::asynchronous::*;
 
     pub mod driver;
+    pub mod events;
+    pub mod fifo;
+    pub mod fifo_plan;
+    pub mod fifo_reader;
+    pub mod fsm;
+    pub mod impact;
+    #[cfg(any(feature = "test-support", test))]
+    pub mod mock;
+    pub mod orientation;
     pub mod prelude;
+    pub mod reg_config;
     pub mod register;
+    pub mod routing;
+    pub mod sflp;
+    pub mod shock;
+    #[cfg(any(feature = "sim", test))]
+    pub mod sim;
+    pub mod units;
+    pub mod wait;
 
     pub use driver::*;
+    pub use wait::*;
 }
 
 #[cfg(feature = "blocking")]
@@ -28,8 +46,24 @@ pub mod blocking {
     use st_mems_bus::blocking::*;
 
     pub mod driver;
+    pub mod events;
+    pub mod fifo;
+    pub mod fifo_plan;
+    pub mod fifo_reader;
+    pub mod fsm;
+    pub mod impact;
+    #[cfg(any(feature = "test-support", test))]
+    pub mod mock;
+    pub mod orientation;
     pub mod prelude;
+    pub mod reg_config;
     pub mod register;
+    pub mod routing;
+    pub mod sflp;
+    pub mod shock;
+    #[cfg(any(feature = "sim", test))]
+    pub mod sim;
+    pub mod units;
 
     pub use driver::*;
 }
@@ -0,0 +1,526 @@
+use core::convert::Infallible;
+
+use super::prelude::*;
+use super::{BusOperation, bisync};
+
+/// Number of transactions `RecordingBus` can log.
+pub const MOCK_LOG_LEN: usize = 256;
+
+/// Number of data bytes stored for each logged transaction.
+pub const MOCK_DATA_LEN: usize = 8;
+
+/// Number of scripted bytes `RecordingBus` can hold.
+pub const MOCK_SCRIPT_LEN: usize = 64;
+
+/// Direction of a bus transaction.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BusOp {
+    Read,
+    Write,
+}
+
+/// Register access logged by `RecordingBus`.
+///
+/// `bank` is the memory bank selected when the access is performed; only the
+/// first `MOCK_DATA_LEN` bytes of data are stored.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transaction {
+    pub op: BusOp,
+    pub bank: MemBank,
+    pub reg: u8,
+    pub len: usize,
+    pub data: [u8; MOCK_DATA_LEN],
+}
+
+impl Transaction {
+    /// Single byte write of `val` to `reg` of `bank`.
+    pub const fn write(bank: MemBank, reg: u8, val: u8) -> Self {
+        Self::single(BusOp::Write, bank, reg, val)
+    }
+
+    /// Single byte read of `val` from `reg` of `bank`.
+    pub const fn read(bank: MemBank, reg: u8, val: u8) -> Self {
+        Self::single(BusOp::Read, bank, reg, val)
+    }
+
+    /// Get the data stored for the transaction.
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len.min(MOCK_DATA_LEN)]
+    }
+
+    const fn single(op: BusOp, bank: MemBank, reg: u8, val: u8) -> Self {
+        let mut data = [0; MOCK_DATA_LEN];
+        data[0] = val;
+        Self {
+            op,
+            bank,
+            reg,
+            len: 1,
+            data,
+        }
+    }
+
+    fn new(op: BusOp, bank: MemBank, reg: u8, buf: &[u8]) -> Self {
+        let mut data = [0; MOCK_DATA_LEN];
+        let len = buf.len().min(MOCK_DATA_LEN);
+        data[..len].copy_from_slice(&buf[..len]);
+        Self {
+            op,
+            bank,
+            reg,
+            len: buf.len(),
+            data,
+        }
+    }
+}
+
+/// Recording mock of the bus.
+///
+/// It implements `BusOperation`, so it can be given to `Lsm6dsv320x::from_bus`
+/// to check the exact register sequence emitted by an API, e.g. against the
+/// application note:
+///
+/// ```rust,ignore
+/// let mut sensor = Lsm6dsv320x::from_bus(RecordingBus::new(), NoDelay);
+/// sensor.gy_full_scale_set(GyFullScale::_2000dps).unwrap();
+///
+/// let log = sensor.bus.log();
+/// assert_eq!(log[0], Transaction::read(MemBank::MainMemBank, Reg::Ctrl6 as u8, 0x00));
+/// ```
+///
+/// Every access is logged with the memory bank selected through
+/// FUNC_CFG_ACCESS (0x01). Reads return the scripted bytes (`script_read`)
+/// first, then the last value written to the register, or 0.
+pub struct RecordingBus {
+    log: [Transaction; MOCK_LOG_LEN],
+    log_len: usize,
+    dropped: usize,
+    script: [u8; MOCK_SCRIPT_LEN],
+    script_head: usize,
+    script_len: usize,
+    regs: [[u8; 256]; 3],
+    address: u8,
+}
+
+impl Default for RecordingBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecordingBus {
+    /// Create a mock with an empty log and all the registers at 0.
+    pub fn new() -> Self {
+        Self {
+            log: [Transaction::write(MemBank::MainMemBank, 0, 0); MOCK_LOG_LEN],
+            log_len: 0,
+            dropped: 0,
+            script: [0; MOCK_SCRIPT_LEN],
+            script_head: 0,
+            script_len: 0,
+            regs: [[0; 256]; 3],
+            address: 0,
+        }
+    }
+
+    /// Get the transactions logged, in order.
+    pub fn log(&self) -> &[Transaction] {
+        &self.log[..self.log_len]
+    }
+
+    /// Get the transactions not logged because the log was full.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Clear the log; registers and script are preserved.
+    pub fn log_clear(&mut self) {
+        self.log_len = 0;
+        self.dropped = 0;
+    }
+
+    /// Get the transactions of the log that are writes.
+    pub fn writes(&self) -> impl Iterator<Item = &Transaction> {
+        self.log().iter().filter(|t| t.op == BusOp::Write)
+    }
+
+    /// Queue `data` to be returned by the following reads, byte by byte and
+    /// regardless of the register read.
+    ///
+    /// Returns false if the script is full and nothing has been queued.
+    pub fn script_read(&mut self, data: &[u8]) -> bool {
+        if self.script_len + data.len() > MOCK_SCRIPT_LEN {
+            return false;
+        }
+
+        for &byte in data {
+            self.script[(self.script_head + self.script_len) % MOCK_SCRIPT_LEN] = byte;
+            self.script_len += 1;
+        }
+        true
+    }
+
+    /// Get the number of scripted bytes not read yet.
+    pub fn script_len(&self) -> usize {
+        self.script_len
+    }
+
+    /// Get a register value without logging.
+    pub fn reg_get(&self, bank: MemBank, reg: u8) -> u8 {
+        self.regs[bank as usize][reg as usize]
+    }
+
+    /// Set a register value without logging.
+    pub fn reg_set(&mut self, bank: MemBank, reg: u8, val: u8) {
+        self.regs[bank as usize][reg as usize] = val;
+    }
+
+    fn bank(&self) -> MemBank {
+        let func_cfg_access = FuncCfgAccess::from_bits(
+            self.regs[MemBank::MainMemBank as usize][Reg::FuncCfgAccess as usize],
+        );
+        if func_cfg_access.emb_func_reg_access() == 1 {
+            MemBank::EmbedFuncMemBank
+        } else if func_cfg_access.shub_reg_access() == 1 {
+            MemBank::SensorHubMemBank
+        } else {
+            MemBank::MainMemBank
+        }
+    }
+
+    /// FUNC_CFG_ACCESS is available in all the banks.
+    fn reg_bank(&self, reg: u8) -> MemBank {
+        if reg == Reg::FuncCfgAccess as u8 {
+            MemBank::MainMemBank
+        } else {
+            self.bank()
+        }
+    }
+
+    fn record(&mut self, transaction: Transaction) {
+        if self.log_len == MOCK_LOG_LEN {
+            self.dropped += 1;
+            return;
+        }
+
+        self.log[self.log_len] = transaction;
+        self.log_len += 1;
+    }
+
+    fn read(&mut self, reg: u8, rbuf: &mut [u8]) {
+        let bank = self.bank();
+        for (i, byte) in rbuf.iter_mut().enumerate() {
+            let addr = reg.wrapping_add(i as u8);
+            *byte = if self.script_len > 0 {
+                let val = self.script[self.script_head];
+                self.script_head = (self.script_head + 1) % MOCK_SCRIPT_LEN;
+                self.script_len -= 1;
+                val
+            } else {
+                self.regs[self.reg_bank(addr) as usize][addr as usize]
+            };
+        }
+        self.address = reg.wrapping_add(rbuf.len() as u8);
+        self.record(Transaction::new(BusOp::Read, bank, reg, rbuf));
+    }
+
+    fn write(&mut self, reg: u8, data: &[u8]) {
+        self.record(Transaction::new(BusOp::Write, self.bank(), reg, data));
+        for (i, &byte) in data.iter().enumerate() {
+            let addr = reg.wrapping_add(i as u8);
+            self.regs[self.reg_bank(addr) as usize][addr as usize] = byte;
+        }
+        self.address = reg.wrapping_add(data.len() as u8);
+    }
+}
+
+#[bisync]
+impl BusOperation for RecordingBus {
+    type Error = Infallible;
+
+    async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        self.read(self.address, rbuf);
+        Ok(())
+    }
+
+    async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        if let Some((&reg, data)) = wbuf.split_first() {
+            self.write(reg, data);
+        }
+        Ok(())
+    }
+
+    async fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.read(wbuf[0], rbuf);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sim::{NoDelay, block_on};
+    use super::super::{EmbAdvFunctions, Lsm6dsv320x};
+    use super::*;
+
+    const MAIN: MemBank = MemBank::MainMemBank;
+    const EMB: MemBank = MemBank::EmbedFuncMemBank;
+    const SHUB: MemBank = MemBank::SensorHubMemBank;
+
+    fn sensor() -> Lsm6dsv320x<RecordingBus, NoDelay, MainBank> {
+        Lsm6dsv320x::from_bus(RecordingBus::new(), NoDelay)
+    }
+
+    /// FUNC_CFG_ACCESS value selecting `bank`.
+    fn bank_access(bank: MemBank) -> u8 {
+        let access = FuncCfgAccess::from_bits(0);
+        match bank {
+            MemBank::MainMemBank => access,
+            MemBank::EmbedFuncMemBank => access.with_emb_func_reg_access(1),
+            MemBank::SensorHubMemBank => access.with_shub_reg_access(1),
+        }
+        .into_bits()
+    }
+
+    /// PAGE_RW value with page read or page write enabled.
+    fn page_rw_bits(read: bool, write: bool) -> u8 {
+        PageRw::from_bits(0)
+            .with_page_read(read as u8)
+            .with_page_write(write as u8)
+            .into_bits()
+    }
+
+    /// PAGE_SEL value selecting `page`.
+    fn page_sel_bits(page: u8) -> u8 {
+        PageSel::from_bits(0).with_page_sel(page).into_bits()
+    }
+
+    /// CTRL1 value with accelerometer `odr` and `mode` fields.
+    fn ctrl1_bits(odr: u8, mode: u8) -> u8 {
+        Ctrl1::from_bits(0)
+            .with_odr_xl(odr)
+            .with_op_mode_xl(mode)
+            .into_bits()
+    }
+
+    /// CTRL2 value with gyroscope `odr` and `mode` fields.
+    fn ctrl2_bits(odr: u8, mode: u8) -> u8 {
+        Ctrl2::from_bits(0)
+            .with_odr_g(odr)
+            .with_op_mode_g(mode)
+            .into_bits()
+    }
+
+    /// CTRL6 value with gyroscope full scale field `fs`.
+    fn ctrl6_bits(fs: u8) -> u8 {
+        Ctrl6::from_bits(0).with_fs_g(fs).into_bits()
+    }
+
+    /// Bank switch from the main bank to `bank`.
+    fn bank_enter(bank: MemBank) -> [Transaction; 2] {
+        let reg = Reg::FuncCfgAccess as u8;
+        [
+            Transaction::read(MAIN, reg, 0x00),
+            Transaction::write(MAIN, reg, bank_access(bank)),
+        ]
+    }
+
+    /// Bank switch from `bank` back to the main bank.
+    fn bank_exit(bank: MemBank) -> [Transaction; 2] {
+        let reg = Reg::FuncCfgAccess as u8;
+        [
+            Transaction::read(bank, reg, bank_access(bank)),
+            Transaction::write(bank, reg, 0x00),
+        ]
+    }
+
+    #[test]
+    fn operate_over_embed() {
+        let mut sensor = sensor();
+        block_on(sensor.sflp_game_rotation_init_set(true)).unwrap();
+
+        let reg = EmbReg::EmbFuncInitA as u8;
+        let mut expected = [Transaction::read(MAIN, 0, 0); 6];
+        expected[..2].copy_from_slice(&bank_enter(EMB));
+        expected[2] = Transaction::read(EMB, reg, 0x00);
+        let init = EmbFuncInitA::from_bits(0).with_sflp_game_init(1);
+        expected[3] = Transaction::write(EMB, reg, init.into_bits());
+        expected[4..].copy_from_slice(&bank_exit(EMB));
+        assert_eq!(sensor.bus.log(), expected);
+    }
+
+    #[test]
+    fn operate_over_sensor_hub() {
+        let mut sensor = sensor();
+        sensor.bus.reg_set(SHUB, SensHubReg::SensorHub1 as u8, 0x12);
+        sensor
+            .bus
+            .reg_set(SHUB, SensHubReg::SensorHub1 as u8 + 1, 0x34);
+
+        let mut buf = [0; 2];
+        block_on(sensor.sh_read_data_raw_get(&mut buf)).unwrap();
+        assert_eq!(buf, [0x12, 0x34]);
+
+        let mut expected = [Transaction::read(MAIN, 0, 0); 5];
+        expected[..2].copy_from_slice(&bank_enter(SHUB));
+        expected[2] = Transaction::new(BusOp::Read, SHUB, SensHubReg::SensorHub1 as u8, &buf);
+        expected[3..].copy_from_slice(&bank_exit(SHUB));
+        assert_eq!(sensor.bus.log(), expected);
+    }
+
+    #[test]
+    fn ln_pg_write_page_wrap() {
+        let mut sensor = sensor();
+        block_on(sensor.ln_pg_write(0x1FE, &[0xA1, 0xA2, 0xA3], 3)).unwrap();
+
+        let page_rw = EmbReg::PageRw as u8;
+        let page_sel = EmbReg::PageSel as u8;
+        let page_value = EmbReg::PageValue as u8;
+        let expected = [
+            bank_enter(EMB)[0],
+            bank_enter(EMB)[1],
+            Transaction::read(EMB, page_rw, 0x00),
+            Transaction::write(EMB, page_rw, page_rw_bits(false, true)),
+            Transaction::read(EMB, page_sel, page_sel_bits(0)),
+            Transaction::write(EMB, page_sel, page_sel_bits(1)),
+            Transaction::write(EMB, EmbReg::PageAddress as u8, 0xFE),
+            Transaction::write(EMB, page_value, 0xA1),
+            Transaction::write(EMB, page_value, 0xA2),
+            // lsb wraps from 0xFF to 0x00: next page
+            Transaction::read(EMB, page_sel, page_sel_bits(1)),
+            Transaction::write(EMB, page_sel, page_sel_bits(2)),
+            Transaction::write(EMB, page_value, 0xA3),
+            Transaction::read(EMB, page_sel, page_sel_bits(2)),
+            Transaction::write(EMB, page_sel, page_sel_bits(0)),
+            Transaction::read(EMB, page_rw, page_rw_bits(false, true)),
+            Transaction::write(EMB, page_rw, 0x00),
+            bank_exit(EMB)[0],
+            bank_exit(EMB)[1],
+        ];
+        assert_eq!(sensor.bus.log(), expected);
+    }
+
+    #[test]
+    fn ln_pg_read_page_wrap() {
+        let mut sensor = sensor();
+        sensor.bus.reg_set(EMB, EmbReg::PageValue as u8, 0x5A);
+
+        let mut buf = [0; 2];
+        block_on(sensor.ln_pg_read(0x0FF, &mut buf, 2)).unwrap();
+        assert_eq!(buf, [0x5A, 0x5A]);
+
+        let page_rw = EmbReg::PageRw as u8;
+        let page_sel = EmbReg::PageSel as u8;
+        let page_value = EmbReg::PageValue as u8;
+        let expected = [
+            bank_enter(EMB)[0],
+            bank_enter(EMB)[1],
+            Transaction::read(EMB, page_rw, 0x00),
+            Transaction::write(EMB, page_rw, page_rw_bits(true, false)),
+            Transaction::read(EMB, page_sel, page_sel_bits(0)),
+            Transaction::write(EMB, page_sel, page_sel_bits(0)),
+            Transaction::write(EMB, EmbReg::PageAddress as u8, 0xFF),
+            Transaction::read(EMB, page_value, 0x5A),
+            // lsb wraps from 0xFF to 0x00: next page
+            Transaction::read(EMB, page_sel, page_sel_bits(0)),
+            Transaction::write(EMB, page_sel, page_sel_bits(1)),
+            Transaction::read(EMB, page_value, 0x5A),
+            Transaction::read(EMB, page_sel, page_sel_bits(1)),
+            Transaction::write(EMB, page_sel, page_sel_bits(0)),
+            Transaction::read(EMB, page_rw, page_rw_bits(true, false)),
+            Transaction::write(EMB, page_rw, 0x00),
+            bank_exit(EMB)[0],
+            bank_exit(EMB)[1],
+        ];
+        assert_eq!(sensor.bus.log(), expected);
+    }
+
+    #[test]
+    fn haodr_set() {
+        let mut sensor = sensor();
+        // high-g accelerometer on: turned off and restored
+        let hg_on = Ctrl1XlHg::from_bits(0)
+            .with_odr_xl_hg(1)
+            .with_xl_hg_regout_en(1)
+            .into_bits();
+        sensor.bus.reg_set(MAIN, Reg::Ctrl1XlHg as u8, hg_on);
+
+        block_on(sensor.haodr_set(
+            DataRate::Ha01At125hz,
+            XlMode::HighAccuracyOdr,
+            DataRate::Ha01At125hz,
+            GyMode::HighAccuracyOdr,
+        ))
+        .unwrap();
+
+        let ctrl1 = Reg::Ctrl1 as u8;
+        let ctrl2 = Reg::Ctrl2 as u8;
+        let ctrl1_xl_hg = Reg::Ctrl1XlHg as u8;
+        let haodr = HaodrCfg::from_bits(0).with_haodr_sel(1).into_bits();
+        let expected = [
+            Transaction::read(MAIN, Reg::HaodrCfg as u8, 0x00),
+            Transaction::read(MAIN, ctrl1, 0x00),
+            Transaction::read(MAIN, ctrl2, 0x00),
+            Transaction::read(MAIN, ctrl1_xl_hg, hg_on),
+            Transaction::read(MAIN, Reg::CtrlEis as u8, 0x00),
+            // power-down
+            Transaction::write(MAIN, ctrl1, 0x00),
+            Transaction::write(MAIN, ctrl2, 0x00),
+            Transaction::write(MAIN, ctrl1_xl_hg, 0x00),
+            // HAODR and modes
+            Transaction::write(MAIN, Reg::HaodrCfg as u8, haodr),
+            Transaction::write(MAIN, ctrl1, ctrl1_bits(0, 1)),
+            Transaction::write(MAIN, ctrl2, ctrl2_bits(0, 1)),
+            // data rates
+            Transaction::write(MAIN, ctrl1, ctrl1_bits(6, 1)),
+            Transaction::write(MAIN, ctrl2, ctrl2_bits(6, 1)),
+            Transaction::write(MAIN, ctrl1_xl_hg, hg_on),
+        ];
+        assert_eq!(sensor.bus.log(), expected);
+    }
+
+    #[test]
+    fn xl_setup() {
+        let mut sensor = sensor();
+        block_on(sensor.xl_setup(DataRate::_120hz, XlMode::Normal)).unwrap();
+
+        let expected = [
+            Transaction::read(MAIN, Reg::Ctrl1 as u8, 0x00),
+            Transaction::read(MAIN, Reg::Ctrl2 as u8, 0x00),
+            Transaction::read(MAIN, Reg::HaodrCfg as u8, 0x00),
+            Transaction::write(MAIN, Reg::Ctrl1 as u8, ctrl1_bits(6, 7)),
+            Transaction::write(MAIN, Reg::HaodrCfg as u8, 0x00),
+        ];
+        assert_eq!(sensor.bus.log(), expected);
+    }
+
+    #[test]
+    fn gy_full_scale_set() {
+        let mut sensor = sensor();
+        block_on(sensor.gy_full_scale_set(GyFullScale::_2000dps)).unwrap();
+
+        let expected = [
+            Transaction::read(MAIN, Reg::Ctrl6 as u8, 0x00),
+            Transaction::read(MAIN, Reg::Ctrl2 as u8, 0x00),
+            Transaction::write(MAIN, Reg::Ctrl6 as u8, ctrl6_bits(4)),
+        ];
+        assert_eq!(sensor.bus.log(), expected);
+
+        // gyroscope on: set in power-down while changing the full scale
+        sensor.bus.log_clear();
+        sensor.bus.reg_set(MAIN, Reg::Ctrl2 as u8, ctrl2_bits(6, 0));
+        block_on(sensor.gy_full_scale_set(GyFullScale::_250dps)).unwrap();
+
+        let expected = [
+            Transaction::read(MAIN, Reg::Ctrl6 as u8, ctrl6_bits(4)),
+            Transaction::read(MAIN, Reg::Ctrl2 as u8, ctrl2_bits(6, 0)),
+            Transaction::write(MAIN, Reg::Ctrl2 as u8, 0x00),
+            Transaction::write(MAIN, Reg::Ctrl6 as u8, ctrl6_bits(1)),
+            Transaction::write(MAIN, Reg::Ctrl2 as u8, ctrl2_bits(6, 0)),
+        ];
+        assert_eq!(sensor.bus.log(), expected);
+    }
+}
@@ -0,0 +1,404 @@
+use core::ops::Mul;
+
+use super::prelude::*;
+use super::{BusOperation, DelayNs, Error, Lsm6dsv320x, bisync};
+
+/// Rotation order of the Euler angles.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum EulerConvention {
+    /// Yaw (z), then pitch (y), then roll (x), intrinsic: aerospace convention
+    /// (default).
+    #[default]
+    Zyx,
+    /// Roll (x), then pitch (y), then yaw (z), intrinsic.
+    Xyz,
+}
+
+/// Euler angles in radians, as rotations about x (roll), y (pitch) and z
+/// (yaw).
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct EulerAngles {
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+}
+
+impl EulerAngles {
+    /// Convert the angles to degrees.
+    pub fn to_degrees(self) -> Self {
+        Self {
+            roll: self.roll.to_degrees(),
+            pitch: self.pitch.to_degrees(),
+            yaw: self.yaw.to_degrees(),
+        }
+    }
+}
+
+/// 3x3 rotation matrix, row-major.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct RotationMatrix(pub [[f32; 3]; 3]);
+
+impl RotationMatrix {
+    /// Rotate the vector `v`.
+    pub fn apply(&self, v: [f32; 3]) -> [f32; 3] {
+        self.0
+            .map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+    }
+
+    /// Get the inverse rotation.
+    pub fn transpose(&self) -> Self {
+        let m = &self.0;
+        Self([
+            [m[0][0], m[1][0], m[2][0]],
+            [m[0][1], m[1][1], m[2][1]],
+            [m[0][2], m[1][2], m[2][2]],
+        ])
+    }
+}
+
+/// Frame the linear acceleration is expressed in.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum ReferenceFrame {
+    /// Sensor axes (default).
+    #[default]
+    Sensor,
+    /// World axes, defined by the SFLP game rotation vector.
+    World,
+}
+
+impl Quaternion {
+    pub const fn new(quat_w: f32, quat_x: f32, quat_y: f32, quat_z: f32) -> Self {
+        Self {
+            quat_w,
+            quat_x,
+            quat_y,
+            quat_z,
+        }
+    }
+
+    /// Rebuild the unit quaternion from the SFLP game rotation vector, i.e.
+    /// the x, y, z components in half-precision float format.
+    ///
+    /// The vector is normalized if its norm exceeds 1, and w is recovered as
+    /// the non-negative root of 1 - x² - y² - z².
+    pub fn from_sflp_half(xyz: [u16; 3]) -> Self {
+        let [mut x, mut y, mut z] = xyz.map(super::npy_half_to_float);
+
+        let mut sumsq = x * x + y * y + z * z;
+        if sumsq > 1.0 {
+            let norm = libm::sqrtf(sumsq);
+            x /= norm;
+            y /= norm;
+            z /= norm;
+            sumsq = 1.0;
+        }
+
+        Self::new(libm::sqrtf(1.0 - sumsq), x, y, z)
+    }
+
+    /// Quaternion of the null rotation.
+    pub const fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Get the norm.
+    pub fn norm(&self) -> f32 {
+        libm::sqrtf(
+            self.quat_w * self.quat_w
+                + self.quat_x * self.quat_x
+                + self.quat_y * self.quat_y
+                + self.quat_z * self.quat_z,
+        )
+    }
+
+    /// Get the quaternion scaled to unit norm; the identity if the norm is 0.
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        if norm == 0.0 {
+            return Self::identity();
+        }
+
+        Self::new(
+            self.quat_w / norm,
+            self.quat_x / norm,
+            self.quat_y / norm,
+            self.quat_z / norm,
+        )
+    }
+
+    /// Get the conjugate, i.e. the inverse rotation for a unit quaternion.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.quat_w, -self.quat_x, -self.quat_y, -self.quat_z)
+    }
+
+    /// Get the rotation matrix of the quaternion, normalized first.
+    ///
+    /// The matrix rotates vectors from the sensor frame to the world frame.
+    pub fn to_rotation_matrix(&self) -> RotationMatrix {
+        let Quaternion {
+            quat_w: w,
+            quat_x: x,
+            quat_y: y,
+            quat_z: z,
+        } = self.normalize();
+
+        RotationMatrix([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ])
+    }
+
+    /// Get the Euler angles of the quaternion, in the rotation order `val`.
+    pub fn to_euler(&self, val: EulerConvention) -> EulerAngles {
+        let m = self.to_rotation_matrix().0;
+
+        match val {
+            EulerConvention::Zyx => EulerAngles {
+                roll: libm::atan2f(m[2][1], m[2][2]),
+                pitch: libm::asinf((-m[2][0]).clamp(-1.0, 1.0)),
+                yaw: libm::atan2f(m[1][0], m[0][0]),
+            },
+            EulerConvention::Xyz => EulerAngles {
+                roll: libm::atan2f(-m[1][2], m[2][2]),
+                pitch: libm::asinf(m[0][2].clamp(-1.0, 1.0)),
+                yaw: libm::atan2f(-m[0][1], m[0][0]),
+            },
+        }
+    }
+
+    /// Rotate the vector `v` from the sensor frame to the world frame.
+    pub fn rotate(&self, v: [f32; 3]) -> [f32; 3] {
+        self.to_rotation_matrix().apply(v)
+    }
+}
+
+impl Mul for &Quaternion {
+    type Output = Quaternion;
+
+    /// Hamilton product: the rotation `rhs` followed by `self`.
+    fn mul(self, rhs: &Quaternion) -> Quaternion {
+        let (w1, x1, y1, z1) = (self.quat_w, self.quat_x, self.quat_y, self.quat_z);
+        let (w2, x2, y2, z2) = (rhs.quat_w, rhs.quat_x, rhs.quat_y, rhs.quat_z);
+
+        Quaternion::new(
+            w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+            w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+            w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+            w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+        )
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        &self * &rhs
+    }
+}
+
+#[bisync]
+impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
+    /// Get the SFLP gravity vector in mg.
+    pub async fn sflp_gravity_mg_get(
+        &mut self,
+    ) -> Result<Vector3<Acceleration<MilliG>>, Error<B::Error>> {
+        let raw = self.sflp_gravity_raw_get().await?;
+
+        Ok(Vector3::from(raw).map(Acceleration::from_gravity_lsb))
+    }
+
+    /// Get the linear acceleration in mg, i.e. the accelerometer output
+    /// without the SFLP gravity vector.
+    ///
+    /// With `ReferenceFrame::World` the result is rotated with the SFLP game
+    /// rotation vector. The SFLP game rotation must be enabled.
+    pub async fn linear_acceleration_get(
+        &mut self,
+        frame: ReferenceFrame,
+    ) -> Result<Vector3<Acceleration<MilliG>>, Error<B::Error>> {
+        let acc = self.acceleration_mg_get().await?;
+        let gravity = self.sflp_gravity_mg_get().await?;
+
+        let mut linear = [
+            acc.x.value() - gravity.x.value(),
+            acc.y.value() - gravity.y.value(),
+            acc.z.value() - gravity.z.value(),
+        ];
+        if frame == ReferenceFrame::World {
+            linear = self.sflp_quaternion_get().await?.rotate(linear);
+        }
+
+        Ok(Vector3::from(linear).map(Acceleration::new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_2};
+
+    use super::*;
+
+    const EPS: f32 = 1e-6;
+
+    fn about_x(deg: f32) -> Quaternion {
+        let half = deg.to_radians() / 2.0;
+        Quaternion::new(libm::cosf(half), libm::sinf(half), 0.0, 0.0)
+    }
+
+    fn about_y(deg: f32) -> Quaternion {
+        let half = deg.to_radians() / 2.0;
+        Quaternion::new(libm::cosf(half), 0.0, libm::sinf(half), 0.0)
+    }
+
+    fn about_z(deg: f32) -> Quaternion {
+        let half = deg.to_radians() / 2.0;
+        Quaternion::new(libm::cosf(half), 0.0, 0.0, libm::sinf(half))
+    }
+
+    fn assert_close(val: f32, expected: f32) {
+        assert!((val - expected).abs() <= EPS, "{val} != {expected}");
+    }
+
+    fn assert_quaternion(q: Quaternion, expected: Quaternion) {
+        assert_close(q.quat_w, expected.quat_w);
+        assert_close(q.quat_x, expected.quat_x);
+        assert_close(q.quat_y, expected.quat_y);
+        assert_close(q.quat_z, expected.quat_z);
+    }
+
+    fn assert_matrix(m: RotationMatrix, expected: [[f32; 3]; 3]) {
+        for (row, expected) in m.0.iter().zip(&expected) {
+            for (&val, &expected) in row.iter().zip(expected) {
+                assert_close(val, expected);
+            }
+        }
+    }
+
+    fn assert_euler(angles: EulerAngles, roll: f32, pitch: f32, yaw: f32) {
+        assert_close(angles.roll, roll);
+        assert_close(angles.pitch, pitch);
+        assert_close(angles.yaw, yaw);
+    }
+
+    #[test]
+    fn identity() {
+        let q = Quaternion::identity();
+        let m = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+        assert_matrix(q.to_rotation_matrix(), m);
+        assert_euler(q.to_euler(EulerConvention::Zyx), 0.0, 0.0, 0.0);
+        assert_euler(q.to_euler(EulerConvention::Xyz), 0.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn quarter_turn_about_x() {
+        let q = Quaternion::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0, 0.0);
+        let m = [[1.0, 0.0, 0.0], [0.0, 0.0, -1.0], [0.0, 1.0, 0.0]];
+
+        assert_matrix(q.to_rotation_matrix(), m);
+        assert_euler(q.to_euler(EulerConvention::Zyx), FRAC_PI_2, 0.0, 0.0);
+        assert_euler(q.to_euler(EulerConvention::Xyz), FRAC_PI_2, 0.0, 0.0);
+    }
+
+    #[test]
+    fn quarter_turn_about_y() {
+        let q = Quaternion::new(FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2, 0.0);
+        let m = [[0.0, 0.0, 1.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 0.0]];
+
+        assert_matrix(q.to_rotation_matrix(), m);
+        /* gimbal lock: only roll - yaw is defined, and it is 0 */
+        for val in [EulerConvention::Zyx, EulerConvention::Xyz] {
+            let angles = q.to_euler(val);
+            assert_close(angles.pitch, FRAC_PI_2);
+            assert_close(libm::sinf(angles.roll - angles.yaw), 0.0);
+            assert_close(libm::cosf(angles.roll - angles.yaw), 1.0);
+        }
+    }
+
+    #[test]
+    fn quarter_turn_about_z() {
+        let q = Quaternion::new(FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2);
+        let m = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+
+        assert_matrix(q.to_rotation_matrix(), m);
+        assert_euler(q.to_euler(EulerConvention::Zyx), 0.0, 0.0, FRAC_PI_2);
+        assert_euler(q.to_euler(EulerConvention::Xyz), 0.0, 0.0, FRAC_PI_2);
+    }
+
+    #[test]
+    fn euler_conventions() {
+        let (roll, pitch, yaw) = (10f32, 20f32, 30f32);
+        let expected = |angles: EulerAngles| {
+            assert_euler(
+                angles,
+                roll.to_radians(),
+                pitch.to_radians(),
+                yaw.to_radians(),
+            )
+        };
+
+        /* intrinsic z-y'-x'': yaw first */
+        let q = about_z(yaw) * about_y(pitch) * about_x(roll);
+        expected(q.to_euler(EulerConvention::Zyx));
+
+        /* intrinsic x-y'-z'': roll first */
+        let q = about_x(roll) * about_y(pitch) * about_z(yaw);
+        expected(q.to_euler(EulerConvention::Xyz));
+    }
+
+    #[test]
+    fn mul_and_conjugate() {
+        let q = Quaternion::new(0.5, -0.1, 0.7, 0.3).normalize();
+
+        assert_quaternion(&q * &q.conjugate(), Quaternion::identity());
+        assert_quaternion(&q.conjugate() * &q, Quaternion::identity());
+        assert_eq!(
+            q.conjugate(),
+            Quaternion::new(q.quat_w, -q.quat_x, -q.quat_y, -q.quat_z)
+        );
+        assert_matrix(
+            q.conjugate().to_rotation_matrix(),
+            q.to_rotation_matrix().transpose().0,
+        );
+
+        /* two quarter turns about x: half turn */
+        assert_quaternion(about_x(90.0) * about_x(90.0), about_x(180.0));
+        /* rhs first: x is kept by the turn about x, then turned to y */
+        let v = (about_z(90.0) * about_x(90.0)).rotate([1.0, 0.0, 0.0]);
+        for (val, expected) in v.into_iter().zip([0.0, 1.0, 0.0]) {
+            assert_close(val, expected);
+        }
+    }
+
+    #[test]
+    fn from_sflp_half() {
+        /* x = 0.5, y = -0.5, z = 0.5 in half-precision float */
+        let q = Quaternion::from_sflp_half([0x3800, 0xB800, 0x3800]);
+        assert_eq!(q, Quaternion::new(0.5, 0.5, -0.5, 0.5));
+
+        /* x = 1.0, y = 1.0: above the unit norm, normalized and w = 0 */
+        let q = Quaternion::from_sflp_half([0x3C00, 0x3C00, 0x0000]);
+        assert_close(q.norm(), 1.0);
+        assert_quaternion(q, Quaternion::new(0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0));
+
+        /* x = -0.25, y = 0, z = 0 */
+        let q = Quaternion::from_sflp_half([0xB400, 0x0000, 0x0000]);
+        assert_quaternion(q, Quaternion::new(libm::sqrtf(0.9375), -0.25, 0.0, 0.0));
+
+        assert_eq!(Quaternion::from_sflp_half([0; 3]), Quaternion::identity());
+    }
+}
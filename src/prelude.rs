@@ -1,4 +1,15 @@
+pub use super::events::*;
+pub use super::fifo::*;
+pub use super::fifo_plan::*;
+pub use super::fifo_reader::*;
+pub use super::fsm::*;
+pub use super::impact::*;
+pub use super::orientation::*;
+pub use super::reg_config::*;
 pub use super::register;
+pub use super::routing::*;
+pub use super::sflp::*;
+pub use super::shock::*;
 
 pub use register::advanced::*;
 pub use register::embedded::*;
@@ -6,3 +17,5 @@ pub use register::if2::*;
 pub use register::main::*;
 pub use register::sensor_hub::*;
 pub use register::*;
+
+pub use super::units::*;
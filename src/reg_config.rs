@@ -0,0 +1,127 @@
+use super::prelude::*;
+
+/// Operation of a register configuration program.
+///
+/// A program is a sequence of operations, as exported in the ST JSON
+/// `reg_config` (UCF) format by MEMS Studio or the configuration tools.
+/// Programs can be stored as constant slices, so FSM and MLC configurations
+/// can be embedded in the firmware without any build-script step:
+///
+/// ```rust,ignore
+/// const PROGRAM: &[RegConfigOp] = &[
+///     RegConfigOp::write(0x10, 0x00),
+///     RegConfigOp::write(0x01, 0x80),
+///     RegConfigOp::delay(5),
+/// ];
+///
+/// sensor.load_reg_config(PROGRAM).unwrap();
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RegConfigOp {
+    /// Write `data` to the register at `address` of the selected memory bank.
+    Write { address: u8, data: u8 },
+    /// Wait for `ms` milliseconds.
+    Delay { ms: u32 },
+    /// Select the memory bank used by the following `Write` operations.
+    MemBank(MemBank),
+    /// Write `data` at `address` (page << 8 | offset) of the advanced pages.
+    PageWrite { address: u16, data: u8 },
+}
+
+impl RegConfigOp {
+    /// Create a register write operation.
+    pub const fn write(address: u8, data: u8) -> Self {
+        Self::Write { address, data }
+    }
+
+    /// Create a delay operation.
+    pub const fn delay(ms: u32) -> Self {
+        Self::Delay { ms }
+    }
+
+    /// Create a memory bank selection operation.
+    pub const fn mem_bank(bank: MemBank) -> Self {
+        Self::MemBank(bank)
+    }
+
+    /// Create an advanced page write operation.
+    pub const fn page_write(address: u16, data: u8) -> Self {
+        Self::PageWrite { address, data }
+    }
+}
+
+/// Get the memory bank selected by a FUNC_CFG_ACCESS value.
+pub(crate) fn reg_config_mem_bank(func_cfg_access: u8) -> MemBank {
+    let func_cfg_access = FuncCfgAccess::from_bits(func_cfg_access);
+    if func_cfg_access.emb_func_reg_access() == 1 {
+        MemBank::EmbedFuncMemBank
+    } else if func_cfg_access.shub_reg_access() == 1 {
+        MemBank::SensorHubMemBank
+    } else {
+        MemBank::MainMemBank
+    }
+}
+
+/* the example tables hold the raw register bytes of the device (LSB first) */
+#[cfg(all(test, not(feature = "bit_order_msb")))]
+mod tests {
+    use super::super::Lsm6dsv320x;
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::*;
+
+    mod fsm_fourd {
+        include!("../bsp/src/config/fsm_fourd.rs");
+    }
+
+    #[test]
+    fn load_fsm_fourd() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+        block_on(sensor.load_reg_config(fsm_fourd::FOUR_D)).unwrap();
+        let sim = &sensor.bus;
+
+        // Main bank is selected at the end of the program
+        assert_eq!(
+            sim.reg_get(MemBank::MainMemBank, Reg::FuncCfgAccess as u8),
+            0x00
+        );
+        assert_eq!(sim.reg_get(MemBank::MainMemBank, Reg::Ctrl1 as u8), 0x44);
+        assert_eq!(sim.reg_get(MemBank::MainMemBank, Reg::Ctrl2 as u8), 0x00);
+        assert_eq!(sim.reg_get(MemBank::MainMemBank, Reg::Md1Cfg as u8), 0x02);
+        assert_eq!(sim.reg_get(MemBank::MainMemBank, Reg::Ctrl8 as u8), 0x03);
+
+        // FSM enable and configuration in the embedded functions bank
+        let embedded = [
+            (EmbReg::EmbFuncEnA, 0x00),
+            (EmbReg::EmbFuncEnB, 0x01),
+            (EmbReg::FsmEnable, 0x01),
+            (EmbReg::FsmInt1, 0x01),
+            (EmbReg::FsmOdr, 0x4B),
+            (EmbReg::PageSel, 0x41),
+            (EmbReg::PageRw, 0x00),
+        ];
+        for (reg, val) in embedded {
+            let reg = reg as u8;
+            assert_eq!(
+                sim.reg_get(MemBank::EmbedFuncMemBank, reg),
+                val,
+                "{reg:#04x}"
+            );
+        }
+
+        // FSM_LC_TIMEOUT, FSM_PROGRAMS and FSM_START_ADD in page 1
+        let page1 = [0x00, 0x00, 0x01, 0x01, 0x00, 0x04];
+        for (n, val) in page1.into_iter().enumerate() {
+            assert_eq!(sim.page_get(0x17A + n as u16), val);
+        }
+
+        // FSM program in page 4
+        let program = [
+            0x91, 0x10, 0x16, 0x00, 0x0F, 0x00, 0x66, 0x3A, 0x66, 0x32, 0xF0, 0x00, 0x00, 0x0F,
+            0x00, 0xEF, 0x33, 0x05, 0x73, 0x99, 0x08, 0x22,
+        ];
+        for (n, val) in program.into_iter().enumerate() {
+            assert_eq!(sim.page_get(0x400 + n as u16), val);
+        }
+        assert_eq!(sim.page_get(0x400 + program.len() as u16), 0x00);
+    }
+}
@@ -1209,6 +1209,7 @@ pub struct EmbFuncConv {
 }
 
 #[derive(Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FifoSflpRaw {
     pub game_rotation: u8,
     pub gravity: u8,
@@ -1227,6 +1228,50 @@ pub struct FsmMode {
     pub fsm8_en: u8,
 }
 
+flag_set! {
+    /// Set of Finite State Machines.
+    pub struct FsmSet(u8) {
+        const FSM1 = 0;
+        const FSM2 = 1;
+        const FSM3 = 2;
+        const FSM4 = 3;
+        const FSM5 = 4;
+        const FSM6 = 5;
+        const FSM7 = 6;
+        const FSM8 = 7;
+    }
+}
+
+impl From<&FsmMode> for FsmSet {
+    fn from(val: &FsmMode) -> Self {
+        let mut flags = FsmSet::empty();
+        flags.set(FsmSet::FSM1, val.fsm1_en == 1);
+        flags.set(FsmSet::FSM2, val.fsm2_en == 1);
+        flags.set(FsmSet::FSM3, val.fsm3_en == 1);
+        flags.set(FsmSet::FSM4, val.fsm4_en == 1);
+        flags.set(FsmSet::FSM5, val.fsm5_en == 1);
+        flags.set(FsmSet::FSM6, val.fsm6_en == 1);
+        flags.set(FsmSet::FSM7, val.fsm7_en == 1);
+        flags.set(FsmSet::FSM8, val.fsm8_en == 1);
+        flags
+    }
+}
+
+impl From<FsmSet> for FsmMode {
+    fn from(val: FsmSet) -> Self {
+        FsmMode {
+            fsm1_en: val.bit(FsmSet::FSM1),
+            fsm2_en: val.bit(FsmSet::FSM2),
+            fsm3_en: val.bit(FsmSet::FSM3),
+            fsm4_en: val.bit(FsmSet::FSM4),
+            fsm5_en: val.bit(FsmSet::FSM5),
+            fsm6_en: val.bit(FsmSet::FSM6),
+            fsm7_en: val.bit(FsmSet::FSM7),
+            fsm8_en: val.bit(FsmSet::FSM8),
+        }
+    }
+}
+
 #[derive(Default, MultiRegister, Debug, Clone, PartialEq)]
 pub struct FsmOut {
     pub fsm_outs1: u8,
@@ -1245,6 +1290,7 @@ pub struct StpcntMode {
     pub false_step_rej: u8,
 }
 
+/// SFLP gyroscope bias in dps.
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SflpGbias {
     pub gbias_x: f32,
@@ -1339,3 +1385,17 @@ pub enum SflpDataRate {
     /// 480 Hz output data rate.
     _480hz = 0x5,
 }
+
+impl SflpDataRate {
+    /// Get the data rate in Hz.
+    pub fn to_hz(self) -> f32 {
+        match self {
+            SflpDataRate::_15hz => 15.0,
+            SflpDataRate::_30hz => 30.0,
+            SflpDataRate::_60hz => 60.0,
+            SflpDataRate::_120hz => 120.0,
+            SflpDataRate::_240hz => 240.0,
+            SflpDataRate::_480hz => 480.0,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::{
     BusOperation, DelayNs, Error, Lsm6dsv320x, RegisterOperation, SensorOperation, bisync,
-    register::{BankState, MainBank},
+    events::Axes,
+    register::{BankState, MainBank, embedded::FifoSflpRaw},
 };
 
 use bitfield_struct::bitfield;
@@ -1888,18 +1889,77 @@ pub struct HgWakeUpCfg {
     pub hg_shock_dur: u8,
 }
 
+/// High-g events status, see `hg_event_status_get`.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct HgEventStatus {
+    /// High-g interrupt event.
+    pub event: bool,
+    /// Wake-up event.
+    pub wake_up: bool,
+    /// Axes involved in the wake-up event.
+    pub wake_up_axes: Axes,
+    /// Wake-up change event.
+    pub wake_up_change: bool,
+    /// Shock function state.
+    pub shock: bool,
+    /// Shock change event.
+    pub shock_change: bool,
+}
+
+impl From<HgEventStatus> for HgEvent {
+    fn from(val: HgEventStatus) -> Self {
+        HgEvent {
+            hg_event: val.event as u8,
+            hg_wakeup_z: val.wake_up_axes.z as u8,
+            hg_wakeup_y: val.wake_up_axes.y as u8,
+            hg_wakeup_x: val.wake_up_axes.x as u8,
+            hg_wakeup: val.wake_up as u8,
+            hg_wakeup_chg: val.wake_up_change as u8,
+            hg_shock: val.shock as u8,
+            hg_shock_change: val.shock_change as u8,
+        }
+    }
+}
+
 #[derive(Default, Debug, PartialEq, Clone)]
 pub struct HgWuInterruptCfg {
     pub hg_interrupts_enable: u8,
     pub hg_wakeup_int_sel: u8,
 }
 
+/// High-g interrupt generation configuration.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct HgInterruptConfig {
+    /// High-g interrupts enabled.
+    pub enable: bool,
+    /// Wake-up change (true) or wake-up (false) event sent to the pads.
+    pub wake_up_change: bool,
+}
+
+impl From<&HgWuInterruptCfg> for HgInterruptConfig {
+    fn from(val: &HgWuInterruptCfg) -> Self {
+        HgInterruptConfig {
+            enable: val.hg_interrupts_enable & 0x01 == 1,
+            wake_up_change: val.hg_wakeup_int_sel & 0x01 == 1,
+        }
+    }
+}
+
 #[derive(Default, Debug, PartialEq, Clone)]
 pub struct InterruptMode {
     pub enable: u8,
     pub lir: u8,
 }
 
+/// Interrupt generation configuration.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct InterruptConfig {
+    /// Interrupts enabled.
+    pub enable: bool,
+    /// Latched (true) or pulsed (false) interrupts.
+    pub latched: bool,
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PinInt1Route {
     pub drdy_xl: u8,
@@ -1917,6 +1977,65 @@ pub struct PinInt1Route {
     pub sleep_change: u8,
 }
 
+flag_set! {
+    /// Signals routed on the INT1 pad.
+    pub struct Int1Route(u16) {
+        const DRDY_XL = 0;
+        const DRDY_G = 1;
+        const FIFO_TH = 2;
+        const FIFO_OVR = 3;
+        const FIFO_FULL = 4;
+        const CNT_BDR = 5;
+        const SHUB = 6;
+        const SIXD = 7;
+        const SINGLE_TAP = 8;
+        const DOUBLE_TAP = 9;
+        const WAKEUP = 10;
+        const FREEFALL = 11;
+        const SLEEP_CHANGE = 12;
+    }
+}
+
+impl From<&PinInt1Route> for Int1Route {
+    fn from(val: &PinInt1Route) -> Self {
+        let mut flags = Int1Route::empty();
+        flags.set(Int1Route::DRDY_XL, val.drdy_xl == 1);
+        flags.set(Int1Route::DRDY_G, val.drdy_g == 1);
+        flags.set(Int1Route::FIFO_TH, val.fifo_th == 1);
+        flags.set(Int1Route::FIFO_OVR, val.fifo_ovr == 1);
+        flags.set(Int1Route::FIFO_FULL, val.fifo_full == 1);
+        flags.set(Int1Route::CNT_BDR, val.cnt_bdr == 1);
+        flags.set(Int1Route::SHUB, val.shub == 1);
+        flags.set(Int1Route::SIXD, val.sixd == 1);
+        flags.set(Int1Route::SINGLE_TAP, val.single_tap == 1);
+        flags.set(Int1Route::DOUBLE_TAP, val.double_tap == 1);
+        flags.set(Int1Route::WAKEUP, val.wakeup == 1);
+        flags.set(Int1Route::FREEFALL, val.freefall == 1);
+        flags.set(Int1Route::SLEEP_CHANGE, val.sleep_change == 1);
+        flags
+    }
+}
+
+impl From<Int1Route> for PinInt1Route {
+    fn from(val: Int1Route) -> Self {
+        PinInt1Route {
+            drdy_xl: val.bit(Int1Route::DRDY_XL),
+            drdy_g: val.bit(Int1Route::DRDY_G),
+            fifo_th: val.bit(Int1Route::FIFO_TH),
+            fifo_ovr: val.bit(Int1Route::FIFO_OVR),
+            fifo_full: val.bit(Int1Route::FIFO_FULL),
+            cnt_bdr: val.bit(Int1Route::CNT_BDR),
+            shub: val.bit(Int1Route::SHUB),
+            sixd: val.bit(Int1Route::SIXD),
+            single_tap: val.bit(Int1Route::SINGLE_TAP),
+            double_tap: val.bit(Int1Route::DOUBLE_TAP),
+            wakeup: val.bit(Int1Route::WAKEUP),
+            freefall: val.bit(Int1Route::FREEFALL),
+            sleep_change: val.bit(Int1Route::SLEEP_CHANGE),
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PinInt2Route {
     pub drdy_xl: u8,
@@ -1937,6 +2056,74 @@ pub struct PinInt2Route {
     pub emb_func_endop: u8,
 }
 
+flag_set! {
+    /// Signals routed on the INT2 pad.
+    pub struct Int2Route(u16) {
+        const DRDY_XL = 0;
+        const DRDY_G = 1;
+        const DRDY_G_EIS = 2;
+        const DRDY_TEMP = 3;
+        const FIFO_TH = 4;
+        const FIFO_OVR = 5;
+        const FIFO_FULL = 6;
+        const CNT_BDR = 7;
+        const TIMESTAMP = 8;
+        const SIXD = 9;
+        const SINGLE_TAP = 10;
+        const DOUBLE_TAP = 11;
+        const WAKEUP = 12;
+        const FREEFALL = 13;
+        const SLEEP_CHANGE = 14;
+        const EMB_FUNC_ENDOP = 15;
+    }
+}
+
+impl From<&PinInt2Route> for Int2Route {
+    fn from(val: &PinInt2Route) -> Self {
+        let mut flags = Int2Route::empty();
+        flags.set(Int2Route::DRDY_XL, val.drdy_xl == 1);
+        flags.set(Int2Route::DRDY_G, val.drdy_g == 1);
+        flags.set(Int2Route::DRDY_G_EIS, val.drdy_g_eis == 1);
+        flags.set(Int2Route::DRDY_TEMP, val.drdy_temp == 1);
+        flags.set(Int2Route::FIFO_TH, val.fifo_th == 1);
+        flags.set(Int2Route::FIFO_OVR, val.fifo_ovr == 1);
+        flags.set(Int2Route::FIFO_FULL, val.fifo_full == 1);
+        flags.set(Int2Route::CNT_BDR, val.cnt_bdr == 1);
+        flags.set(Int2Route::TIMESTAMP, val.timestamp == 1);
+        flags.set(Int2Route::SIXD, val.sixd == 1);
+        flags.set(Int2Route::SINGLE_TAP, val.single_tap == 1);
+        flags.set(Int2Route::DOUBLE_TAP, val.double_tap == 1);
+        flags.set(Int2Route::WAKEUP, val.wakeup == 1);
+        flags.set(Int2Route::FREEFALL, val.freefall == 1);
+        flags.set(Int2Route::SLEEP_CHANGE, val.sleep_change == 1);
+        flags.set(Int2Route::EMB_FUNC_ENDOP, val.emb_func_endop == 1);
+        flags
+    }
+}
+
+impl From<Int2Route> for PinInt2Route {
+    fn from(val: Int2Route) -> Self {
+        PinInt2Route {
+            drdy_xl: val.bit(Int2Route::DRDY_XL),
+            drdy_g: val.bit(Int2Route::DRDY_G),
+            drdy_g_eis: val.bit(Int2Route::DRDY_G_EIS),
+            drdy_temp: val.bit(Int2Route::DRDY_TEMP),
+            fifo_th: val.bit(Int2Route::FIFO_TH),
+            fifo_ovr: val.bit(Int2Route::FIFO_OVR),
+            fifo_full: val.bit(Int2Route::FIFO_FULL),
+            cnt_bdr: val.bit(Int2Route::CNT_BDR),
+            timestamp: val.bit(Int2Route::TIMESTAMP),
+            sixd: val.bit(Int2Route::SIXD),
+            single_tap: val.bit(Int2Route::SINGLE_TAP),
+            double_tap: val.bit(Int2Route::DOUBLE_TAP),
+            wakeup: val.bit(Int2Route::WAKEUP),
+            freefall: val.bit(Int2Route::FREEFALL),
+            sleep_change: val.bit(Int2Route::SLEEP_CHANGE),
+            emb_func_endop: val.bit(Int2Route::EMB_FUNC_ENDOP),
+        }
+    }
+}
+
 #[derive(Default, Debug, PartialEq)]
 pub struct PinIntRouteHg {
     pub drdy_hg_xl: u8,
@@ -1944,6 +2131,35 @@ pub struct PinIntRouteHg {
     pub hg_shock_change: u8,
 }
 
+flag_set! {
+    /// High-g signals routed on an interrupt pad.
+    pub struct IntRouteHg(u8) {
+        const DRDY_HG_XL = 0;
+        const HG_WAKEUP = 1;
+        const HG_SHOCK_CHANGE = 2;
+    }
+}
+
+impl From<&PinIntRouteHg> for IntRouteHg {
+    fn from(val: &PinIntRouteHg) -> Self {
+        let mut flags = IntRouteHg::empty();
+        flags.set(IntRouteHg::DRDY_HG_XL, val.drdy_hg_xl == 1);
+        flags.set(IntRouteHg::HG_WAKEUP, val.hg_wakeup == 1);
+        flags.set(IntRouteHg::HG_SHOCK_CHANGE, val.hg_shock_change == 1);
+        flags
+    }
+}
+
+impl From<IntRouteHg> for PinIntRouteHg {
+    fn from(val: IntRouteHg) -> Self {
+        PinIntRouteHg {
+            drdy_hg_xl: val.bit(IntRouteHg::DRDY_HG_XL),
+            hg_wakeup: val.bit(IntRouteHg::HG_WAKEUP),
+            hg_shock_change: val.bit(IntRouteHg::HG_SHOCK_CHANGE),
+        }
+    }
+}
+
 #[derive(Default, Debug, PartialEq)]
 pub struct PinIntRouteEmb {
     pub step_detector: u8,
@@ -1967,6 +2183,83 @@ pub struct PinIntRouteEmb {
     pub mlc8: u8,
 }
 
+flag_set! {
+    /// Embedded functions signals routed on an interrupt pad.
+    pub struct IntRouteEmb(u32) {
+        const STEP_DETECTOR = 0;
+        const TILT = 1;
+        const SIG_MOT = 2;
+        const FSM1 = 3;
+        const FSM2 = 4;
+        const FSM3 = 5;
+        const FSM4 = 6;
+        const FSM5 = 7;
+        const FSM6 = 8;
+        const FSM7 = 9;
+        const FSM8 = 10;
+        const MLC1 = 11;
+        const MLC2 = 12;
+        const MLC3 = 13;
+        const MLC4 = 14;
+        const MLC5 = 15;
+        const MLC6 = 16;
+        const MLC7 = 17;
+        const MLC8 = 18;
+    }
+}
+
+impl From<&PinIntRouteEmb> for IntRouteEmb {
+    fn from(val: &PinIntRouteEmb) -> Self {
+        let mut flags = IntRouteEmb::empty();
+        flags.set(IntRouteEmb::STEP_DETECTOR, val.step_detector == 1);
+        flags.set(IntRouteEmb::TILT, val.tilt == 1);
+        flags.set(IntRouteEmb::SIG_MOT, val.sig_mot == 1);
+        flags.set(IntRouteEmb::FSM1, val.fsm1 == 1);
+        flags.set(IntRouteEmb::FSM2, val.fsm2 == 1);
+        flags.set(IntRouteEmb::FSM3, val.fsm3 == 1);
+        flags.set(IntRouteEmb::FSM4, val.fsm4 == 1);
+        flags.set(IntRouteEmb::FSM5, val.fsm5 == 1);
+        flags.set(IntRouteEmb::FSM6, val.fsm6 == 1);
+        flags.set(IntRouteEmb::FSM7, val.fsm7 == 1);
+        flags.set(IntRouteEmb::FSM8, val.fsm8 == 1);
+        flags.set(IntRouteEmb::MLC1, val.mlc1 == 1);
+        flags.set(IntRouteEmb::MLC2, val.mlc2 == 1);
+        flags.set(IntRouteEmb::MLC3, val.mlc3 == 1);
+        flags.set(IntRouteEmb::MLC4, val.mlc4 == 1);
+        flags.set(IntRouteEmb::MLC5, val.mlc5 == 1);
+        flags.set(IntRouteEmb::MLC6, val.mlc6 == 1);
+        flags.set(IntRouteEmb::MLC7, val.mlc7 == 1);
+        flags.set(IntRouteEmb::MLC8, val.mlc8 == 1);
+        flags
+    }
+}
+
+impl From<IntRouteEmb> for PinIntRouteEmb {
+    fn from(val: IntRouteEmb) -> Self {
+        PinIntRouteEmb {
+            step_detector: val.bit(IntRouteEmb::STEP_DETECTOR),
+            tilt: val.bit(IntRouteEmb::TILT),
+            sig_mot: val.bit(IntRouteEmb::SIG_MOT),
+            fsm1: val.bit(IntRouteEmb::FSM1),
+            fsm2: val.bit(IntRouteEmb::FSM2),
+            fsm3: val.bit(IntRouteEmb::FSM3),
+            fsm4: val.bit(IntRouteEmb::FSM4),
+            fsm5: val.bit(IntRouteEmb::FSM5),
+            fsm6: val.bit(IntRouteEmb::FSM6),
+            fsm7: val.bit(IntRouteEmb::FSM7),
+            fsm8: val.bit(IntRouteEmb::FSM8),
+            mlc1: val.bit(IntRouteEmb::MLC1),
+            mlc2: val.bit(IntRouteEmb::MLC2),
+            mlc3: val.bit(IntRouteEmb::MLC3),
+            mlc4: val.bit(IntRouteEmb::MLC4),
+            mlc5: val.bit(IntRouteEmb::MLC5),
+            mlc6: val.bit(IntRouteEmb::MLC6),
+            mlc7: val.bit(IntRouteEmb::MLC7),
+            mlc8: val.bit(IntRouteEmb::MLC8),
+        }
+    }
+}
+
 #[derive(Default, Debug, PartialEq)]
 pub struct DataReady {
     pub drdy_hgxl: u8,
@@ -1975,6 +2268,38 @@ pub struct DataReady {
     pub drdy_temp: u8,
 }
 
+flag_set! {
+    /// Data-ready flags.
+    pub struct DataReadyFlags(u8) {
+        const DRDY_HGXL = 0;
+        const DRDY_XL = 1;
+        const DRDY_GY = 2;
+        const DRDY_TEMP = 3;
+    }
+}
+
+impl From<&DataReady> for DataReadyFlags {
+    fn from(val: &DataReady) -> Self {
+        let mut flags = DataReadyFlags::empty();
+        flags.set(DataReadyFlags::DRDY_HGXL, val.drdy_hgxl == 1);
+        flags.set(DataReadyFlags::DRDY_XL, val.drdy_xl == 1);
+        flags.set(DataReadyFlags::DRDY_GY, val.drdy_gy == 1);
+        flags.set(DataReadyFlags::DRDY_TEMP, val.drdy_temp == 1);
+        flags
+    }
+}
+
+impl From<DataReadyFlags> for DataReady {
+    fn from(val: DataReadyFlags) -> Self {
+        DataReady {
+            drdy_hgxl: val.bit(DataReadyFlags::DRDY_HGXL),
+            drdy_xl: val.bit(DataReadyFlags::DRDY_XL),
+            drdy_gy: val.bit(DataReadyFlags::DRDY_GY),
+            drdy_temp: val.bit(DataReadyFlags::DRDY_TEMP),
+        }
+    }
+}
+
 #[derive(Default, Debug, PartialEq)]
 pub struct FifoStatus {
     // Number of element stored in the fifo
@@ -1989,6 +2314,21 @@ pub struct FifoStatus {
     pub fifo_th: u8,
 }
 
+/// FIFO level and interrupt flags.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct FifoState {
+    /// Number of words stored in the FIFO.
+    pub level: u16,
+    /// Counter batch data rate (BDR) threshold reached.
+    pub bdr: bool,
+    /// FIFO full.
+    pub full: bool,
+    /// FIFO overrun.
+    pub overrun: bool,
+    /// FIFO watermark reached.
+    pub watermark: bool,
+}
+
 #[derive(Default, Debug, PartialEq, Clone)]
 pub struct FiltSettlingMask {
     pub drdy: u8,
@@ -2053,6 +2393,167 @@ pub struct FifoOutRaw {
     pub data: [u8; 6],
 }
 
+/// Snapshot of the device configuration.
+///
+/// Use `device_config_get` to read it from the device and `device_config_set`
+/// to program it; `diff` reports the fields that differ between two snapshots.
+///
+/// The snapshot covers the sensors setup, the FIFO batching (including SFLP
+/// and step counter) and the interrupt routing (including high-g and embedded
+/// functions events). The embedded functions themselves (enable, thresholds,
+/// FSM/MLC programs) and the sensor hub are not part of it.
+#[derive(Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceConfig {
+    pub xl_odr: DataRate,
+    pub xl_mode: XlMode,
+    pub xl_full_scale: XlFullScale,
+    pub gy_odr: DataRate,
+    pub gy_mode: GyMode,
+    pub gy_full_scale: GyFullScale,
+    pub hg_xl_odr: HgXlDataRate,
+    pub hg_xl_reg_out_en: bool,
+    pub hg_xl_full_scale: HgXlFullScale,
+    pub block_data_update: bool,
+    pub timestamp: bool,
+    pub filt_gy_lp1: bool,
+    pub filt_gy_lp1_bandwidth: FiltLpBandwidth,
+    pub filt_xl_lp2: bool,
+    pub filt_xl_lp2_bandwidth: FiltLpBandwidth,
+    pub fifo_mode: FifoMode,
+    pub fifo_watermark: u8,
+    pub fifo_compress_algo: FifoCompressAlgo,
+    pub fifo_xl_batch: FifoBatch,
+    pub fifo_gy_batch: FifoBatch,
+    pub fifo_hg_xl_batch: bool,
+    pub fifo_temp_batch: FifoTempBatch,
+    pub fifo_timestamp_batch: FifoTimestampBatch,
+    pub fifo_sflp_batch: FifoSflpRaw,
+    pub fifo_stpcnt_batch: bool,
+    pub pin_int1_route: Int1Route,
+    pub pin_int2_route: Int2Route,
+    pub pin_int1_route_hg: IntRouteHg,
+    pub pin_int2_route_hg: IntRouteHg,
+    pub pin_int1_route_embedded: IntRouteEmb,
+    pub pin_int2_route_embedded: IntRouteEmb,
+}
+
+impl DeviceConfig {
+    /// Get the names of the fields that differ from `other`.
+    pub fn diff<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'static str> + 'a {
+        [
+            ("xl_odr", self.xl_odr != other.xl_odr),
+            ("xl_mode", self.xl_mode != other.xl_mode),
+            ("xl_full_scale", self.xl_full_scale != other.xl_full_scale),
+            ("gy_odr", self.gy_odr != other.gy_odr),
+            ("gy_mode", self.gy_mode != other.gy_mode),
+            ("gy_full_scale", self.gy_full_scale != other.gy_full_scale),
+            ("hg_xl_odr", self.hg_xl_odr != other.hg_xl_odr),
+            (
+                "hg_xl_reg_out_en",
+                self.hg_xl_reg_out_en != other.hg_xl_reg_out_en,
+            ),
+            (
+                "hg_xl_full_scale",
+                self.hg_xl_full_scale != other.hg_xl_full_scale,
+            ),
+            (
+                "block_data_update",
+                self.block_data_update != other.block_data_update,
+            ),
+            ("timestamp", self.timestamp != other.timestamp),
+            ("filt_gy_lp1", self.filt_gy_lp1 != other.filt_gy_lp1),
+            (
+                "filt_gy_lp1_bandwidth",
+                self.filt_gy_lp1_bandwidth != other.filt_gy_lp1_bandwidth,
+            ),
+            ("filt_xl_lp2", self.filt_xl_lp2 != other.filt_xl_lp2),
+            (
+                "filt_xl_lp2_bandwidth",
+                self.filt_xl_lp2_bandwidth != other.filt_xl_lp2_bandwidth,
+            ),
+            ("fifo_mode", self.fifo_mode != other.fifo_mode),
+            (
+                "fifo_watermark",
+                self.fifo_watermark != other.fifo_watermark,
+            ),
+            (
+                "fifo_compress_algo",
+                self.fifo_compress_algo != other.fifo_compress_algo,
+            ),
+            ("fifo_xl_batch", self.fifo_xl_batch != other.fifo_xl_batch),
+            ("fifo_gy_batch", self.fifo_gy_batch != other.fifo_gy_batch),
+            (
+                "fifo_hg_xl_batch",
+                self.fifo_hg_xl_batch != other.fifo_hg_xl_batch,
+            ),
+            (
+                "fifo_temp_batch",
+                self.fifo_temp_batch != other.fifo_temp_batch,
+            ),
+            (
+                "fifo_timestamp_batch",
+                self.fifo_timestamp_batch != other.fifo_timestamp_batch,
+            ),
+            (
+                "fifo_sflp_batch",
+                self.fifo_sflp_batch != other.fifo_sflp_batch,
+            ),
+            (
+                "fifo_stpcnt_batch",
+                self.fifo_stpcnt_batch != other.fifo_stpcnt_batch,
+            ),
+            (
+                "pin_int1_route",
+                self.pin_int1_route != other.pin_int1_route,
+            ),
+            (
+                "pin_int2_route",
+                self.pin_int2_route != other.pin_int2_route,
+            ),
+            (
+                "pin_int1_route_hg",
+                self.pin_int1_route_hg != other.pin_int1_route_hg,
+            ),
+            (
+                "pin_int2_route_hg",
+                self.pin_int2_route_hg != other.pin_int2_route_hg,
+            ),
+            (
+                "pin_int1_route_embedded",
+                self.pin_int1_route_embedded != other.pin_int1_route_embedded,
+            ),
+            (
+                "pin_int2_route_embedded",
+                self.pin_int2_route_embedded != other.pin_int2_route_embedded,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(name, changed)| changed.then_some(name))
+    }
+}
+
+/// Result of a self-test procedure.
+///
+/// Output changes are absolute values in mg (accelerometers) or mdps
+/// (gyroscope), for x, y, z axes.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SelfTestReport {
+    /// Output change with positive stimulus.
+    pub positive: [f32; 3],
+    /// Output change with negative stimulus.
+    pub negative: [f32; 3],
+    /// Axis passed: both output changes are within the limits.
+    pub pass: [bool; 3],
+}
+
+impl SelfTestReport {
+    /// True if all the axes passed the self-test.
+    pub fn passed(&self) -> bool {
+        self.pass.iter().all(|&pass| pass)
+    }
+}
+
 #[derive(Default, Debug, PartialEq, Clone)]
 pub struct I3cConfig {
     pub if2_ta0_pid: u8,
@@ -2098,6 +2599,7 @@ impl TryFrom<u8> for Reset {
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataRate {
     /// Output data rate off (default).
     #[default]
@@ -2188,10 +2690,62 @@ pub enum DataRate {
     Ha03At6667hz = 0x3C,
 }
 
+impl DataRate {
+    /// Get the data rate in Hz.
+    pub fn to_hz(self) -> f32 {
+        match self {
+            DataRate::Off => 0.0,
+            DataRate::_1_875hz => 1.875,
+            DataRate::_7_5hz => 7.5,
+            DataRate::_15hz => 15.0,
+            DataRate::_30hz => 30.0,
+            DataRate::_60hz => 60.0,
+            DataRate::_120hz => 120.0,
+            DataRate::_240hz => 240.0,
+            DataRate::_480hz => 480.0,
+            DataRate::_960hz => 960.0,
+            DataRate::_1920hz => 1920.0,
+            DataRate::_3840hz => 3840.0,
+            DataRate::_7680hz => 7680.0,
+            DataRate::Ha01At15_625hz => 15.625,
+            DataRate::Ha01At31_25hz => 31.25,
+            DataRate::Ha01At62_5hz => 62.5,
+            DataRate::Ha01At125hz => 125.0,
+            DataRate::Ha01At250hz => 250.0,
+            DataRate::Ha01At500hz => 500.0,
+            DataRate::Ha01At1000hz => 1000.0,
+            DataRate::Ha01At2000hz => 2000.0,
+            DataRate::Ha01At4000hz => 4000.0,
+            DataRate::Ha01At8000hz => 8000.0,
+            DataRate::Ha02At12_5hz => 12.5,
+            DataRate::Ha02At25hz => 25.0,
+            DataRate::Ha02At50hz => 50.0,
+            DataRate::Ha02At100hz => 100.0,
+            DataRate::Ha02At200hz => 200.0,
+            DataRate::Ha02At400hz => 400.0,
+            DataRate::Ha02At800hz => 800.0,
+            DataRate::Ha02At1600hz => 1600.0,
+            DataRate::Ha02At3200hz => 3200.0,
+            DataRate::Ha02At6400hz => 6400.0,
+            DataRate::Ha03At13hz => 13.0,
+            DataRate::Ha03At26hz => 26.0,
+            DataRate::Ha03At52hz => 52.0,
+            DataRate::Ha03At104hz => 104.0,
+            DataRate::Ha03At208hz => 208.0,
+            DataRate::Ha03At417hz => 417.0,
+            DataRate::Ha03At833hz => 833.0,
+            DataRate::Ha03At1667hz => 1667.0,
+            DataRate::Ha03At3333hz => 3333.0,
+            DataRate::Ha03At6667hz => 6667.0,
+        }
+    }
+}
+
 /// High-G accelerometer output data rate (ODR) selection.
 #[repr(u8)]
-#[derive(Clone, Copy, PartialEq, Default, TryFrom)]
+#[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HgXlDataRate {
     /// Output data rate off (default).
     #[default]
@@ -2208,10 +2762,25 @@ pub enum HgXlDataRate {
     _7680hz = 0x7,
 }
 
+impl HgXlDataRate {
+    /// Get the data rate in Hz.
+    pub fn to_hz(self) -> f32 {
+        match self {
+            HgXlDataRate::Off => 0.0,
+            HgXlDataRate::_480hz => 480.0,
+            HgXlDataRate::_960hz => 960.0,
+            HgXlDataRate::_1920hz => 1920.0,
+            HgXlDataRate::_3840hz => 3840.0,
+            HgXlDataRate::_7680hz => 7680.0,
+        }
+    }
+}
+
 /// Accelerometer operating mode selection.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum XlMode {
     /// High-performance mode (default).
     #[default]
@@ -2234,6 +2803,7 @@ pub enum XlMode {
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GyMode {
     /// High-performance mode (default).
     #[default]
@@ -2264,6 +2834,7 @@ pub enum DataReadyMode {
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GyFullScale {
     /// ±250 dps (default).
     #[default]
@@ -2278,10 +2849,24 @@ pub enum GyFullScale {
     _4000dps = 0x5,
 }
 
+impl GyFullScale {
+    /// Convert a raw angular rate sample to mdps for the full scale.
+    pub fn to_mdps(self, lsb: i16) -> f32 {
+        match self {
+            GyFullScale::_250dps => super::super::from_fs250_to_mdps(lsb),
+            GyFullScale::_500dps => super::super::from_fs500_to_mdps(lsb),
+            GyFullScale::_1000dps => super::super::from_fs1000_to_mdps(lsb),
+            GyFullScale::_2000dps => super::super::from_fs2000_to_mdps(lsb),
+            GyFullScale::_4000dps => super::super::from_fs4000_to_mdps(lsb),
+        }
+    }
+}
+
 /// Accelerometer full-scale selection.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum XlFullScale {
     /// ±2 g (default).
     #[default]
@@ -2294,6 +2879,18 @@ pub enum XlFullScale {
     _16g = 0x3,
 }
 
+impl XlFullScale {
+    /// Convert a raw acceleration sample to mg for the full scale.
+    pub fn to_mg(self, lsb: i16) -> f32 {
+        match self {
+            XlFullScale::_2g => super::super::from_fs2_to_mg(lsb),
+            XlFullScale::_4g => super::super::from_fs4_to_mg(lsb),
+            XlFullScale::_8g => super::super::from_fs8_to_mg(lsb),
+            XlFullScale::_16g => super::super::from_fs16_to_mg(lsb),
+        }
+    }
+}
+
 /// Setup filter pipeline from lpf1 filter to UI
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, TryFrom)]
@@ -2310,6 +2907,7 @@ pub enum XlFilter {
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HgXlFullScale {
     /// ±32 g (default).
     #[default]
@@ -2324,6 +2922,19 @@ pub enum HgXlFullScale {
     _320g = 0x4,
 }
 
+impl HgXlFullScale {
+    /// Convert a raw acceleration sample to mg for the full scale.
+    pub fn to_mg(self, lsb: i16) -> f32 {
+        match self {
+            HgXlFullScale::_32g => super::super::from_fs32_to_mg(lsb),
+            HgXlFullScale::_64g => super::super::from_fs64_to_mg(lsb),
+            HgXlFullScale::_128g => super::super::from_fs128_to_mg(lsb),
+            HgXlFullScale::_256g => super::super::from_fs256_to_mg(lsb),
+            HgXlFullScale::_320g => super::super::from_fs320_to_mg(lsb),
+        }
+    }
+}
+
 /// Accelerometer and gyroscope self-test selection.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
@@ -2350,6 +2961,18 @@ pub enum DenPolarity {
     ActiveHigh = 0x1,
 }
 
+/// Interrupt pins (INT1, INT2) active level.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Default, TryFrom, Debug)]
+#[try_from(repr)]
+pub enum IntPinPolarity {
+    /// Active high (default).
+    #[default]
+    ActiveHigh = 0x0,
+    /// Active low.
+    ActiveLow = 0x1,
+}
+
 /// Gyroscope full-scale selection for EIS channel.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, TryFrom, Debug)]
@@ -2368,6 +2991,19 @@ pub enum EisGyFullScale {
     _4000dps = 0x5,
 }
 
+impl EisGyFullScale {
+    /// Convert a raw angular rate sample to mdps for the full scale.
+    pub fn to_mdps(self, lsb: i16) -> f32 {
+        match self {
+            EisGyFullScale::_250dps => super::super::from_fs250_to_mdps(lsb),
+            EisGyFullScale::_500dps => super::super::from_fs500_to_mdps(lsb),
+            EisGyFullScale::_1000dps => super::super::from_fs1000_to_mdps(lsb),
+            EisGyFullScale::_2000dps => super::super::from_fs2000_to_mdps(lsb),
+            EisGyFullScale::_4000dps => super::super::from_fs4000_to_mdps(lsb),
+        }
+    }
+}
+
 /// Gyroscope EIS output data rate selection.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, TryFrom)]
@@ -2386,6 +3022,7 @@ pub enum EisGyDataRate {
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, TryFrom, Debug)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FifoCompressAlgo {
     /// Compression disabled (default).
     #[default]
@@ -2402,6 +3039,7 @@ pub enum FifoCompressAlgo {
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FifoBatch {
     /// Not batched (default).
     #[default]
@@ -2432,10 +3070,32 @@ pub enum FifoBatch {
     _7680hz = 0xC,
 }
 
+impl FifoBatch {
+    /// Get the batch data rate in Hz.
+    pub fn to_hz(self) -> f32 {
+        match self {
+            FifoBatch::NotBatched => 0.0,
+            FifoBatch::_1_875hz => 1.875,
+            FifoBatch::_7_5hz => 7.5,
+            FifoBatch::_15hz => 15.0,
+            FifoBatch::_30hz => 30.0,
+            FifoBatch::_60hz => 60.0,
+            FifoBatch::_120hz => 120.0,
+            FifoBatch::_240hz => 240.0,
+            FifoBatch::_480hz => 480.0,
+            FifoBatch::_960hz => 960.0,
+            FifoBatch::_1920hz => 1920.0,
+            FifoBatch::_3840hz => 3840.0,
+            FifoBatch::_7680hz => 7680.0,
+        }
+    }
+}
+
 /// FIFO mode selection.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FifoMode {
     /// Bypass mode: FIFO disabled (default).
     #[default]
@@ -2458,6 +3118,7 @@ pub enum FifoMode {
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FifoTempBatch {
     /// Temperature not batched (default).
     #[default]
@@ -2470,12 +3131,25 @@ pub enum FifoTempBatch {
     _60hz = 0x3,
 }
 
+impl FifoTempBatch {
+    /// Get the batch data rate in Hz.
+    pub fn to_hz(self) -> f32 {
+        match self {
+            FifoTempBatch::NotBatched => 0.0,
+            FifoTempBatch::_1_875hz => 1.875,
+            FifoTempBatch::_15hz => 15.0,
+            FifoTempBatch::_60hz => 60.0,
+        }
+    }
+}
+
 /// Decimation for timestamp batching in FIFO.
 ///
 /// Write rate is the maximum rate between accelerometer and gyroscope BDR divided by decimation factor.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FifoTimestampBatch {
     /// Timestamp not batched (default).
     #[default]
@@ -2520,6 +3194,7 @@ pub enum FiltAntiSpike {
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
 #[try_from(repr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FiltLpBandwidth {
     /// Ultra light bandwidth (default).
     #[default]
@@ -2700,6 +3375,18 @@ pub enum OisGyFullScale {
     _2000dps = 0x4,
 }
 
+impl OisGyFullScale {
+    /// Convert a raw angular rate sample to mdps for the full scale.
+    pub fn to_mdps(self, lsb: i16) -> f32 {
+        match self {
+            OisGyFullScale::_250dps => super::super::from_fs250_to_mdps(lsb),
+            OisGyFullScale::_500dps => super::super::from_fs500_to_mdps(lsb),
+            OisGyFullScale::_1000dps => super::super::from_fs1000_to_mdps(lsb),
+            OisGyFullScale::_2000dps => super::super::from_fs2000_to_mdps(lsb),
+        }
+    }
+}
+
 /// Accelerometer OIS full-scale selection.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
@@ -2716,6 +3403,18 @@ pub enum OisXlFullScale {
     _16g = 0x3,
 }
 
+impl OisXlFullScale {
+    /// Convert a raw acceleration sample to mg for the full scale.
+    pub fn to_mg(self, lsb: i16) -> f32 {
+        match self {
+            OisXlFullScale::_2g => super::super::from_fs2_to_mg(lsb),
+            OisXlFullScale::_4g => super::super::from_fs4_to_mg(lsb),
+            OisXlFullScale::_8g => super::super::from_fs8_to_mg(lsb),
+            OisXlFullScale::_16g => super::super::from_fs16_to_mg(lsb),
+        }
+    }
+}
+
 /// Threshold for 4D/6D function.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, Debug, TryFrom)]
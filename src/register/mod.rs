@@ -1,3 +1,104 @@
+/// Define a set of flags stored in the bits of an integer.
+///
+/// The generated type provides one constant per flag, the usual set
+/// operations, and can be combined with `|`.
+macro_rules! flag_set {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident($ty:ty) {
+            $(
+                $(#[$flag_meta:meta])*
+                const $flag:ident = $bit:expr;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name($ty);
+
+        impl $name {
+            $(
+                $(#[$flag_meta])*
+                pub const $flag: Self = Self(1 << $bit);
+            )*
+
+            /// Get a set with no flags.
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// Get a set with all the flags.
+            pub const fn all() -> Self {
+                Self(0 $(| (1 << $bit))*)
+            }
+
+            /// Get the raw bits of the set.
+            pub const fn bits(&self) -> $ty {
+                self.0
+            }
+
+            /// Check if no flag is set.
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// Check if all the flags of `other` are set.
+            pub const fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Check if at least one flag of `other` is set.
+            pub const fn intersects(&self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            /// Get the union of two sets.
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            /// Set the flags of `other`.
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            /// Clear the flags of `other`.
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            /// Set or clear the flags of `other`.
+            pub fn set(&mut self, other: Self, value: bool) {
+                if value {
+                    self.insert(other);
+                } else {
+                    self.remove(other);
+                }
+            }
+
+            /// Get the register bit value (0 or 1) of a flag.
+            pub(crate) const fn bit(&self, flag: Self) -> u8 {
+                self.contains(flag) as u8
+            }
+        }
+
+        impl core::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                self.union(rhs)
+            }
+        }
+
+        impl core::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.insert(rhs);
+            }
+        }
+    };
+}
+
 pub mod advanced;
 pub mod embedded;
 pub mod if2;
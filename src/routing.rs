@@ -0,0 +1,360 @@
+use super::prelude::*;
+
+/// Interrupt pads a source is routed to.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum IntPin {
+    #[default]
+    None,
+    Int1,
+    Int2,
+    Both,
+}
+
+impl IntPin {
+    /// Get the pads from the INT1 and INT2 selection.
+    pub const fn from_pins(int1: bool, int2: bool) -> Self {
+        match (int1, int2) {
+            (false, false) => IntPin::None,
+            (true, false) => IntPin::Int1,
+            (false, true) => IntPin::Int2,
+            (true, true) => IntPin::Both,
+        }
+    }
+
+    /// Check if INT1 is selected.
+    pub const fn int1(&self) -> bool {
+        matches!(self, IntPin::Int1 | IntPin::Both)
+    }
+
+    /// Check if INT2 is selected.
+    pub const fn int2(&self) -> bool {
+        matches!(self, IntPin::Int2 | IntPin::Both)
+    }
+}
+
+/// Source of an interrupt signal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IntSource {
+    DrdyXl,
+    DrdyGy,
+    DrdyGyEis,
+    DrdyTemp,
+    DrdyHgXl,
+    FifoTh,
+    FifoOvr,
+    FifoFull,
+    CntBdr,
+    Timestamp,
+    SensorHub,
+    SixD,
+    SingleTap,
+    DoubleTap,
+    WakeUp,
+    FreeFall,
+    SleepChange,
+    EmbFuncEndop,
+    HgWakeUp,
+    HgShockChange,
+    StepDetector,
+    Tilt,
+    SigMot,
+    /// FSM `n` (1 - 8).
+    Fsm(u8),
+    /// MLC `n` (1 - 8).
+    Mlc(u8),
+}
+
+const FSM_ROUTES: [IntRouteEmb; 8] = [
+    IntRouteEmb::FSM1,
+    IntRouteEmb::FSM2,
+    IntRouteEmb::FSM3,
+    IntRouteEmb::FSM4,
+    IntRouteEmb::FSM5,
+    IntRouteEmb::FSM6,
+    IntRouteEmb::FSM7,
+    IntRouteEmb::FSM8,
+];
+
+const MLC_ROUTES: [IntRouteEmb; 8] = [
+    IntRouteEmb::MLC1,
+    IntRouteEmb::MLC2,
+    IntRouteEmb::MLC3,
+    IntRouteEmb::MLC4,
+    IntRouteEmb::MLC5,
+    IntRouteEmb::MLC6,
+    IntRouteEmb::MLC7,
+    IntRouteEmb::MLC8,
+];
+
+/// Routing bits of a source.
+enum RouteFlag {
+    Main(Option<Int1Route>, Option<Int2Route>),
+    Hg(IntRouteHg),
+    Emb(IntRouteEmb),
+}
+
+impl IntSource {
+    fn route_flag(&self) -> Option<RouteFlag> {
+        let flag = match *self {
+            IntSource::DrdyXl => {
+                RouteFlag::Main(Some(Int1Route::DRDY_XL), Some(Int2Route::DRDY_XL))
+            }
+            IntSource::DrdyGy => RouteFlag::Main(Some(Int1Route::DRDY_G), Some(Int2Route::DRDY_G)),
+            IntSource::DrdyGyEis => RouteFlag::Main(None, Some(Int2Route::DRDY_G_EIS)),
+            IntSource::DrdyTemp => RouteFlag::Main(None, Some(Int2Route::DRDY_TEMP)),
+            IntSource::FifoTh => {
+                RouteFlag::Main(Some(Int1Route::FIFO_TH), Some(Int2Route::FIFO_TH))
+            }
+            IntSource::FifoOvr => {
+                RouteFlag::Main(Some(Int1Route::FIFO_OVR), Some(Int2Route::FIFO_OVR))
+            }
+            IntSource::FifoFull => {
+                RouteFlag::Main(Some(Int1Route::FIFO_FULL), Some(Int2Route::FIFO_FULL))
+            }
+            IntSource::CntBdr => {
+                RouteFlag::Main(Some(Int1Route::CNT_BDR), Some(Int2Route::CNT_BDR))
+            }
+            IntSource::Timestamp => RouteFlag::Main(None, Some(Int2Route::TIMESTAMP)),
+            IntSource::SensorHub => RouteFlag::Main(Some(Int1Route::SHUB), None),
+            IntSource::SixD => RouteFlag::Main(Some(Int1Route::SIXD), Some(Int2Route::SIXD)),
+            IntSource::SingleTap => {
+                RouteFlag::Main(Some(Int1Route::SINGLE_TAP), Some(Int2Route::SINGLE_TAP))
+            }
+            IntSource::DoubleTap => {
+                RouteFlag::Main(Some(Int1Route::DOUBLE_TAP), Some(Int2Route::DOUBLE_TAP))
+            }
+            IntSource::WakeUp => RouteFlag::Main(Some(Int1Route::WAKEUP), Some(Int2Route::WAKEUP)),
+            IntSource::FreeFall => {
+                RouteFlag::Main(Some(Int1Route::FREEFALL), Some(Int2Route::FREEFALL))
+            }
+            IntSource::SleepChange => {
+                RouteFlag::Main(Some(Int1Route::SLEEP_CHANGE), Some(Int2Route::SLEEP_CHANGE))
+            }
+            IntSource::EmbFuncEndop => RouteFlag::Main(None, Some(Int2Route::EMB_FUNC_ENDOP)),
+            IntSource::DrdyHgXl => RouteFlag::Hg(IntRouteHg::DRDY_HG_XL),
+            IntSource::HgWakeUp => RouteFlag::Hg(IntRouteHg::HG_WAKEUP),
+            IntSource::HgShockChange => RouteFlag::Hg(IntRouteHg::HG_SHOCK_CHANGE),
+            IntSource::StepDetector => RouteFlag::Emb(IntRouteEmb::STEP_DETECTOR),
+            IntSource::Tilt => RouteFlag::Emb(IntRouteEmb::TILT),
+            IntSource::SigMot => RouteFlag::Emb(IntRouteEmb::SIG_MOT),
+            IntSource::Fsm(n @ 1..=8) => RouteFlag::Emb(FSM_ROUTES[n as usize - 1]),
+            IntSource::Mlc(n @ 1..=8) => RouteFlag::Emb(MLC_ROUTES[n as usize - 1]),
+            IntSource::Fsm(_) | IntSource::Mlc(_) => return None,
+        };
+
+        Some(flag)
+    }
+
+    /// Get the pads the source can be routed to.
+    pub fn supported_pins(&self) -> IntPin {
+        match self.route_flag() {
+            Some(RouteFlag::Main(int1, int2)) => IntPin::from_pins(int1.is_some(), int2.is_some()),
+            Some(RouteFlag::Hg(_)) | Some(RouteFlag::Emb(_)) => IntPin::Both,
+            None => IntPin::None,
+        }
+    }
+}
+
+/// Routing of the interrupt sources on the INT1 and INT2 pads.
+///
+/// Each source is assigned to INT1, INT2, both or none; sources that are not
+/// listed are not routed. The routing is applied with
+/// `Lsm6dsv320x::interrupt_routing_set`:
+///
+/// ```rust,ignore
+/// let routing = InterruptRouting::new()
+///     .route(IntSource::FifoTh, IntPin::Int1)
+///     .route(IntSource::HgWakeUp, IntPin::Both)
+///     .route(IntSource::Fsm(1), IntPin::Int2);
+///
+/// sensor.interrupt_routing_set(&routing).unwrap();
+/// ```
+///
+/// Not every source can be routed on both pads (for example the sensor hub
+/// only on INT1, the temperature data-ready only on INT2): routing a source
+/// on a pad it does not support is reported by `unsupported` and rejected by
+/// `interrupt_routing_set`.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct InterruptRouting {
+    pub(crate) int1: Int1Route,
+    pub(crate) int2: Int2Route,
+    pub(crate) int1_hg: IntRouteHg,
+    pub(crate) int2_hg: IntRouteHg,
+    pub(crate) int1_emb: IntRouteEmb,
+    pub(crate) int2_emb: IntRouteEmb,
+    unsupported: Option<IntSource>,
+}
+
+impl InterruptRouting {
+    /// Create a routing with no source routed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Route `source` on `pin`, replacing its previous routing.
+    pub fn route(mut self, source: IntSource, pin: IntPin) -> Self {
+        let supported = source.supported_pins();
+        if (pin.int1() && !supported.int1()) || (pin.int2() && !supported.int2()) {
+            if self.unsupported.is_none() {
+                self.unsupported = Some(source);
+            }
+            return self;
+        }
+
+        match source.route_flag() {
+            Some(RouteFlag::Main(int1, int2)) => {
+                if let Some(flag) = int1 {
+                    self.int1.set(flag, pin.int1());
+                }
+                if let Some(flag) = int2 {
+                    self.int2.set(flag, pin.int2());
+                }
+            }
+            Some(RouteFlag::Hg(flag)) => {
+                self.int1_hg.set(flag, pin.int1());
+                self.int2_hg.set(flag, pin.int2());
+            }
+            Some(RouteFlag::Emb(flag)) => {
+                self.int1_emb.set(flag, pin.int1());
+                self.int2_emb.set(flag, pin.int2());
+            }
+            None => {}
+        }
+
+        self
+    }
+
+    /// Get the pads `source` is routed to.
+    pub fn pin(&self, source: IntSource) -> IntPin {
+        match source.route_flag() {
+            Some(RouteFlag::Main(int1, int2)) => IntPin::from_pins(
+                int1.is_some_and(|flag| self.int1.contains(flag)),
+                int2.is_some_and(|flag| self.int2.contains(flag)),
+            ),
+            Some(RouteFlag::Hg(flag)) => {
+                IntPin::from_pins(self.int1_hg.contains(flag), self.int2_hg.contains(flag))
+            }
+            Some(RouteFlag::Emb(flag)) => {
+                IntPin::from_pins(self.int1_emb.contains(flag), self.int2_emb.contains(flag))
+            }
+            None => IntPin::None,
+        }
+    }
+
+    /// Get the first source routed on a pad it does not support, if any.
+    pub fn unsupported(&self) -> Option<IntSource> {
+        self.unsupported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::super::{Error, Lsm6dsv320x};
+    use super::*;
+
+    #[test]
+    fn route_and_pin() {
+        let routing = InterruptRouting::new()
+            .route(IntSource::DrdyXl, IntPin::Both)
+            .route(IntSource::SensorHub, IntPin::Int1)
+            .route(IntSource::HgWakeUp, IntPin::Int2)
+            .route(IntSource::Fsm(3), IntPin::Int1)
+            .route(IntSource::Mlc(8), IntPin::Both);
+
+        assert_eq!(routing.pin(IntSource::DrdyXl), IntPin::Both);
+        assert_eq!(routing.pin(IntSource::SensorHub), IntPin::Int1);
+        assert_eq!(routing.pin(IntSource::HgWakeUp), IntPin::Int2);
+        assert_eq!(routing.pin(IntSource::Fsm(3)), IntPin::Int1);
+        assert_eq!(routing.pin(IntSource::Mlc(8)), IntPin::Both);
+        assert_eq!(routing.pin(IntSource::Fsm(4)), IntPin::None);
+        assert_eq!(routing.pin(IntSource::FreeFall), IntPin::None);
+        assert_eq!(routing.unsupported(), None);
+
+        assert_eq!(routing.int1, Int1Route::DRDY_XL | Int1Route::SHUB);
+        assert_eq!(routing.int2, Int2Route::DRDY_XL);
+        assert_eq!(routing.int1_hg, IntRouteHg::empty());
+        assert_eq!(routing.int2_hg, IntRouteHg::HG_WAKEUP);
+        assert_eq!(routing.int1_emb, IntRouteEmb::FSM3 | IntRouteEmb::MLC8);
+        assert_eq!(routing.int2_emb, IntRouteEmb::MLC8);
+    }
+
+    #[test]
+    fn route_replaces_previous() {
+        let routing = InterruptRouting::new()
+            .route(IntSource::WakeUp, IntPin::Both)
+            .route(IntSource::Tilt, IntPin::Int1)
+            .route(IntSource::WakeUp, IntPin::Int2)
+            .route(IntSource::Tilt, IntPin::None);
+
+        assert_eq!(routing.pin(IntSource::WakeUp), IntPin::Int2);
+        assert_eq!(routing.pin(IntSource::Tilt), IntPin::None);
+        assert_eq!(routing.int1, Int1Route::empty());
+        assert_eq!(routing.int2, Int2Route::WAKEUP);
+        assert!(routing.int1_emb.is_empty());
+    }
+
+    #[test]
+    fn unsupported_pins() {
+        assert_eq!(IntSource::SensorHub.supported_pins(), IntPin::Int1);
+        assert_eq!(IntSource::DrdyTemp.supported_pins(), IntPin::Int2);
+        assert_eq!(IntSource::Fsm(9).supported_pins(), IntPin::None);
+
+        // The first unsupported source is kept, its routing is not applied
+        let routing = InterruptRouting::new()
+            .route(IntSource::DrdyTemp, IntPin::Both)
+            .route(IntSource::SensorHub, IntPin::Int2)
+            .route(IntSource::FifoTh, IntPin::Int1);
+        assert_eq!(routing.unsupported(), Some(IntSource::DrdyTemp));
+        assert_eq!(routing.pin(IntSource::DrdyTemp), IntPin::None);
+        assert_eq!(routing.pin(IntSource::SensorHub), IntPin::None);
+        assert_eq!(routing.pin(IntSource::FifoTh), IntPin::Int1);
+
+        let routing = InterruptRouting::new().route(IntSource::Mlc(0), IntPin::Int1);
+        assert_eq!(routing.unsupported(), Some(IntSource::Mlc(0)));
+        let routing = InterruptRouting::new().route(IntSource::Fsm(9), IntPin::None);
+        assert_eq!(routing.unsupported(), None);
+    }
+
+    #[test]
+    fn emb_func_enabled_only_if_routed() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+        let main = MemBank::MainMemBank;
+
+        let routing = InterruptRouting::new()
+            .route(IntSource::FifoTh, IntPin::Int1)
+            .route(IntSource::StepDetector, IntPin::Int2);
+        block_on(sensor.interrupt_routing_set(&routing)).unwrap();
+        let md1_cfg = Md1Cfg::from_bits(sensor.bus.reg_get(main, Reg::Md1Cfg as u8));
+        let md2_cfg = Md2Cfg::from_bits(sensor.bus.reg_get(main, Reg::Md2Cfg as u8));
+        assert_eq!(md1_cfg.int1_emb_func(), 0);
+        assert_eq!(md2_cfg.int2_emb_func(), 1);
+        assert_eq!(block_on(sensor.interrupt_routing_get()).unwrap(), routing);
+
+        let routing = InterruptRouting::new().route(IntSource::Fsm(1), IntPin::Int1);
+        block_on(sensor.interrupt_routing_set(&routing)).unwrap();
+        let md1_cfg = Md1Cfg::from_bits(sensor.bus.reg_get(main, Reg::Md1Cfg as u8));
+        let md2_cfg = Md2Cfg::from_bits(sensor.bus.reg_get(main, Reg::Md2Cfg as u8));
+        assert_eq!(md1_cfg.int1_emb_func(), 1);
+        assert_eq!(md2_cfg.int2_emb_func(), 0);
+        assert_eq!(block_on(sensor.interrupt_routing_get()).unwrap(), routing);
+
+        block_on(sensor.interrupt_routing_set(&InterruptRouting::new())).unwrap();
+        assert_eq!(sensor.bus.reg_get(main, Reg::Md1Cfg as u8), 0);
+        assert_eq!(sensor.bus.reg_get(main, Reg::Md2Cfg as u8), 0);
+        assert_eq!(
+            block_on(sensor.interrupt_routing_get()).unwrap(),
+            InterruptRouting::new()
+        );
+
+        // Unsupported routing is rejected before any write
+        let routing = InterruptRouting::new()
+            .route(IntSource::Tilt, IntPin::Int1)
+            .route(IntSource::Timestamp, IntPin::Int1);
+        assert!(matches!(
+            block_on(sensor.interrupt_routing_set(&routing)),
+            Err(Error::InvalidConfiguration)
+        ));
+        assert_eq!(sensor.bus.reg_get(main, Reg::Md1Cfg as u8), 0);
+    }
+}
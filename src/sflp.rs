@@ -0,0 +1,263 @@
+use super::prelude::*;
+use super::{BusOperation, DelayNs, Error, Lsm6dsv320x, bisync};
+
+/// Size in bytes of a serialized `SflpGbiasBlob`.
+pub const SFLP_GBIAS_BLOB_SIZE: usize = 7;
+
+/// Format version of the serialized `SflpGbiasBlob`.
+const SFLP_GBIAS_BLOB_VERSION: u8 = 1;
+
+/// Gyroscope bias estimated by the SFLP, saved to seed the next run.
+///
+/// It holds the raw output of `sflp_gbias_raw_get` and can be stored as
+/// `SFLP_GBIAS_BLOB_SIZE` bytes (`to_bytes`/`from_bytes`) or with serde.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SflpGbiasBlob {
+    pub raw: [i16; 3],
+}
+
+impl SflpGbiasBlob {
+    /// Serialize the blob: version byte followed by x, y, z little-endian.
+    pub fn to_bytes(&self) -> [u8; SFLP_GBIAS_BLOB_SIZE] {
+        let mut buf = [0; SFLP_GBIAS_BLOB_SIZE];
+        buf[0] = SFLP_GBIAS_BLOB_VERSION;
+        for (chunk, val) in buf[1..].chunks_exact_mut(2).zip(self.raw) {
+            chunk.copy_from_slice(&val.to_le_bytes());
+        }
+        buf
+    }
+
+    /// Deserialize a blob written by `to_bytes`.
+    ///
+    /// Returns None if `buf` is too short or has a different version.
+    pub fn from_bytes(buf: &[u8]) -> Option<Self> {
+        if buf.len() < SFLP_GBIAS_BLOB_SIZE || buf[0] != SFLP_GBIAS_BLOB_VERSION {
+            return None;
+        }
+
+        let mut raw = [0; 3];
+        for (val, chunk) in raw
+            .iter_mut()
+            .zip(buf[1..SFLP_GBIAS_BLOB_SIZE].chunks_exact(2))
+        {
+            *val = i16::from_le_bytes([chunk[0], chunk[1]]);
+        }
+        Some(Self { raw })
+    }
+
+    /// Get the bias in mdps, as expected by `sflp_gbias_set`.
+    pub fn to_mdps(&self) -> Vector3<AngularRate<MilliDps>> {
+        Vector3::from(self.raw).map(AngularRate::from_gbias_lsb)
+    }
+}
+
+/// Sensor fusion low-power (SFLP) session.
+///
+/// The session applies the SFLP configuration in the required order, seeding
+/// the gyroscope bias from a previous run, and tracks the convergence of the
+/// bias estimate:
+///
+/// ```rust,ignore
+/// let mut sflp = Sflp::new(SflpDataRate::_120hz)
+///     .fifo(FifoSflpRaw { game_rotation: 1, gravity: 1, gbias: 0 })
+///     .gbias(SflpGbiasBlob::from_bytes(&stored).unwrap());
+/// sflp.start(&mut sensor).unwrap();
+///
+/// while !sflp.converged(&mut sensor).unwrap() {
+///     // ...
+/// }
+/// let blob = sflp.gbias_save(&mut sensor).unwrap();
+/// ```
+///
+/// The accelerometer and gyroscope must be running at a data rate not lower
+/// than the SFLP one before `start` is called.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Sflp {
+    data_rate: SflpDataRate,
+    fifo: FifoSflpRaw,
+    gbias: Option<SflpGbiasBlob>,
+    warm_up_ms: u32,
+    tolerance: u16,
+    last_gbias: Option<[i16; 3]>,
+}
+
+impl Sflp {
+    /// Create a session with the SFLP running at `data_rate`.
+    ///
+    /// By default nothing is batched in FIFO, the bias is not seeded, there is
+    /// no warm-up delay and the convergence tolerance is 2 LSB.
+    pub fn new(data_rate: SflpDataRate) -> Self {
+        Self {
+            data_rate,
+            fifo: FifoSflpRaw::default(),
+            gbias: None,
+            warm_up_ms: 0,
+            tolerance: 2,
+            last_gbias: None,
+        }
+    }
+
+    /// Batch the SFLP outputs in `val` in FIFO.
+    pub fn fifo(mut self, val: FifoSflpRaw) -> Self {
+        self.fifo = val;
+        self
+    }
+
+    /// Seed the gyroscope bias with the one saved from a previous run.
+    pub fn gbias(mut self, val: SflpGbiasBlob) -> Self {
+        self.gbias = Some(val);
+        self
+    }
+
+    /// Wait `val` milliseconds at the end of `start`.
+    pub fn warm_up_ms(mut self, val: u32) -> Self {
+        self.warm_up_ms = val;
+        self
+    }
+
+    /// Set the maximum change (LSB) of the bias estimate, on any axis,
+    /// between two `converged` calls to consider it converged.
+    pub fn convergence_tolerance(mut self, val: u16) -> Self {
+        self.tolerance = val;
+        self
+    }
+}
+
+#[bisync]
+impl Sflp {
+    /// Configure and start the SFLP.
+    ///
+    /// The sequence is: data rate, gyroscope bias seed, game rotation enable,
+    /// algorithm initialization, FIFO batching and warm-up.
+    ///
+    /// Returns `Error::InvalidConfiguration` if the accelerometer or gyroscope
+    /// data rate is lower than the SFLP one.
+    pub async fn start<B: BusOperation, T: DelayNs>(
+        &mut self,
+        sensor: &mut Lsm6dsv320x<B, T, MainBank>,
+    ) -> Result<(), Error<B::Error>> {
+        /* 1. check the accelerometer/gyroscope data rates */
+        let sflp_hz = self.data_rate.to_hz();
+        let xl_hz = sensor.xl_data_rate_get().await?.to_hz();
+        let gy_hz = sensor.gy_data_rate_get().await?.to_hz();
+        if xl_hz < sflp_hz || gy_hz < sflp_hz {
+            return Err(Error::InvalidConfiguration);
+        }
+
+        /* 2. data rate and bias seed */
+        sensor.sflp_data_rate_set(self.data_rate).await?;
+        if let Some(gbias) = self.gbias {
+            sensor.sflp_gbias_set(gbias.to_mdps()).await?;
+        }
+
+        /* 3. enable and initialize */
+        sensor.sflp_game_rotation_enable_set(true).await?;
+        sensor.sflp_game_rotation_init_set(true).await?;
+        self.last_gbias = None;
+
+        /* 4. FIFO batching and warm-up */
+        sensor.fifo_sflp_batch_set(self.fifo.clone()).await?;
+        sensor.tim.delay_ms(self.warm_up_ms).await;
+
+        Ok(())
+    }
+
+    /// Stop the SFLP and its FIFO batching.
+    pub async fn stop<B: BusOperation, T: DelayNs>(
+        &mut self,
+        sensor: &mut Lsm6dsv320x<B, T, MainBank>,
+    ) -> Result<(), Error<B::Error>> {
+        sensor.fifo_sflp_batch_set(FifoSflpRaw::default()).await?;
+        sensor.sflp_game_rotation_enable_set(false).await?;
+        self.last_gbias = None;
+
+        Ok(())
+    }
+
+    /// Check the convergence of the gyroscope bias estimate.
+    ///
+    /// The estimate is read and compared with the one read by the previous
+    /// call: it is converged if no axis changed more than the tolerance. The
+    /// first call after `start` always returns false.
+    pub async fn converged<B: BusOperation, T: DelayNs>(
+        &mut self,
+        sensor: &mut Lsm6dsv320x<B, T, MainBank>,
+    ) -> Result<bool, Error<B::Error>> {
+        let gbias = sensor.sflp_gbias_raw_get().await?;
+        let converged = self.last_gbias.is_some_and(|last| {
+            last.iter()
+                .zip(gbias)
+                .all(|(&last, val)| last.abs_diff(val) <= self.tolerance)
+        });
+        self.last_gbias = Some(gbias);
+
+        Ok(converged)
+    }
+
+    /// Read the gyroscope bias estimate, to seed the next run.
+    pub async fn gbias_save<B: BusOperation, T: DelayNs>(
+        &self,
+        sensor: &mut Lsm6dsv320x<B, T, MainBank>,
+    ) -> Result<SflpGbiasBlob, Error<B::Error>> {
+        let raw = sensor.sflp_gbias_raw_get().await?;
+        Ok(SflpGbiasBlob { raw })
+    }
+
+    /// Reset the heading: the game rotation algorithm is initialized again,
+    /// so the game rotation vector restarts from the actual orientation.
+    pub async fn heading_reset<B: BusOperation, T: DelayNs>(
+        &self,
+        sensor: &mut Lsm6dsv320x<B, T, MainBank>,
+    ) -> Result<(), Error<B::Error>> {
+        sensor.sflp_game_rotation_init_set(true).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::*;
+
+    #[test]
+    fn gbias_round_trip() {
+        let rates = [
+            SflpDataRate::_15hz,
+            SflpDataRate::_30hz,
+            SflpDataRate::_60hz,
+            SflpDataRate::_120hz,
+            SflpDataRate::_240hz,
+            SflpDataRate::_480hz,
+        ];
+        let raw: [i16; 3] = [100, -2000, 3000];
+
+        for rate in rates {
+            let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+            block_on(sensor.sflp_data_rate_set(rate)).unwrap();
+
+            /* SFLP gbias output registers */
+            let emb = MemBank::EmbedFuncMemBank;
+            for (n, val) in raw.iter().enumerate() {
+                let [lsb, msb] = val.to_le_bytes();
+                let reg = EmbReg::SflpGbiasxL as u8 + 2 * n as u8;
+                sensor.bus.reg_set(emb, reg, lsb);
+                sensor.bus.reg_set(emb, reg + 1, msb);
+            }
+
+            let gbias = block_on(sensor.sflp_gbias_get()).unwrap();
+            assert_eq!(gbias, SflpGbiasBlob { raw }.to_mdps());
+
+            block_on(sensor.sflp_gbias_set(gbias)).unwrap();
+            let init = block_on(sensor.sflp_gbias_init_get()).unwrap();
+
+            /* the init value is a half-precision float: 11 significant bits */
+            for (val, expected) in [(init.x, gbias.x), (init.y, gbias.y), (init.z, gbias.z)] {
+                let (val, expected) = (val.value(), expected.value());
+                assert!(
+                    (val - expected).abs() <= expected.abs() / 1024.0,
+                    "{rate:?}: {val} != {expected}"
+                );
+            }
+        }
+    }
+}
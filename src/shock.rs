@@ -0,0 +1,400 @@
+use super::impact::resultant_g;
+use super::prelude::*;
+use super::{BusOperation, DelayNs, Error, Lsm6dsv320x, bisync};
+
+/// Number of FIFO words drained by `ShockRecorder::capture` with a single
+/// burst read.
+const SHOCK_READ_WORDS: usize = 32;
+
+/// Sensor of a recorded sample.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum ShockSensor {
+    /// High-g accelerometer.
+    #[default]
+    HgXl,
+    /// Low-g accelerometer.
+    Xl,
+    /// High-g accelerometer peak.
+    HgXlPeak,
+}
+
+/// Sample recorded around a shock.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct ShockSample {
+    pub sensor: ShockSensor,
+    /// Time of the sample (ns), None if no timestamp has been read yet.
+    pub time_ns: Option<u64>,
+    /// Sample (x, y, z) in LSB.
+    pub xyz: [i16; 3],
+    /// The sample is the first high-g sample above the wake-up threshold or
+    /// has been written in FIFO after it.
+    pub post_trigger: bool,
+}
+
+/// Samples recorded around a shock, see `ShockRecorder::capture`.
+#[derive(PartialEq, Debug)]
+pub struct ShockCapture<'a> {
+    samples: &'a [ShockSample],
+    pre_trigger: usize,
+    peak: Option<[i16; 3]>,
+    dropped: usize,
+    full_scale: HgXlFullScale,
+    data_rate: HgXlDataRate,
+}
+
+impl<'a> ShockCapture<'a> {
+    /// Get all the samples, in FIFO order.
+    pub fn samples(&self) -> &'a [ShockSample] {
+        self.samples
+    }
+
+    /// Get the samples written in FIFO before the trigger.
+    ///
+    /// If no high-g sample exceeds the wake-up threshold, all the samples are
+    /// returned.
+    pub fn pre_trigger(&self) -> &'a [ShockSample] {
+        &self.samples[..self.pre_trigger]
+    }
+
+    /// Get the samples written in FIFO from the trigger, i.e. from the first
+    /// high-g sample above the wake-up threshold.
+    pub fn post_trigger(&self) -> &'a [ShockSample] {
+        &self.samples[self.pre_trigger..]
+    }
+
+    /// Get the time (ns) of the trigger sample.
+    pub fn trigger_time_ns(&self) -> Option<u64> {
+        self.post_trigger()
+            .first()
+            .and_then(|sample| sample.time_ns)
+    }
+
+    /// Get the high-g peak (x, y, z) in LSB with the highest magnitude on a
+    /// single axis.
+    pub fn peak(&self) -> Option<[i16; 3]> {
+        self.peak
+    }
+
+    /// Get the high-g peak (x, y, z) in g, see `peak`.
+    pub fn peak_g(&self) -> Option<HgPeak> {
+        self.peak.map(|xyz| HgPeak::from_raw(xyz, self.full_scale))
+    }
+
+    /// Compute the impact metrics over the high-g accelerometer samples;
+    /// samples above `threshold_g` contribute to the duration and delta-V.
+    pub fn impact_metrics(&self, threshold_g: f32) -> ImpactMetrics {
+        let samples = self
+            .samples
+            .iter()
+            .filter(|sample| sample.sensor == ShockSensor::HgXl)
+            .map(|sample| resultant_g(&sample.xyz, self.full_scale));
+
+        ImpactMetrics::from_resultants(samples, self.data_rate.to_hz(), threshold_g)
+    }
+
+    /// Get the number of samples dropped because the buffer was full.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+}
+
+/// Recorder of the high-g and low-g accelerometer data around a shock.
+///
+/// The high-g wake-up is routed on an interrupt pad and the FIFO is set in
+/// continuous-to-FIFO mode: data is batched continuously, keeping the latest
+/// `FIFO_DEPTH` words, until the high-g wake-up event; the FIFO then fills up
+/// and stops. The FIFO content is split in the pre-trigger and post-trigger
+/// windows at the first high-g sample above the wake-up threshold:
+///
+/// ```rust,ignore
+/// let recorder = ShockRecorder::new(HgXlDataRate::_960hz, wake_up_cfg)
+///     .xl(FifoBatch::_960hz)
+///     .post_trigger_ms(200);
+/// recorder.arm(&mut sensor).unwrap();
+///
+/// // wait for the high-g wake-up interrupt
+///
+/// let mut buf = [ShockSample::default(); 512];
+/// let capture = recorder.capture(&mut sensor, &mut buf).unwrap();
+/// ```
+///
+/// The FIFO stops as soon as it is full, so the windows depend on how long
+/// the recorder has been armed before the event:
+/// - if the FIFO is not full yet, the pre-trigger window holds all the data
+///   batched since `arm` and the post-trigger window lasts until the FIFO is
+///   full (or `post_trigger_ms` expires);
+/// - if the FIFO is already full, it stops at the event: the capture is the
+///   last `FIFO_DEPTH` words and the post-trigger window only holds the
+///   samples between the threshold crossing and the wake-up event (see
+///   `HgWakeUpCfg::hg_shock_dur`).
+///
+/// To keep a post-trigger window, `arm` the recorder again (flushing the FIFO)
+/// before the pre-trigger history exceeds the FIFO depth. Call `arm` again to
+/// record the next shock.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShockRecorder {
+    hg_xl: HgXlDataRate,
+    hg_full_scale: HgXlFullScale,
+    wake_up: HgWakeUpCfg,
+    xl: FifoBatch,
+    timestamp: FifoTimestampBatch,
+    pad: IntPin,
+    post_trigger_ms: u32,
+}
+
+impl ShockRecorder {
+    /// Create a recorder with the high-g accelerometer at `hg_xl` (320 g full
+    /// scale) and the high-g wake-up configured with `wake_up`.
+    ///
+    /// The event is routed on INT1, the low-g accelerometer is not recorded
+    /// and the post-trigger window lasts until the FIFO is full, up to 1 s.
+    pub fn new(hg_xl: HgXlDataRate, wake_up: HgWakeUpCfg) -> Self {
+        Self {
+            hg_xl,
+            hg_full_scale: HgXlFullScale::_320g,
+            wake_up,
+            xl: FifoBatch::NotBatched,
+            timestamp: FifoTimestampBatch::Dec1,
+            pad: IntPin::Int1,
+            post_trigger_ms: 1000,
+        }
+    }
+
+    /// Set the full scale of the high-g accelerometer.
+    pub fn hg_full_scale(mut self, val: HgXlFullScale) -> Self {
+        self.hg_full_scale = val;
+        self
+    }
+
+    /// Record the low-g accelerometer batched at `val`.
+    ///
+    /// The low-g accelerometer data rate must be configured separately.
+    pub fn xl(mut self, val: FifoBatch) -> Self {
+        self.xl = val;
+        self
+    }
+
+    /// Set the decimation of the timestamp batched in FIFO.
+    ///
+    /// Timestamp is batched at the low-g accelerometer rate: if the low-g
+    /// accelerometer is not recorded, sample times are not available.
+    pub fn timestamp(mut self, val: FifoTimestampBatch) -> Self {
+        self.timestamp = val;
+        self
+    }
+
+    /// Set the pad the high-g wake-up event is routed on.
+    pub fn pad(mut self, val: IntPin) -> Self {
+        self.pad = val;
+        self
+    }
+
+    /// Set the maximum duration of the post-trigger window; the window ends
+    /// earlier if the FIFO is full.
+    pub fn post_trigger_ms(mut self, val: u32) -> Self {
+        self.post_trigger_ms = val;
+        self
+    }
+
+    /// Get the high-g wake-up threshold in mg: 1 g/LSB up to ±256 g, 1.25 g/LSB
+    /// at ±320 g.
+    fn wake_up_threshold_mg(&self) -> f32 {
+        let lsb_mg = if self.hg_full_scale == HgXlFullScale::_320g {
+            1250.0
+        } else {
+            1000.0
+        };
+        self.wake_up.hg_wakeup_ths as f32 * lsb_mg
+    }
+
+    fn fifo_plan(&self) -> FifoPlan {
+        let timestamp = if self.xl == FifoBatch::NotBatched {
+            FifoTimestampBatch::NotBatched
+        } else {
+            self.timestamp
+        };
+
+        FifoPlan::new(FifoMode::StreamToFifo)
+            .hg_xl(self.hg_xl)
+            .xl(self.xl)
+            .timestamp(timestamp)
+    }
+}
+
+#[bisync]
+impl ShockRecorder {
+    /// Configure the device and start recording.
+    ///
+    /// The high-g accelerometer data rate, full scale, wake-up and peak
+    /// tracking are configured, the high-g wake-up is added to the routing of
+    /// the pad and the FIFO is flushed and set in continuous-to-FIFO mode.
+    pub async fn arm<B: BusOperation, T: DelayNs>(
+        &self,
+        sensor: &mut Lsm6dsv320x<B, T, MainBank>,
+    ) -> Result<(), Error<B::Error>> {
+        /* 1. high-g accelerometer and wake-up */
+        sensor.hg_xl_full_scale_set(self.hg_full_scale).await?;
+        sensor.hg_xl_setup(self.hg_xl, true).await?;
+        sensor.hg_wake_up_cfg_set(self.wake_up.clone()).await?;
+        sensor
+            .hg_interrupt_config_set(HgInterruptConfig {
+                enable: true,
+                wake_up_change: false,
+            })
+            .await?;
+        sensor.xl_hg_peak_tracking_enable_set(true).await?;
+        sensor.timestamp_enable_set(true).await?;
+
+        /* 2. route the high-g wake-up, keeping the other sources */
+        let routing = sensor.interrupt_routing_get().await?;
+        let pin = routing.pin(IntSource::HgWakeUp);
+        let routing = routing.route(
+            IntSource::HgWakeUp,
+            IntPin::from_pins(pin.int1() || self.pad.int1(), pin.int2() || self.pad.int2()),
+        );
+        sensor.interrupt_routing_set(&routing).await?;
+
+        /* 3. FIFO in continuous-to-FIFO mode */
+        sensor.fifo_plan_set(&self.fifo_plan()).await
+    }
+
+    /// Wait for the end of the post-trigger window and read the samples
+    /// recorded in FIFO into `buf`.
+    ///
+    /// To be called once the high-g wake-up event has been notified. The
+    /// trigger is the first high-g sample with an axis above the wake-up
+    /// threshold, so the split does not depend on the interrupt latency.
+    /// Samples exceeding the size of `buf` are dropped.
+    pub async fn capture<'a, B: BusOperation, T: DelayNs>(
+        &self,
+        sensor: &mut Lsm6dsv320x<B, T, MainBank>,
+        buf: &'a mut [ShockSample],
+    ) -> Result<ShockCapture<'a>, Error<B::Error>> {
+        /* 1. post-trigger window */
+        let mut elapsed = 0;
+        while elapsed < self.post_trigger_ms && !sensor.fifo_state_get().await?.full {
+            sensor.tim.delay_ms(1).await;
+            elapsed += 1;
+        }
+
+        /* 2. drain and decode the FIFO, looking for the trigger */
+        let threshold_mg = self.wake_up_threshold_mg();
+        let mut aligner = sensor.fifo_time_aligner_get().await?;
+        let mut raw = [0u8; SHOCK_READ_WORDS * FIFO_WORD_SIZE];
+        let mut triggered = false;
+        let mut len = 0;
+        let mut pre_trigger = 0;
+        let mut dropped = 0;
+        let mut peak: Option<[i16; 3]> = None;
+
+        loop {
+            let num = sensor.fifo_read_batch_raw(&mut raw).await?;
+            if num == 0 {
+                break;
+            }
+
+            for bytes in raw[..num * FIFO_WORD_SIZE].chunks_exact(FIFO_WORD_SIZE) {
+                let Some(out) = FifoOutRaw::from_bytes(bytes) else {
+                    continue;
+                };
+                let time_ns = aligner.push(&out);
+                let (kind, xyz) = match FifoFrame::from(&out) {
+                    FifoFrame::XlHg { xyz, .. } => (ShockSensor::HgXl, xyz),
+                    FifoFrame::XlNc { xyz, .. } => (ShockSensor::Xl, xyz),
+                    FifoFrame::HgXlPeak { xyz, .. } => {
+                        if peak.is_none_or(|peak| max_abs(&xyz) > max_abs(&peak)) {
+                            peak = Some(xyz);
+                        }
+                        (ShockSensor::HgXlPeak, xyz)
+                    }
+                    _ => continue,
+                };
+                if kind == ShockSensor::HgXl && !triggered {
+                    triggered = xyz
+                        .iter()
+                        .any(|&lsb| libm::fabsf(self.hg_full_scale.to_mg(lsb)) > threshold_mg);
+                }
+                let post_trigger = triggered;
+
+                if len == buf.len() {
+                    dropped += 1;
+                    continue;
+                }
+
+                buf[len] = ShockSample {
+                    sensor: kind,
+                    time_ns,
+                    xyz,
+                    post_trigger,
+                };
+                len += 1;
+                if !post_trigger {
+                    pre_trigger = len;
+                }
+            }
+        }
+
+        Ok(ShockCapture {
+            samples: &buf[..len],
+            pre_trigger,
+            peak,
+            dropped,
+            full_scale: self.hg_full_scale,
+            data_rate: self.hg_xl,
+        })
+    }
+}
+
+fn max_abs(xyz: &[i16; 3]) -> u16 {
+    xyz.iter().map(|val| val.unsigned_abs()).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Lsm6dsv320x;
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::*;
+
+    fn hg_word(cnt: u8, xyz: [i16; 3]) -> FifoOutRaw {
+        let mut data = [0; 6];
+        for (bytes, val) in data.chunks_exact_mut(2).zip(xyz) {
+            bytes.copy_from_slice(&val.to_le_bytes());
+        }
+        FifoOutRaw {
+            tag: Tag::XlHg,
+            cnt,
+            data,
+        }
+    }
+
+    #[test]
+    fn trigger_at_threshold_crossing() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+        let wake_up = HgWakeUpCfg {
+            hg_wakeup_ths: 10,
+            hg_shock_dur: 0,
+        };
+        let recorder = ShockRecorder::new(HgXlDataRate::_960hz, wake_up)
+            .hg_full_scale(HgXlFullScale::_32g)
+            .post_trigger_ms(0);
+        block_on(recorder.arm(&mut sensor)).unwrap();
+
+        // ~1 g, ~2 g, ~11.7 g (trigger), ~2 g
+        let words = [[1024, 0, 0], [0, 2048, 0], [0, 0, -12000], [2048, 0, 0]];
+        for (cnt, &xyz) in words.iter().enumerate() {
+            assert!(sensor.bus.fifo_push_raw(&hg_word(cnt as u8, xyz)));
+        }
+
+        let mut buf = [ShockSample::default(); 8];
+        let capture = block_on(recorder.capture(&mut sensor, &mut buf)).unwrap();
+        assert_eq!(capture.samples().len(), 4);
+        assert_eq!(capture.pre_trigger().len(), 2);
+        assert_eq!(capture.post_trigger()[0].xyz, [0, 0, -12000]);
+        assert!(
+            capture
+                .post_trigger()
+                .iter()
+                .all(|sample| sample.post_trigger)
+        );
+    }
+}
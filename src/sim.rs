@@ -0,0 +1,806 @@
+use core::convert::Infallible;
+
+use super::prelude::*;
+use super::{BusOperation, DelayNs, ID, bisync};
+#[cfg(test)]
+use super::{only_async, only_sync};
+
+/// Number of advanced pages modeled by the simulator.
+pub const SIM_PAGES: usize = 16;
+
+/// Number of words the simulated FIFO can hold.
+pub const SIM_FIFO_DEPTH: usize = 512;
+
+/// Register-level simulator of the device.
+///
+/// It implements `BusOperation`, so it can be given to `Lsm6dsv320x::from_bus`
+/// to run the driver without hardware. The simulator models:
+/// - the main, embedded functions and sensor hub register banks, selected
+///   through FUNC_CFG_ACCESS (0x01);
+/// - the advanced pages, accessed through PAGE_SEL (0x02), PAGE_ADDRESS (0x08),
+///   PAGE_VALUE (0x09) and PAGE_RW (0x17) of the embedded functions bank;
+/// - the FIFO, filled by the test with `fifo_push` and read back from
+///   FIFO_DATA_OUT_TAG (0x78), with address roll back after 0x7E;
+/// - software reset, reboot and the read-only WHO_AM_I register.
+///
+/// Registers have no other side effects: the value written is the value read.
+pub struct Simulator {
+    main: [u8; 256],
+    embedded: [u8; 256],
+    sensor_hub: [u8; 256],
+    advanced: [[u8; 256]; SIM_PAGES],
+    fifo: [[u8; FIFO_WORD_SIZE]; SIM_FIFO_DEPTH],
+    fifo_head: usize,
+    fifo_len: usize,
+    fifo_out: [u8; FIFO_WORD_SIZE],
+    address: u8,
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Simulator {
+    /// Create a simulator with the registers at their power-on value.
+    pub fn new() -> Self {
+        let mut sim = Self {
+            main: [0; 256],
+            embedded: [0; 256],
+            sensor_hub: [0; 256],
+            advanced: [[0; 256]; SIM_PAGES],
+            fifo: [[0; FIFO_WORD_SIZE]; SIM_FIFO_DEPTH],
+            fifo_head: 0,
+            fifo_len: 0,
+            fifo_out: [0; FIFO_WORD_SIZE],
+            address: 0,
+        };
+        sim.reset();
+        sim
+    }
+
+    /// Restore the registers of all the banks to their power-on value and
+    /// empty the FIFO. Advanced pages are preserved.
+    pub fn reset(&mut self) {
+        self.main = [0; 256];
+        self.embedded = [0; 256];
+        self.sensor_hub = [0; 256];
+        self.main[Reg::WhoAmI as usize] = ID;
+        self.main[Reg::Ctrl3 as usize] = Ctrl3::new().into_bits();
+        self.embedded[EmbReg::PageSel as usize] = PageSel::new().into_bits();
+        self.fifo_clear();
+    }
+
+    /// Get a register value without side effects.
+    pub fn reg_get(&self, bank: MemBank, reg: u8) -> u8 {
+        match bank {
+            MemBank::MainMemBank => self.main[reg as usize],
+            MemBank::EmbedFuncMemBank => self.embedded[reg as usize],
+            MemBank::SensorHubMemBank => self.sensor_hub[reg as usize],
+        }
+    }
+
+    /// Set a register value without side effects.
+    pub fn reg_set(&mut self, bank: MemBank, reg: u8, val: u8) {
+        match bank {
+            MemBank::MainMemBank => self.main[reg as usize] = val,
+            MemBank::EmbedFuncMemBank => self.embedded[reg as usize] = val,
+            MemBank::SensorHubMemBank => self.sensor_hub[reg as usize] = val,
+        }
+    }
+
+    /// Get the value at an advanced page address (page << 8 | address).
+    pub fn page_get(&self, address: u16) -> u8 {
+        self.advanced[(address >> 8) as usize % SIM_PAGES][(address & 0xFF) as usize]
+    }
+
+    /// Set the value at an advanced page address (page << 8 | address).
+    pub fn page_set(&mut self, address: u16, val: u8) {
+        self.advanced[(address >> 8) as usize % SIM_PAGES][(address & 0xFF) as usize] = val;
+    }
+
+    /// Append a word (tag + 6 data bytes) to the FIFO.
+    ///
+    /// Returns false if the FIFO is full and the word has been dropped.
+    pub fn fifo_push(&mut self, word: &[u8; FIFO_WORD_SIZE]) -> bool {
+        if self.fifo_len == SIM_FIFO_DEPTH {
+            return false;
+        }
+
+        self.fifo[(self.fifo_head + self.fifo_len) % SIM_FIFO_DEPTH] = *word;
+        self.fifo_len += 1;
+        true
+    }
+
+    /// Append a raw word to the FIFO.
+    ///
+    /// Returns false if the FIFO is full and the word has been dropped.
+    pub fn fifo_push_raw(&mut self, raw: &FifoOutRaw) -> bool {
+        let tag = FifoDataOutTag::new()
+            .with_tag_sensor(raw.tag as u8)
+            .with_tag_cnt(raw.cnt)
+            .into_bits();
+
+        let mut word = [0; FIFO_WORD_SIZE];
+        word[0] = tag;
+        word[1..].copy_from_slice(&raw.data);
+        self.fifo_push(&word)
+    }
+
+    /// Get the number of words in the FIFO.
+    pub fn fifo_len(&self) -> usize {
+        self.fifo_len
+    }
+
+    /// Remove all the words from the FIFO.
+    pub fn fifo_clear(&mut self) {
+        self.fifo_head = 0;
+        self.fifo_len = 0;
+        self.fifo_out = [0; FIFO_WORD_SIZE];
+    }
+
+    fn fifo_pop(&mut self) -> [u8; FIFO_WORD_SIZE] {
+        if self.fifo_len == 0 {
+            return [0; FIFO_WORD_SIZE];
+        }
+
+        let word = self.fifo[self.fifo_head];
+        self.fifo_head = (self.fifo_head + 1) % SIM_FIFO_DEPTH;
+        self.fifo_len -= 1;
+        word
+    }
+
+    /// FIFO_STATUS1/2 with DIFF_FIFO set to the words in the FIFO and the
+    /// flags as set with `reg_set`.
+    fn fifo_status(&self) -> [u8; 2] {
+        let status = FifoStatusReg::from_bits(u16::from_le_bytes([
+            self.main[Reg::FifoStatus1 as usize],
+            self.main[Reg::FifoStatus2 as usize],
+        ]));
+        status
+            .with_diff_fifo(self.fifo_len as u16)
+            .into_bits()
+            .to_le_bytes()
+    }
+
+    fn bank(&self) -> MemBank {
+        let func_cfg_access = FuncCfgAccess::from_bits(self.main[Reg::FuncCfgAccess as usize]);
+        if func_cfg_access.emb_func_reg_access() == 1 {
+            MemBank::EmbedFuncMemBank
+        } else if func_cfg_access.shub_reg_access() == 1 {
+            MemBank::SensorHubMemBank
+        } else {
+            MemBank::MainMemBank
+        }
+    }
+
+    fn page_cursor(&self) -> u16 {
+        let page_sel = PageSel::from_bits(self.embedded[EmbReg::PageSel as usize]);
+        ((page_sel.page_sel() as u16) << 8) | self.embedded[EmbReg::PageAddress as usize] as u16
+    }
+
+    fn page_cursor_inc(&mut self) {
+        let addr = &mut self.embedded[EmbReg::PageAddress as usize];
+        *addr = addr.wrapping_add(1);
+    }
+
+    fn read_byte(&mut self, reg: u8) -> u8 {
+        if reg == Reg::FuncCfgAccess as u8 {
+            return self.main[reg as usize];
+        }
+
+        match self.bank() {
+            MemBank::MainMemBank => match reg {
+                r if r == Reg::FifoStatus1 as u8 => self.fifo_status()[0],
+                r if r == Reg::FifoStatus2 as u8 => self.fifo_status()[1],
+                r if r == Reg::FifoDataOutTag as u8 => {
+                    self.fifo_out = self.fifo_pop();
+                    self.fifo_out[0]
+                }
+                r if r > Reg::FifoDataOutTag as u8 && r <= Reg::FifoDataOutZH as u8 => {
+                    self.fifo_out[(r - Reg::FifoDataOutTag as u8) as usize]
+                }
+                _ => self.main[reg as usize],
+            },
+            MemBank::EmbedFuncMemBank => {
+                let page_rw = PageRw::from_bits(self.embedded[EmbReg::PageRw as usize]);
+                if reg == EmbReg::PageValue as u8 && page_rw.page_read() == 1 {
+                    let val = self.page_get(self.page_cursor());
+                    self.page_cursor_inc();
+                    val
+                } else {
+                    self.embedded[reg as usize]
+                }
+            }
+            MemBank::SensorHubMemBank => self.sensor_hub[reg as usize],
+        }
+    }
+
+    fn write_byte(&mut self, reg: u8, val: u8) {
+        if reg == Reg::FuncCfgAccess as u8 {
+            let func_cfg_access = FuncCfgAccess::from_bits(val);
+            if func_cfg_access.sw_por() == 1 {
+                self.reset();
+            } else {
+                self.main[reg as usize] = val;
+            }
+            return;
+        }
+
+        match self.bank() {
+            MemBank::MainMemBank => match reg {
+                r if r == Reg::WhoAmI as u8 => {}
+                r if r == Reg::Ctrl3 as u8 => {
+                    let ctrl3 = Ctrl3::from_bits(val);
+                    if ctrl3.sw_reset() == 1 {
+                        self.reset();
+                    } else {
+                        self.main[reg as usize] = ctrl3.with_boot(0).into_bits();
+                    }
+                }
+                _ => self.main[reg as usize] = val,
+            },
+            MemBank::EmbedFuncMemBank => {
+                let page_rw = PageRw::from_bits(self.embedded[EmbReg::PageRw as usize]);
+                if reg == EmbReg::PageValue as u8 && page_rw.page_write() == 1 {
+                    self.page_set(self.page_cursor(), val);
+                    self.page_cursor_inc();
+                }
+                self.embedded[reg as usize] = val;
+            }
+            MemBank::SensorHubMemBank => self.sensor_hub[reg as usize] = val,
+        }
+    }
+
+    fn next_address(&self, reg: u8) -> u8 {
+        if self.bank() == MemBank::MainMemBank && reg == Reg::FifoDataOutZH as u8 {
+            Reg::FifoDataOutTag as u8
+        } else {
+            reg.wrapping_add(1)
+        }
+    }
+
+    fn read(&mut self, rbuf: &mut [u8]) {
+        for byte in rbuf.iter_mut() {
+            *byte = self.read_byte(self.address);
+            self.address = self.next_address(self.address);
+        }
+    }
+
+    fn write(&mut self, wbuf: &[u8]) {
+        for &byte in wbuf {
+            self.write_byte(self.address, byte);
+            self.address = self.address.wrapping_add(1);
+        }
+    }
+}
+
+#[bisync]
+impl BusOperation for Simulator {
+    type Error = Infallible;
+
+    async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        self.read(rbuf);
+        Ok(())
+    }
+
+    async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        if let Some((&reg, data)) = wbuf.split_first() {
+            self.address = reg;
+            self.write(data);
+        }
+        Ok(())
+    }
+
+    async fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.address = wbuf[0];
+        self.read(rbuf);
+        Ok(())
+    }
+}
+
+/// DelayNs implementation returning immediately, to be used with `Simulator`.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct NoDelay;
+
+#[bisync]
+impl DelayNs for NoDelay {
+    async fn delay_ns(&mut self, _ns: u32) {}
+}
+
+/// Run a driver call to completion in tests.
+///
+/// The simulator never blocks, so the future is ready at the first poll.
+#[cfg(test)]
+#[only_async]
+pub(crate) fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+    let mut fut = core::pin::pin!(fut);
+    let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    loop {
+        if let core::task::Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
+
+/// Run a driver call to completion in tests.
+#[cfg(test)]
+#[only_sync]
+pub(crate) fn block_on<O>(out: O) -> O {
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        EmbAdvFunctions, Error, InterruptSources, Lsm6dsv320x, MemBankFunctions, SensorOperation,
+    };
+    use super::*;
+
+    fn sensor() -> Lsm6dsv320x<Simulator, NoDelay, MainBank> {
+        Lsm6dsv320x::from_bus(Simulator::new(), NoDelay)
+    }
+
+    #[test]
+    fn who_am_i() {
+        let mut sensor = sensor();
+        assert_eq!(block_on(sensor.device_id_get()).unwrap(), ID);
+
+        block_on(sensor.write_to_register(Reg::WhoAmI as u8, &[0x00])).unwrap();
+        assert_eq!(block_on(sensor.device_id_get()).unwrap(), ID);
+    }
+
+    #[test]
+    fn register_banks() {
+        let mut sensor = sensor();
+        let banks = [
+            (MemBank::MainMemBank, 0x11),
+            (MemBank::EmbedFuncMemBank, 0x22),
+            (MemBank::SensorHubMemBank, 0x33),
+        ];
+
+        for (bank, val) in banks {
+            block_on(sensor.mem_bank_set(bank)).unwrap();
+            assert_eq!(block_on(sensor.mem_bank_get()).unwrap(), bank);
+            block_on(sensor.write_to_register(0x10, &[val, val + 1])).unwrap();
+        }
+        block_on(sensor.mem_bank_set(MemBank::MainMemBank)).unwrap();
+
+        for (bank, val) in banks {
+            assert_eq!(sensor.bus.reg_get(bank, 0x10), val);
+            assert_eq!(sensor.bus.reg_get(bank, 0x11), val + 1);
+
+            block_on(sensor.mem_bank_set(bank)).unwrap();
+            let mut buf = [0; 2];
+            block_on(sensor.read_from_register(0x10, &mut buf)).unwrap();
+            assert_eq!(buf, [val, val + 1]);
+        }
+    }
+
+    #[test]
+    fn advanced_pages() {
+        let mut sensor = sensor();
+        let data = [0xA0, 0xA1, 0xA2, 0xA3];
+
+        // Crosses the page boundary: 0x1FE - 0x1FF, 0x200 - 0x201
+        block_on(sensor.ln_pg_write(0x1FE, &data, 4)).unwrap();
+        assert_eq!(sensor.bus.page_get(0x1FE), 0xA0);
+        assert_eq!(sensor.bus.page_get(0x1FF), 0xA1);
+        assert_eq!(sensor.bus.page_get(0x200), 0xA2);
+        assert_eq!(sensor.bus.page_get(0x201), 0xA3);
+        assert_eq!(sensor.bus.page_get(0x100), 0x00);
+
+        let mut buf = [0; 4];
+        block_on(sensor.ln_pg_read(0x1FE, &mut buf, 4)).unwrap();
+        assert_eq!(buf, data);
+
+        // Page selection and main bank are restored
+        assert_eq!(
+            PageSel::from_bits(
+                sensor
+                    .bus
+                    .reg_get(MemBank::EmbedFuncMemBank, EmbReg::PageSel as u8)
+            )
+            .page_sel(),
+            0
+        );
+        assert_eq!(
+            block_on(sensor.mem_bank_get()).unwrap(),
+            MemBank::MainMemBank
+        );
+    }
+
+    #[test]
+    fn fifo_drain() {
+        let mut sensor = sensor();
+        let words = [
+            [0x10, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06],
+            [0x0A, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16],
+            [0x24, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26],
+        ];
+        for word in &words {
+            assert!(sensor.bus.fifo_push(word));
+        }
+
+        // Single burst over the three words: 0x7E rolls back to 0x78
+        let mut buf = [0; 4 * FIFO_WORD_SIZE];
+        assert_eq!(block_on(sensor.fifo_read_batch_raw(&mut buf)).unwrap(), 3);
+        for (chunk, word) in buf.chunks_exact(FIFO_WORD_SIZE).zip(&words) {
+            assert_eq!(chunk, word);
+        }
+        assert_eq!(sensor.bus.fifo_len(), 0);
+        assert_eq!(block_on(sensor.fifo_state_get()).unwrap().level, 0);
+    }
+
+    #[test]
+    fn device_config_round_trip() {
+        let mut sensor = sensor();
+        let config = DeviceConfig {
+            xl_odr: DataRate::_120hz,
+            xl_mode: XlMode::HighPerformance,
+            xl_full_scale: XlFullScale::_4g,
+            gy_odr: DataRate::_240hz,
+            gy_mode: GyMode::HighPerformance,
+            gy_full_scale: GyFullScale::_1000dps,
+            hg_xl_odr: HgXlDataRate::_960hz,
+            hg_xl_reg_out_en: true,
+            hg_xl_full_scale: HgXlFullScale::_64g,
+            block_data_update: true,
+            timestamp: true,
+            filt_gy_lp1: true,
+            filt_gy_lp1_bandwidth: FiltLpBandwidth::Medium,
+            filt_xl_lp2: true,
+            filt_xl_lp2_bandwidth: FiltLpBandwidth::Strong,
+            fifo_mode: FifoMode::Stream,
+            fifo_watermark: 64,
+            fifo_compress_algo: FifoCompressAlgo::_8To1,
+            fifo_xl_batch: FifoBatch::_120hz,
+            fifo_gy_batch: FifoBatch::_240hz,
+            fifo_hg_xl_batch: true,
+            fifo_temp_batch: FifoTempBatch::_15hz,
+            fifo_timestamp_batch: FifoTimestampBatch::Dec8,
+            fifo_sflp_batch: FifoSflpRaw {
+                game_rotation: 1,
+                gravity: 0,
+                gbias: 1,
+            },
+            fifo_stpcnt_batch: true,
+            pin_int1_route: Int1Route::FIFO_TH | Int1Route::WAKEUP,
+            pin_int2_route: Int2Route::DRDY_TEMP | Int2Route::EMB_FUNC_ENDOP,
+            pin_int1_route_hg: IntRouteHg::HG_WAKEUP,
+            pin_int2_route_hg: IntRouteHg::DRDY_HG_XL | IntRouteHg::HG_SHOCK_CHANGE,
+            pin_int1_route_embedded: IntRouteEmb::STEP_DETECTOR | IntRouteEmb::FSM3,
+            pin_int2_route_embedded: IntRouteEmb::MLC8,
+        };
+
+        block_on(sensor.device_config_set(&config)).unwrap();
+        let read = block_on(sensor.device_config_get()).unwrap();
+        assert_eq!(read.diff(&config).next(), None);
+        assert_eq!(read, config);
+
+        // Back to the reset configuration
+        block_on(sensor.device_config_set(&DeviceConfig::default())).unwrap();
+        let read = block_on(sensor.device_config_get()).unwrap();
+        assert_eq!(read, DeviceConfig::default());
+    }
+
+    #[test]
+    fn all_sources_decode() {
+        let mut sensor = sensor();
+        let main = MemBank::MainMemBank;
+        let emb = MemBank::EmbedFuncMemBank;
+
+        let fifo_status = FifoStatusReg::new()
+            .with_fifo_ovr_ia(1)
+            .with_fifo_wtm_ia(1)
+            .into_bits()
+            .to_le_bytes();
+        sensor
+            .bus
+            .reg_set(main, Reg::FifoStatus1 as u8, fifo_status[0]);
+        sensor
+            .bus
+            .reg_set(main, Reg::FifoStatus2 as u8, fifo_status[1]);
+        let main_regs = [
+            (
+                Reg::AllIntSrc,
+                AllIntSrc::new().with_hg_ia(1).with_d6d_ia(1).into_bits(),
+            ),
+            (
+                Reg::StatusReg,
+                StatusReg::new()
+                    .with_xlda(1)
+                    .with_timestamp_endcount(1)
+                    .into_bits(),
+            ),
+            (
+                Reg::UiStatusRegOis,
+                UiStatusRegOis::new().with_gyro_settling(1).into_bits(),
+            ),
+            (
+                Reg::WakeUpSrc,
+                WakeUpSrc::new()
+                    .with_sleep_change_ia(1)
+                    .with_y_wu(1)
+                    .into_bits(),
+            ),
+            (
+                Reg::TapSrc,
+                TapSrc::new().with_z_tap(1).with_double_tap(1).into_bits(),
+            ),
+            (Reg::D6dSrc, D6dSrc::new().with_xh(1).into_bits()),
+            (
+                Reg::HgWakeUpSrc,
+                HgWakeUpSrc::new()
+                    .with_hg_wu_change_ia(1)
+                    .with_hg_x_wu(1)
+                    .into_bits(),
+            ),
+        ];
+        for (reg, val) in main_regs {
+            sensor.bus.reg_set(main, reg as u8, val);
+        }
+        let emb_regs = [
+            (
+                EmbReg::EmbFuncExecStatus,
+                EmbFuncExecStatus::new().with_emb_func_endop(1).into_bits(),
+            ),
+            (
+                EmbReg::EmbFuncStatus,
+                EmbFuncStatus::new().with_is_tilt(1).into_bits(),
+            ),
+            (
+                EmbReg::FsmStatus,
+                FsmStatus::new().with_is_fsm3(1).into_bits(),
+            ),
+            (
+                EmbReg::MlcStatus,
+                MlcStatus::new().with_is_mlc8(1).into_bits(),
+            ),
+            (
+                EmbReg::EmbFuncSrc,
+                EmbFuncSrc::new().with_step_overflow(1).into_bits(),
+            ),
+        ];
+        for (reg, val) in emb_regs {
+            sensor.bus.reg_set(emb, reg as u8, val);
+        }
+
+        let expected = InterruptSources {
+            fifo_ovr: true,
+            fifo_th: true,
+            hg: true,
+            six_d: true,
+            drdy_xl: true,
+            timestamp: true,
+            gy_settling: true,
+            sleep_change: true,
+            wake_up_y: true,
+            tap_z: true,
+            double_tap: true,
+            six_d_xh: true,
+            hg_wake_up_change: true,
+            hg_wake_up_x: true,
+            emb_func_stand_by: true,
+            tilt: true,
+            fsm3: true,
+            mlc8: true,
+            step_count_overflow: true,
+            ..Default::default()
+        };
+        assert_eq!(block_on(sensor.interrupt_sources_get()).unwrap(), expected);
+
+        #[allow(deprecated)]
+        let sources = block_on(sensor.all_sources_get()).unwrap();
+        assert_eq!((sources.fifo_ovr, sources.fifo_full), (1, 0));
+        assert_eq!((sources.tap_z, sources.mlc8, sources.mlc7), (1, 1, 0));
+
+        // Latched interrupts reset is enabled again after the burst read
+        let functions_enable = sensor.bus.reg_get(main, Reg::FunctionsEnable as u8);
+        assert_eq!(
+            FunctionsEnable::from_bits(functions_enable).dis_rst_lir_all_int(),
+            0
+        );
+        assert_eq!(block_on(sensor.mem_bank_get()).unwrap(), main);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn hg_event_status_decode() {
+        let mut sensor = sensor();
+        let main = MemBank::MainMemBank;
+        let all_int_src = AllIntSrc::new().with_hg_ia(1).into_bits();
+        sensor.bus.reg_set(main, Reg::AllIntSrc as u8, all_int_src);
+        let hg_wake_up_src = HgWakeUpSrc::new()
+            .with_hg_wu_ia(1)
+            .with_hg_z_wu(1)
+            .with_hg_shock_change_ia(1)
+            .into_bits();
+        sensor
+            .bus
+            .reg_set(main, Reg::HgWakeUpSrc as u8, hg_wake_up_src);
+
+        let status = block_on(sensor.hg_event_status_get()).unwrap();
+        assert_eq!(
+            status,
+            HgEventStatus {
+                event: true,
+                wake_up: true,
+                wake_up_axes: Axes {
+                    x: false,
+                    y: false,
+                    z: true,
+                },
+                wake_up_change: false,
+                shock: false,
+                shock_change: true,
+            }
+        );
+
+        let event = block_on(sensor.hg_event_get()).unwrap();
+        assert_eq!(
+            (event.hg_event, event.hg_wakeup, event.hg_wakeup_z),
+            (1, 1, 1)
+        );
+        assert_eq!((event.hg_wakeup_chg, event.hg_shock_change), (0, 1));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn hg_interrupt_config_u8_api() {
+        let mut sensor = sensor();
+        let cfg = HgWuInterruptCfg {
+            hg_interrupts_enable: 1,
+            hg_wakeup_int_sel: 1,
+        };
+        block_on(sensor.hg_wu_interrupt_cfg_set(cfg.clone())).unwrap();
+        assert_eq!(
+            block_on(sensor.hg_interrupt_config_get()).unwrap(),
+            HgInterruptConfig {
+                enable: true,
+                wake_up_change: true,
+            }
+        );
+        assert_eq!(block_on(sensor.hg_wu_interrupt_cfg_get()).unwrap(), cfg);
+
+        block_on(sensor.hg_interrupt_config_set(HgInterruptConfig {
+            enable: true,
+            wake_up_change: false,
+        }))
+        .unwrap();
+        assert_eq!(
+            block_on(sensor.hg_wu_interrupt_cfg_get()).unwrap(),
+            HgWuInterruptCfg {
+                hg_interrupts_enable: 1,
+                hg_wakeup_int_sel: 0,
+            }
+        );
+    }
+
+    /// Simulator adding a stimulus to the accelerometer output, with the sign
+    /// selected by the ST_XL bits of CTRL10.
+    struct SelfTestBus {
+        sim: Simulator,
+        stimulus: [i16; 3],
+    }
+
+    #[bisync]
+    impl BusOperation for SelfTestBus {
+        type Error = Infallible;
+
+        async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+            self.sim.read_bytes(rbuf).await
+        }
+
+        async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+            self.sim.write_bytes(wbuf).await
+        }
+
+        async fn write_byte_read_bytes(
+            &mut self,
+            wbuf: &[u8; 1],
+            rbuf: &mut [u8],
+        ) -> Result<(), Self::Error> {
+            self.sim.write_byte_read_bytes(wbuf, rbuf).await?;
+            if wbuf[0] == Reg::OutxLA as u8 {
+                let ctrl10 =
+                    Ctrl10::from_bits(self.sim.reg_get(MemBank::MainMemBank, Reg::Ctrl10 as u8));
+                let sign = match SelfTest::try_from(ctrl10.st_xl()) {
+                    Ok(SelfTest::Positive) => 1,
+                    Ok(SelfTest::Negative) => -1,
+                    _ => 0,
+                };
+                for (out, stimulus) in rbuf.chunks_exact_mut(2).zip(self.stimulus) {
+                    let val = i16::from_le_bytes([out[0], out[1]]) + sign * stimulus;
+                    out.copy_from_slice(&val.to_le_bytes());
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn self_test_sensor(stimulus: [i16; 3]) -> Lsm6dsv320x<SelfTestBus, NoDelay, MainBank> {
+        let mut sim = Simulator::new();
+        let status = StatusReg::new().with_xlda(1);
+        sim.reg_set(
+            MemBank::MainMemBank,
+            Reg::StatusReg as u8,
+            status.into_bits(),
+        );
+        for (n, val) in [100i16, -200, 4096].into_iter().enumerate() {
+            let [lo, hi] = val.to_le_bytes();
+            sim.reg_set(MemBank::MainMemBank, Reg::OutxLA as u8 + 2 * n as u8, lo);
+            sim.reg_set(
+                MemBank::MainMemBank,
+                Reg::OutxLA as u8 + 2 * n as u8 + 1,
+                hi,
+            );
+        }
+
+        let mut sensor = Lsm6dsv320x::from_bus(SelfTestBus { sim, stimulus }, NoDelay);
+        block_on(sensor.xl_full_scale_set(XlFullScale::_2g)).unwrap();
+        block_on(sensor.xl_setup(DataRate::_120hz, XlMode::Normal)).unwrap();
+        sensor
+    }
+
+    fn assert_xl_restored(sensor: &mut Lsm6dsv320x<SelfTestBus, NoDelay, MainBank>) {
+        assert_eq!(
+            block_on(sensor.xl_data_rate_get()).unwrap(),
+            DataRate::_120hz
+        );
+        assert_eq!(block_on(sensor.xl_mode_get()).unwrap(), XlMode::Normal);
+        assert_eq!(
+            block_on(sensor.xl_full_scale_get()).unwrap(),
+            XlFullScale::_2g
+        );
+        assert_eq!(
+            block_on(sensor.xl_self_test_get()).unwrap(),
+            SelfTest::Disable
+        );
+    }
+
+    #[test]
+    fn xl_self_test_pass() {
+        let stimulus = [4098, 2049, 6000];
+        let mut sensor = self_test_sensor(stimulus);
+
+        let report = block_on(sensor.xl_self_test_run()).unwrap();
+        assert_eq!(report.pass, [true; 3]);
+        for ((pos, neg), lsb) in report.positive.iter().zip(report.negative).zip(stimulus) {
+            let mg = XlFullScale::_8g.to_mg(lsb);
+            assert!((pos - mg).abs() < 0.01, "{pos} != {mg}");
+            assert!((neg - mg).abs() < 0.01, "{neg} != {mg}");
+        }
+        assert_xl_restored(&mut sensor);
+    }
+
+    #[test]
+    fn xl_self_test_limits() {
+        // 0 mg on Y is below XL_SELF_TEST_MIN_MG, 1952 mg on Z above XL_SELF_TEST_MAX_MG
+        let mut sensor = self_test_sensor([4098, 0, 8000]);
+
+        let report = block_on(sensor.xl_self_test_run()).unwrap();
+        assert_eq!(report.pass, [true, false, false]);
+        assert_xl_restored(&mut sensor);
+    }
+
+    #[test]
+    fn xl_self_test_no_data_ready() {
+        let mut sensor = self_test_sensor([4098, 4098, 4098]);
+        sensor
+            .bus
+            .sim
+            .reg_set(MemBank::MainMemBank, Reg::StatusReg as u8, 0);
+
+        assert!(matches!(
+            block_on(sensor.xl_self_test_run()),
+            Err(Error::HwNoResponse)
+        ));
+        assert_xl_restored(&mut sensor);
+    }
+}
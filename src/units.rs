@@ -0,0 +1,408 @@
+use core::marker::PhantomData;
+
+use super::prelude::*;
+
+/// Standard gravity in m/s².
+pub const STANDARD_GRAVITY: f32 = 9.806_65;
+
+/// Milli-g, unit of acceleration.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct MilliG;
+
+/// Meters per second squared, unit of acceleration.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct MetersPerSecondSquared;
+
+/// Milli-degrees per second, unit of angular rate.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct MilliDps;
+
+/// Radians per second, unit of angular rate.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct RadiansPerSecond;
+
+/// Degree Celsius, unit of temperature.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct Celsius;
+
+/// Acceleration expressed in the unit `U`.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct Acceleration<U> {
+    value: f32,
+    _unit: PhantomData<U>,
+}
+
+impl<U> Acceleration<U> {
+    pub const fn new(value: f32) -> Self {
+        Self {
+            value,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Get the value in the unit `U`.
+    pub const fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+impl Acceleration<MilliG> {
+    /// Convert to m/s².
+    pub fn to_mps2(self) -> Acceleration<MetersPerSecondSquared> {
+        Acceleration::new(self.value * STANDARD_GRAVITY / 1000.0)
+    }
+}
+
+impl Acceleration<MetersPerSecondSquared> {
+    /// Convert to mg.
+    pub fn to_mg(self) -> Acceleration<MilliG> {
+        Acceleration::new(self.value * 1000.0 / STANDARD_GRAVITY)
+    }
+}
+
+/// Angular rate expressed in the unit `U`.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct AngularRate<U> {
+    value: f32,
+    _unit: PhantomData<U>,
+}
+
+impl<U> AngularRate<U> {
+    pub const fn new(value: f32) -> Self {
+        Self {
+            value,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Get the value in the unit `U`.
+    pub const fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+impl Acceleration<MilliG> {
+    /// Convert a raw SFLP gravity sample.
+    pub fn from_gravity_lsb(lsb: i16) -> Self {
+        Self::new(super::from_gravity_lsb_to_mg(lsb))
+    }
+}
+
+impl AngularRate<MilliDps> {
+    /// Convert a raw SFLP gyroscope bias sample.
+    pub fn from_gbias_lsb(lsb: i16) -> Self {
+        Self::new(super::from_gbias_lsb_to_mdps(lsb))
+    }
+
+    /// Convert to rad/s.
+    pub fn to_rad_s(self) -> AngularRate<RadiansPerSecond> {
+        AngularRate::new(self.value.to_radians() / 1000.0)
+    }
+}
+
+impl AngularRate<RadiansPerSecond> {
+    /// Convert to mdps.
+    pub fn to_mdps(self) -> AngularRate<MilliDps> {
+        AngularRate::new(self.value.to_degrees() * 1000.0)
+    }
+}
+
+/// Temperature expressed in the unit `U`.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct Temperature<U> {
+    value: f32,
+    _unit: PhantomData<U>,
+}
+
+impl<U> Temperature<U> {
+    pub const fn new(value: f32) -> Self {
+        Self {
+            value,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Get the value in the unit `U`.
+    pub const fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+impl Temperature<Celsius> {
+    /// Convert a raw temperature sample.
+    pub fn from_lsb(lsb: i16) -> Self {
+        Self::new(super::from_lsb_to_celsius(lsb))
+    }
+}
+
+/// 3-axis quantity.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct Vector3<Q> {
+    pub x: Q,
+    pub y: Q,
+    pub z: Q,
+}
+
+impl<Q> Vector3<Q> {
+    pub const fn new(x: Q, y: Q, z: Q) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Apply `f` to each axis.
+    pub fn map<R>(self, mut f: impl FnMut(Q) -> R) -> Vector3<R> {
+        Vector3 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+}
+
+impl<Q> From<[Q; 3]> for Vector3<Q> {
+    fn from([x, y, z]: [Q; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Vector3<Acceleration<MilliG>> {
+    /// Convert to m/s².
+    pub fn to_mps2(self) -> Vector3<Acceleration<MetersPerSecondSquared>> {
+        self.map(Acceleration::to_mps2)
+    }
+}
+
+impl Vector3<AngularRate<MilliDps>> {
+    /// Convert to rad/s.
+    pub fn to_rad_s(self) -> Vector3<AngularRate<RadiansPerSecond>> {
+        self.map(AngularRate::to_rad_s)
+    }
+}
+
+impl XlFullScale {
+    /// Convert a raw acceleration sample for the full scale.
+    pub fn to_acceleration(self, lsb: i16) -> Acceleration<MilliG> {
+        Acceleration::new(self.to_mg(lsb))
+    }
+}
+
+impl HgXlFullScale {
+    /// Convert a raw acceleration sample for the full scale.
+    pub fn to_acceleration(self, lsb: i16) -> Acceleration<MilliG> {
+        Acceleration::new(self.to_mg(lsb))
+    }
+}
+
+impl OisXlFullScale {
+    /// Convert a raw acceleration sample for the full scale.
+    pub fn to_acceleration(self, lsb: i16) -> Acceleration<MilliG> {
+        Acceleration::new(self.to_mg(lsb))
+    }
+}
+
+impl GyFullScale {
+    /// Convert a raw angular rate sample for the full scale.
+    pub fn to_angular_rate(self, lsb: i16) -> AngularRate<MilliDps> {
+        AngularRate::new(self.to_mdps(lsb))
+    }
+}
+
+impl EisGyFullScale {
+    /// Convert a raw angular rate sample for the full scale.
+    pub fn to_angular_rate(self, lsb: i16) -> AngularRate<MilliDps> {
+        AngularRate::new(self.to_mdps(lsb))
+    }
+}
+
+impl OisGyFullScale {
+    /// Convert a raw angular rate sample for the full scale.
+    pub fn to_angular_rate(self, lsb: i16) -> AngularRate<MilliDps> {
+        AngularRate::new(self.to_mdps(lsb))
+    }
+}
+
+impl From<Vector3<Acceleration<MilliG>>> for XlOffsetMg {
+    fn from(val: Vector3<Acceleration<MilliG>>) -> Self {
+        XlOffsetMg {
+            z_mg: val.z.value(),
+            y_mg: val.y.value(),
+            x_mg: val.x.value(),
+        }
+    }
+}
+
+impl From<XlOffsetMg> for Vector3<Acceleration<MilliG>> {
+    fn from(val: XlOffsetMg) -> Self {
+        Vector3::new(
+            Acceleration::new(val.x_mg),
+            Acceleration::new(val.y_mg),
+            Acceleration::new(val.z_mg),
+        )
+    }
+}
+
+impl From<Vector3<AngularRate<MilliDps>>> for SflpGbias {
+    fn from(val: Vector3<AngularRate<MilliDps>>) -> Self {
+        SflpGbias {
+            gbias_x: val.x.value() / 1000.0,
+            gbias_y: val.y.value() / 1000.0,
+            gbias_z: val.z.value() / 1000.0,
+        }
+    }
+}
+
+impl From<SflpGbias> for Vector3<AngularRate<MilliDps>> {
+    fn from(val: SflpGbias) -> Self {
+        Vector3::new(
+            AngularRate::new(val.gbias_x * 1000.0),
+            AngularRate::new(val.gbias_y * 1000.0),
+            AngularRate::new(val.gbias_z * 1000.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::super::{Lsm6dsv320x, from_fs4_to_mg};
+    use super::*;
+
+    #[test]
+    fn sflp_gbias_is_dps() {
+        let mdps = Vector3::new(
+            AngularRate::<MilliDps>::new(1500.0),
+            AngularRate::new(-250.0),
+            AngularRate::new(0.0),
+        );
+
+        let gbias = SflpGbias::from(mdps);
+        assert_eq!(
+            gbias,
+            SflpGbias {
+                gbias_x: 1.5,
+                gbias_y: -0.25,
+                gbias_z: 0.0,
+            }
+        );
+        assert_eq!(Vector3::<AngularRate<MilliDps>>::from(gbias), mdps);
+    }
+
+    const LSB: [i16; 4] = [i16::MIN, -1, 1, i16::MAX];
+
+    #[test]
+    fn xl_full_scale_sensitivity() {
+        let table = [
+            (XlFullScale::_2g, 0.061),
+            (XlFullScale::_4g, 0.122),
+            (XlFullScale::_8g, 0.244),
+            (XlFullScale::_16g, 0.488),
+        ];
+        for (fs, mg_lsb) in table {
+            for lsb in LSB {
+                assert_eq!(fs.to_mg(lsb), lsb as f32 * mg_lsb, "{fs:?}");
+            }
+        }
+
+        let table = [
+            (HgXlFullScale::_32g, 0.976),
+            (HgXlFullScale::_64g, 1.952),
+            (HgXlFullScale::_128g, 3.904),
+            (HgXlFullScale::_256g, 7.808),
+            (HgXlFullScale::_320g, 10.417),
+        ];
+        for (fs, mg_lsb) in table {
+            for lsb in LSB {
+                assert_eq!(fs.to_mg(lsb), lsb as f32 * mg_lsb, "{fs:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn gy_full_scale_sensitivity() {
+        let table = [
+            (GyFullScale::_250dps, 8.75),
+            (GyFullScale::_500dps, 17.5),
+            (GyFullScale::_1000dps, 35.0),
+            (GyFullScale::_2000dps, 70.0),
+            (GyFullScale::_4000dps, 140.0),
+        ];
+        for (fs, mdps_lsb) in table {
+            for lsb in LSB {
+                assert_eq!(fs.to_mdps(lsb), lsb as f32 * mdps_lsb, "{fs:?}");
+            }
+        }
+    }
+
+    fn out_set(sensor: &mut Lsm6dsv320x<Simulator, NoDelay, MainBank>, reg: Reg, xyz: [i16; 3]) {
+        for (i, val) in xyz.iter().enumerate() {
+            let [l, h] = val.to_le_bytes();
+            let addr = reg as u8 + 2 * i as u8;
+            sensor.bus.reg_set(MemBank::MainMemBank, addr, l);
+            sensor.bus.reg_set(MemBank::MainMemBank, addr + 1, h);
+        }
+    }
+
+    #[test]
+    fn unit_getters_use_device_full_scale() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+        out_set(&mut sensor, Reg::OutxLA, [1000, -2000, 0]);
+        out_set(&mut sensor, Reg::OutxLG, [100, -1, i16::MAX]);
+
+        block_on(sensor.xl_full_scale_set(XlFullScale::_8g)).unwrap();
+        let mg = block_on(sensor.acceleration_mg_get()).unwrap();
+        assert_eq!(mg.map(|a| a.value()), Vector3::new(244.0, -488.0, 0.0));
+
+        block_on(sensor.gy_full_scale_set(GyFullScale::_500dps)).unwrap();
+        let mdps = block_on(sensor.angular_rate_mdps_get()).unwrap();
+        assert_eq!(
+            mdps.map(|g| g.value()),
+            Vector3::new(1750.0, -17.5, i16::MAX as f32 * 17.5)
+        );
+    }
+
+    fn mg(x: f32, y: f32, z: f32) -> Vector3<Acceleration<MilliG>> {
+        Vector3::new(
+            Acceleration::new(x),
+            Acceleration::new(y),
+            Acceleration::new(z),
+        )
+    }
+
+    #[test]
+    fn offset_round_trip() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay);
+
+        // ±0.9921875 mg range, 0.0078125 mg precision
+        let low = mg(0.5, -0.25, 0.0078125);
+        block_on(sensor.xl_offset_set(low)).unwrap();
+        assert_eq!(block_on(sensor.xl_offset_get()).unwrap(), low);
+
+        // ±15.875 mg range, 0.125 mg precision
+        let high = mg(10.0, -0.125, 15.75);
+        block_on(sensor.xl_offset_set(high)).unwrap();
+        assert_eq!(block_on(sensor.xl_offset_get()).unwrap(), high);
+
+        let hg = mg(2.5, -5.0, 0.25);
+        block_on(sensor.hg_xl_offset_set(hg)).unwrap();
+        assert_eq!(block_on(sensor.hg_xl_offset_get()).unwrap(), hg);
+    }
+
+    #[test]
+    fn typed_conversions() {
+        assert_eq!(
+            XlFullScale::_4g.to_acceleration(100),
+            Acceleration::new(from_fs4_to_mg(100))
+        );
+        assert_eq!(
+            GyFullScale::_250dps.to_angular_rate(-8),
+            AngularRate::new(-70.0)
+        );
+        assert_eq!(
+            Temperature::from_lsb(512),
+            Temperature::<Celsius>::new(27.0)
+        );
+        assert_eq!(
+            AngularRate::from_gbias_lsb(-2),
+            AngularRate::<MilliDps>::new(-8.75)
+        );
+    }
+}
@@ -0,0 +1,444 @@
+use core::future::{Future, poll_fn};
+use core::ops::{Deref, DerefMut};
+use core::pin::pin;
+use core::task::Poll;
+
+use embedded_hal_async::digital::Wait;
+
+use super::prelude::*;
+use super::{BusOperation, DelayNs, Error, Lsm6dsv320x};
+
+/// Error of the `wait_for_*` functions.
+#[derive(Debug, PartialEq)]
+pub enum WaitError<B, P> {
+    /// Error of the sensor.
+    Sensor(Error<B>),
+    /// Error of the interrupt pin.
+    Pin(P),
+    /// The interrupt pin has not been asserted within the timeout.
+    Timeout,
+}
+
+impl<B, P> From<Error<B>> for WaitError<B, P> {
+    fn from(err: Error<B>) -> Self {
+        WaitError::Sensor(err)
+    }
+}
+
+/// Driver owning the interrupt pin connected to the sensor.
+///
+/// The `wait_for_*` functions route the needed sources on the pad the pin is
+/// connected to, wait for the pin to be asserted and acknowledge the latched
+/// sources, returning the decoded status:
+///
+/// ```rust,ignore
+/// let mut sensor = Lsm6dsv320x::new_i2c(i2c, I2CAddress::I2cAddL, delay)
+///     .with_int_pin(int1, IntPin::Int1);
+///
+/// let fifo = sensor.wait_for_fifo_watermark().await.unwrap();
+/// ```
+///
+/// The pin is first awaited on its active level (see `int_pin_polarity_set`),
+/// so both pulsed and latched interrupts are supported; if the expected source
+/// is not asserted, the next assertion is awaited on the pin edge, so a source
+/// kept active (e.g. the FIFO watermark) does not keep reading the status
+/// registers. The `timeout_ms` of the `*_timeout` variants bounds each wait
+/// for the pin: an assertion for sources other than the expected one starts a
+/// new wait. The detection functions (FSM, high-g wake-up, ...) must be
+/// configured and enabled by the caller; all the other driver functions are
+/// available through `Deref`.
+pub struct Lsm6dsv320xIrq<B, T, P>
+where
+    B: BusOperation,
+    T: DelayNs,
+{
+    sensor: Lsm6dsv320x<B, T, MainBank>,
+    pin: P,
+    pad: IntPin,
+}
+
+impl<B: BusOperation, T: DelayNs> Lsm6dsv320x<B, T, MainBank> {
+    /// Give the driver the ownership of the interrupt pin connected to `pad`.
+    pub fn with_int_pin<P: Wait>(self, pin: P, pad: IntPin) -> Lsm6dsv320xIrq<B, T, P> {
+        Lsm6dsv320xIrq::new(self, pin, pad)
+    }
+}
+
+impl<B, T, P> Lsm6dsv320xIrq<B, T, P>
+where
+    B: BusOperation,
+    T: DelayNs,
+    P: Wait,
+{
+    /// Create the driver from the sensor and the interrupt pin connected to
+    /// `pad` (INT1, INT2 or both, if tied together).
+    pub fn new(sensor: Lsm6dsv320x<B, T, MainBank>, pin: P, pad: IntPin) -> Self {
+        Self { sensor, pin, pad }
+    }
+
+    /// Release the sensor and the interrupt pin.
+    pub fn release(self) -> (Lsm6dsv320x<B, T, MainBank>, P) {
+        (self.sensor, self.pin)
+    }
+
+    /// Wait for the interrupt pin and get the interrupt events.
+    ///
+    /// No source is routed: the routing must be configured by the caller.
+    pub async fn wait_for_event(&mut self) -> Result<Events, WaitError<B::Error, P::Error>> {
+        self.events_wait(None).await
+    }
+
+    /// Same as `wait_for_event`, failing if the pin is not asserted within
+    /// `timeout_ms` milliseconds.
+    pub async fn wait_for_event_timeout(
+        &mut self,
+        timeout_ms: u32,
+    ) -> Result<Events, WaitError<B::Error, P::Error>> {
+        self.events_wait(Some(timeout_ms)).await
+    }
+
+    /// Wait for the FIFO watermark and get the FIFO status.
+    pub async fn wait_for_fifo_watermark(
+        &mut self,
+    ) -> Result<FifoState, WaitError<B::Error, P::Error>> {
+        self.fifo_watermark_wait(None).await
+    }
+
+    /// Same as `wait_for_fifo_watermark`, failing if the pin is not asserted
+    /// within `timeout_ms` milliseconds.
+    pub async fn wait_for_fifo_watermark_timeout(
+        &mut self,
+        timeout_ms: u32,
+    ) -> Result<FifoState, WaitError<B::Error, P::Error>> {
+        self.fifo_watermark_wait(Some(timeout_ms)).await
+    }
+
+    /// Wait for new data of at least one of the sensors in `val` and get
+    /// the data-ready flags.
+    pub async fn wait_for_data_ready(
+        &mut self,
+        val: DataReadyFlags,
+    ) -> Result<DataReadyFlags, WaitError<B::Error, P::Error>> {
+        self.data_ready_wait(val, None).await
+    }
+
+    /// Same as `wait_for_data_ready`, failing if the pin is not asserted
+    /// within `timeout_ms` milliseconds.
+    pub async fn wait_for_data_ready_timeout(
+        &mut self,
+        val: DataReadyFlags,
+        timeout_ms: u32,
+    ) -> Result<DataReadyFlags, WaitError<B::Error, P::Error>> {
+        self.data_ready_wait(val, Some(timeout_ms)).await
+    }
+
+    /// Wait for a high-g shock state change and get the new shock state.
+    pub async fn wait_for_hg_shock(&mut self) -> Result<bool, WaitError<B::Error, P::Error>> {
+        self.hg_shock_wait(None).await
+    }
+
+    /// Same as `wait_for_hg_shock`, failing if the pin is not asserted
+    /// within `timeout_ms` milliseconds.
+    pub async fn wait_for_hg_shock_timeout(
+        &mut self,
+        timeout_ms: u32,
+    ) -> Result<bool, WaitError<B::Error, P::Error>> {
+        self.hg_shock_wait(Some(timeout_ms)).await
+    }
+
+    /// Wait for the interrupt of FSM `n` (1 - 8) and get its output.
+    pub async fn wait_for_fsm(&mut self, n: u8) -> Result<u8, WaitError<B::Error, P::Error>> {
+        self.fsm_wait(n, None).await
+    }
+
+    /// Same as `wait_for_fsm`, failing if the pin is not asserted within
+    /// `timeout_ms` milliseconds.
+    pub async fn wait_for_fsm_timeout(
+        &mut self,
+        n: u8,
+        timeout_ms: u32,
+    ) -> Result<u8, WaitError<B::Error, P::Error>> {
+        self.fsm_wait(n, Some(timeout_ms)).await
+    }
+
+    /// Wait for the interrupt of MLC `n` (1 - 8) and get the decision tree
+    /// class.
+    pub async fn wait_for_mlc(&mut self, n: u8) -> Result<u8, WaitError<B::Error, P::Error>> {
+        self.mlc_wait(n, None).await
+    }
+
+    /// Same as `wait_for_mlc`, failing if the pin is not asserted within
+    /// `timeout_ms` milliseconds.
+    pub async fn wait_for_mlc_timeout(
+        &mut self,
+        n: u8,
+        timeout_ms: u32,
+    ) -> Result<u8, WaitError<B::Error, P::Error>> {
+        self.mlc_wait(n, Some(timeout_ms)).await
+    }
+
+    async fn events_wait(
+        &mut self,
+        timeout_ms: Option<u32>,
+    ) -> Result<Events, WaitError<B::Error, P::Error>> {
+        self.pin_wait(timeout_ms, false).await?;
+        Ok(self.sensor.events_get().await?)
+    }
+
+    async fn fifo_watermark_wait(
+        &mut self,
+        timeout_ms: Option<u32>,
+    ) -> Result<FifoState, WaitError<B::Error, P::Error>> {
+        self.source_wait(&[IntSource::FifoTh], timeout_ms, |mut events| {
+            events
+                .any(|event| event == Event::FifoWatermark)
+                .then_some(())
+        })
+        .await?;
+
+        Ok(self.sensor.fifo_state_get().await?)
+    }
+
+    async fn data_ready_wait(
+        &mut self,
+        val: DataReadyFlags,
+        timeout_ms: Option<u32>,
+    ) -> Result<DataReadyFlags, WaitError<B::Error, P::Error>> {
+        let routes = [
+            (DataReadyFlags::DRDY_XL, IntSource::DrdyXl),
+            (DataReadyFlags::DRDY_GY, IntSource::DrdyGy),
+            (DataReadyFlags::DRDY_TEMP, IntSource::DrdyTemp),
+            (DataReadyFlags::DRDY_HGXL, IntSource::DrdyHgXl),
+        ];
+        let mut sources = [IntSource::DrdyXl; 4];
+        let mut len = 0;
+        for &(flag, source) in routes.iter() {
+            if val.contains(flag) {
+                sources[len] = source;
+                len += 1;
+            }
+        }
+
+        self.source_wait(&sources[..len], timeout_ms, |events| {
+            let mut ready = DataReadyFlags::empty();
+            for event in events {
+                match event {
+                    Event::DataReadyXl => ready.insert(DataReadyFlags::DRDY_XL),
+                    Event::DataReadyGy => ready.insert(DataReadyFlags::DRDY_GY),
+                    Event::DataReadyTemp => ready.insert(DataReadyFlags::DRDY_TEMP),
+                    Event::DataReadyHgXl => ready.insert(DataReadyFlags::DRDY_HGXL),
+                    _ => {}
+                }
+            }
+            ready.intersects(val).then_some(ready)
+        })
+        .await
+    }
+
+    async fn hg_shock_wait(
+        &mut self,
+        timeout_ms: Option<u32>,
+    ) -> Result<bool, WaitError<B::Error, P::Error>> {
+        self.source_wait(&[IntSource::HgShockChange], timeout_ms, |mut events| {
+            events.find_map(|event| match event {
+                Event::HgShock { shock } => Some(shock),
+                _ => None,
+            })
+        })
+        .await
+    }
+
+    async fn fsm_wait(
+        &mut self,
+        n: u8,
+        timeout_ms: Option<u32>,
+    ) -> Result<u8, WaitError<B::Error, P::Error>> {
+        self.source_wait(&[IntSource::Fsm(n)], timeout_ms, |mut events| {
+            events.find_map(|event| match event {
+                Event::FsmOutput(fsm, value) if fsm == n => Some(value),
+                _ => None,
+            })
+        })
+        .await
+    }
+
+    async fn mlc_wait(
+        &mut self,
+        n: u8,
+        timeout_ms: Option<u32>,
+    ) -> Result<u8, WaitError<B::Error, P::Error>> {
+        self.source_wait(&[IntSource::Mlc(n)], timeout_ms, |mut events| {
+            events.find_map(|event| match event {
+                Event::MlcClass(mlc, class) if mlc == n => Some(class),
+                _ => None,
+            })
+        })
+        .await
+    }
+
+    /// Route `sources` on the pad, then wait until `f` finds the expected
+    /// event. Events reported for other sources are acknowledged and dropped.
+    ///
+    /// After the first check, the pin edge is awaited: sources which are not
+    /// cleared by the acknowledge keep the pin active.
+    async fn source_wait<R>(
+        &mut self,
+        sources: &[IntSource],
+        timeout_ms: Option<u32>,
+        mut f: impl FnMut(Events) -> Option<R>,
+    ) -> Result<R, WaitError<B::Error, P::Error>> {
+        self.arm(sources).await?;
+
+        let mut edge = false;
+        loop {
+            self.pin_wait(timeout_ms, edge).await?;
+
+            let events = self.sensor.events_get().await?;
+            if let Some(val) = f(events) {
+                return Ok(val);
+            }
+            edge = true;
+        }
+    }
+
+    /// Add `sources` to the routing of the pad, keeping the sources already
+    /// routed.
+    async fn arm(&mut self, sources: &[IntSource]) -> Result<(), Error<B::Error>> {
+        let mut routing = self.sensor.interrupt_routing_get().await?;
+
+        let mut changed = false;
+        for &source in sources {
+            let pin = routing.pin(source);
+            let armed =
+                IntPin::from_pins(pin.int1() || self.pad.int1(), pin.int2() || self.pad.int2());
+            if armed != pin {
+                routing = routing.route(source, armed);
+                changed = true;
+            }
+        }
+
+        if routing.unsupported().is_some() {
+            return Err(Error::InvalidConfiguration);
+        }
+
+        if changed {
+            self.sensor.interrupt_routing_set(&routing).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Wait for the active level of the interrupt pin or, if `edge` is set,
+    /// for its transition to the active level, at most `timeout_ms`.
+    async fn pin_wait(
+        &mut self,
+        timeout_ms: Option<u32>,
+        edge: bool,
+    ) -> Result<(), WaitError<B::Error, P::Error>> {
+        let polarity = self.sensor.int_pin_polarity_get().await?;
+
+        let pin = &mut self.pin;
+        let active = async {
+            match (polarity, edge) {
+                (IntPinPolarity::ActiveHigh, false) => pin.wait_for_high().await,
+                (IntPinPolarity::ActiveLow, false) => pin.wait_for_low().await,
+                (IntPinPolarity::ActiveHigh, true) => pin.wait_for_rising_edge().await,
+                (IntPinPolarity::ActiveLow, true) => pin.wait_for_falling_edge().await,
+            }
+        };
+
+        let res = match timeout_ms {
+            Some(ms) => race(active, self.sensor.tim.delay_ms(ms))
+                .await
+                .ok_or(WaitError::Timeout)?,
+            None => active.await,
+        };
+
+        res.map_err(WaitError::Pin)
+    }
+}
+
+impl<B: BusOperation, T: DelayNs, P> Deref for Lsm6dsv320xIrq<B, T, P> {
+    type Target = Lsm6dsv320x<B, T, MainBank>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sensor
+    }
+}
+
+impl<B: BusOperation, T: DelayNs, P> DerefMut for Lsm6dsv320xIrq<B, T, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.sensor
+    }
+}
+
+/// Run `fut` until it completes or `timeout` expires.
+async fn race<F: Future>(fut: F, timeout: impl Future<Output = ()>) -> Option<F::Output> {
+    let mut fut = pin!(fut);
+    let mut timeout = pin!(timeout);
+
+    poll_fn(|cx| {
+        if let Poll::Ready(val) = fut.as_mut().poll(cx) {
+            return Poll::Ready(Some(val));
+        }
+        if timeout.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(None);
+        }
+        Poll::Pending
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use embedded_hal::digital::ErrorType;
+
+    use super::super::sim::{NoDelay, Simulator, block_on};
+    use super::*;
+
+    /// Interrupt pin stuck at the active level, with no further edges.
+    #[derive(Default)]
+    struct StuckPin {
+        level_waits: usize,
+    }
+
+    impl ErrorType for StuckPin {
+        type Error = Infallible;
+    }
+
+    impl Wait for StuckPin {
+        async fn wait_for_high(&mut self) -> Result<(), Infallible> {
+            self.level_waits += 1;
+            Ok(())
+        }
+
+        async fn wait_for_low(&mut self) -> Result<(), Infallible> {
+            self.level_waits += 1;
+            Ok(())
+        }
+
+        async fn wait_for_rising_edge(&mut self) -> Result<(), Infallible> {
+            core::future::pending().await
+        }
+
+        async fn wait_for_falling_edge(&mut self) -> Result<(), Infallible> {
+            core::future::pending().await
+        }
+
+        async fn wait_for_any_edge(&mut self) -> Result<(), Infallible> {
+            core::future::pending().await
+        }
+    }
+
+    #[test]
+    fn pin_stuck_active_times_out() {
+        let mut sensor = Lsm6dsv320x::from_bus(Simulator::new(), NoDelay)
+            .with_int_pin(StuckPin::default(), IntPin::Int1);
+
+        let res = block_on(sensor.wait_for_fsm_timeout(1, 5));
+        assert!(matches!(res, Err(WaitError::Timeout)));
+        assert_eq!(sensor.pin.level_waits, 1);
+    }
+}